@@ -0,0 +1 @@
+ALTER TABLE accounts ADD COLUMN password_changed_at TIMESTAMPTZ;
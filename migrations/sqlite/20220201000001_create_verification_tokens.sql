@@ -0,0 +1,8 @@
+CREATE TABLE verification_tokens (
+    token_hash TEXT PRIMARY KEY NOT NULL,
+    purpose TEXT NOT NULL,
+    account_id TEXT NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    expires_at TEXT NOT NULL
+);
+
+CREATE INDEX verification_tokens_account_id_idx ON verification_tokens (account_id);
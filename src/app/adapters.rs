@@ -0,0 +1,3 @@
+pub mod actix_route_adapter;
+
+pub use actix_route_adapter::{adapt_protected_route, adapt_route};
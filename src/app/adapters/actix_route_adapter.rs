@@ -0,0 +1,279 @@
+use actix_web::http::{header, StatusCode};
+use actix_web::web;
+use actix_web::Route;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::presentation::http::{HttpRequest, HttpResponse};
+use crate::presentation::middlewares::{AuthMiddleware, AuthResBody};
+use crate::presentation::protocols::ControllerProtocol;
+
+/// Turns `route` into a handler for the controller `C` registered as
+/// `web::Data<C>` in the app data.
+///
+/// A missing or malformed JSON body is passed to the controller as
+/// `HttpRequest::new(None)`.
+pub fn adapt_route<C, ReqBody, ResBody>(route: Route) -> Route
+where
+    C: ControllerProtocol<ReqBody, ResBody> + Send + Sync + 'static,
+    ReqBody: DeserializeOwned + Send + 'static,
+    ResBody: Serialize + Send + 'static,
+{
+    route.to(handle::<C, ReqBody, ResBody>)
+}
+
+/// Like [`adapt_route`], but only lets through requests the `AuthMiddleware`
+/// registered as `web::Data<AuthMiddleware>` authenticates, passing the
+/// controller the id of their account.
+pub fn adapt_protected_route<C, ReqBody, ResBody>(route: Route) -> Route
+where
+    C: ControllerProtocol<ReqBody, ResBody> + Send + Sync + 'static,
+    ReqBody: DeserializeOwned + Send + 'static,
+    ResBody: Serialize + Send + 'static,
+{
+    route.to(handle_protected::<C, ReqBody, ResBody>)
+}
+
+async fn handle<C, ReqBody, ResBody>(
+    controller: web::Data<C>,
+    body: Option<web::Json<ReqBody>>,
+) -> actix_web::HttpResponse
+where
+    C: ControllerProtocol<ReqBody, ResBody> + Send + Sync + 'static,
+    ReqBody: DeserializeOwned + Send + 'static,
+    ResBody: Serialize + Send + 'static,
+{
+    let req = HttpRequest::new(body.map(web::Json::into_inner));
+    let res = controller.handle(req).await;
+
+    respond(res)
+}
+
+async fn handle_protected<C, ReqBody, ResBody>(
+    auth_middleware: web::Data<AuthMiddleware>,
+    controller: web::Data<C>,
+    http_req: actix_web::HttpRequest,
+    body: Option<web::Json<ReqBody>>,
+) -> actix_web::HttpResponse
+where
+    C: ControllerProtocol<ReqBody, ResBody> + Send + Sync + 'static,
+    ReqBody: DeserializeOwned + Send + 'static,
+    ResBody: Serialize + Send + 'static,
+{
+    let authorization = http_req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    let auth_res = auth_middleware.handle(authorization).await;
+
+    let account_id = match auth_res.body() {
+        AuthResBody::AccountId { account_id } => account_id,
+        AuthResBody::Err(_) => return respond(auth_res),
+    };
+
+    let req = HttpRequest::new(body.map(web::Json::into_inner)).with_account_id(account_id);
+    let res = controller.handle(req).await;
+
+    respond(res)
+}
+
+fn respond<T>(res: HttpResponse<T>) -> actix_web::HttpResponse
+where
+    T: Serialize + Send,
+{
+    actix_web::HttpResponse::build(status_code(res.status_code()))
+        .content_type(res.content_type())
+        .json(res.body())
+}
+
+fn status_code(status_code: u32) -> StatusCode {
+    u16::try_from(status_code)
+        .ok()
+        .and_then(|code| StatusCode::from_u16(code).ok())
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::{http, test, web, App};
+    use async_trait::async_trait;
+    use chrono::{TimeZone, Utc};
+    use serde::{Deserialize, Serialize};
+    use serde_json::{json, Value};
+
+    use crate::domain::entities::AccountEntity;
+    use crate::domain::usecases::MockLoadAccountByToken;
+    use crate::presentation::http::{
+        HttpRequest, HttpResponse, JSON_CONTENT_TYPE, PROBLEM_JSON_CONTENT_TYPE,
+    };
+    use crate::presentation::middlewares::AuthMiddleware;
+    use crate::presentation::protocols::ControllerProtocol;
+
+    use super::{adapt_protected_route, adapt_route};
+
+    #[derive(Deserialize)]
+    struct EchoReqBody {
+        value: String,
+    }
+
+    #[derive(Serialize)]
+    struct EchoResBody {
+        value: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        account_id: Option<String>,
+    }
+
+    struct EchoController {
+        status_code: u32,
+    }
+
+    #[async_trait]
+    impl ControllerProtocol<EchoReqBody, EchoResBody> for EchoController {
+        async fn handle(&self, req: HttpRequest<EchoReqBody>) -> HttpResponse<EchoResBody> {
+            let value = req.body().map(|body| body.value.clone());
+            let account_id = req.account_id().map(String::from);
+            let res = HttpResponse::new(self.status_code, EchoResBody { value, account_id });
+
+            if self.status_code >= 400 {
+                return res.with_content_type(PROBLEM_JSON_CONTENT_TYPE);
+            }
+
+            res
+        }
+    }
+
+    async fn call(status_code: u32, req: test::TestRequest) -> ServiceResponse {
+        let app = App::new()
+            .app_data(web::Data::new(EchoController { status_code }))
+            .route(
+                "/echo",
+                adapt_route::<EchoController, EchoReqBody, EchoResBody>(web::post()),
+            );
+        let app = test::init_service(app).await;
+
+        app.call(req.uri("/echo").to_request()).await.unwrap()
+    }
+
+    async fn call_protected(req: test::TestRequest) -> ServiceResponse {
+        let mut load_account_by_token = MockLoadAccountByToken::default();
+        load_account_by_token.expect_load().returning(|token| {
+            if token != "valid_token" {
+                return Ok(None);
+            }
+
+            Ok(Some(AccountEntity::new(
+                "any_id",
+                "any_name",
+                "any_email@mail.com",
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        });
+
+        let app = App::new()
+            .app_data(web::Data::new(AuthMiddleware::new(Box::new(
+                load_account_by_token,
+            ))))
+            .app_data(web::Data::new(EchoController { status_code: 200 }))
+            .route(
+                "/echo",
+                adapt_protected_route::<EchoController, EchoReqBody, EchoResBody>(web::post()),
+            );
+        let app = test::init_service(app).await;
+
+        app.call(req.uri("/echo").to_request()).await.unwrap()
+    }
+
+    #[actix_web::test]
+    async fn passes_the_json_body_to_the_controller() {
+        let req = test::TestRequest::post().set_json(json!({ "value": "any_value" }));
+        let res = call(200, req).await;
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body, json!({ "value": "any_value" }));
+    }
+
+    #[actix_web::test]
+    async fn passes_none_if_body_is_missing() {
+        let res = call(200, test::TestRequest::post()).await;
+
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body, json!({ "value": null }));
+    }
+
+    #[actix_web::test]
+    async fn passes_none_if_body_is_malformed() {
+        let req = test::TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .set_payload("{ invalid json");
+        let res = call(200, req).await;
+
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body, json!({ "value": null }));
+    }
+
+    #[actix_web::test]
+    async fn uses_the_controller_status_code() {
+        let res = call(409, test::TestRequest::post()).await;
+
+        assert_eq!(res.status(), http::StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn uses_the_controller_content_type() {
+        let res = call(200, test::TestRequest::post()).await;
+        assert_eq!(
+            res.headers().get("content-type").unwrap(),
+            JSON_CONTENT_TYPE
+        );
+
+        let res = call(400, test::TestRequest::post()).await;
+        assert_eq!(
+            res.headers().get("content-type").unwrap(),
+            PROBLEM_JSON_CONTENT_TYPE
+        );
+    }
+
+    #[actix_web::test]
+    async fn returns_500_if_the_status_code_is_invalid() {
+        let res = call(70_000, test::TestRequest::post()).await;
+
+        assert_eq!(res.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn passes_the_authenticated_account_id_to_protected_controllers() {
+        let req = test::TestRequest::post()
+            .insert_header(("authorization", "Bearer valid_token"))
+            .set_json(json!({ "value": "any_value" }));
+        let res = call_protected(req).await;
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(
+            body,
+            json!({ "value": "any_value", "account_id": "any_id" })
+        );
+    }
+
+    #[actix_web::test]
+    async fn returns_401_if_protected_routes_are_not_authenticated() {
+        for req in [
+            test::TestRequest::post(),
+            test::TestRequest::post().insert_header(("authorization", "Bearer invalid_token")),
+        ] {
+            let res = call_protected(req).await;
+
+            assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+            assert_eq!(
+                res.headers().get("content-type").unwrap(),
+                PROBLEM_JSON_CONTENT_TYPE
+            );
+        }
+    }
+}
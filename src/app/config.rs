@@ -0,0 +1,1131 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::str::FromStr;
+
+use log::LevelFilter;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::infra::crypto::Argon2Config;
+use crate::infra::db::{MongoConfig, SqlConfig};
+use crate::infra::mail::SmtpConfig;
+use crate::utils::{EmailNormalizerConfig, EmailPolicyConfig};
+use crate::validation::validators::PasswordPolicy;
+use crate::GenericResult;
+
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read the config file: {0}")]
+    Read(#[from] io::Error),
+    #[error("failed to parse the config file: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("invalid value for {key}: {msg}")]
+    InvalidEnv { key: String, msg: String },
+    #[error("SERVER_TLS_CERT_PATH and SERVER_TLS_KEY_PATH must be set together")]
+    IncompleteTlsEnv,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub log: LogConfig,
+    pub database: DatabaseConfig,
+    pub mongo: MongoConfig,
+    pub sql: SqlConfig,
+    pub argon2: Argon2Config,
+    pub jwt: JwtSettings,
+    pub password_policy: PasswordPolicy,
+    pub email_normalizer: EmailNormalizerConfig,
+    pub email_policy: EmailPolicyConfig,
+    pub mailer: MailerConfig,
+    pub smtp: SmtpConfig,
+    pub email_verification: EmailVerificationConfig,
+    pub password_reset: PasswordResetConfig,
+}
+
+impl Config {
+    /// Loads the TOML file named by `CONFIG_FILE`, or `config.toml` when it
+    /// exists, and applies the environment variable overrides on top of it.
+    pub fn load() -> GenericResult<Self, ConfigError> {
+        let contents = match env::var("CONFIG_FILE") {
+            Ok(path) => Some(fs::read_to_string(path)?),
+            Err(_) => fs::read_to_string(DEFAULT_CONFIG_FILE).ok(),
+        };
+
+        Self::from_sources(contents.as_deref(), |key| env::var(key).ok())
+    }
+
+    fn from_sources<F>(contents: Option<&str>, env: F) -> GenericResult<Self, ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut config = match contents {
+            Some(contents) => toml::from_str(contents)?,
+            None => Self::default(),
+        };
+
+        config.apply_env(env)?;
+
+        Ok(config)
+    }
+
+    fn apply_env<F>(&mut self, env: F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        self.server.apply_env(&env)?;
+
+        if let Some(level) = parse_env(&env, "LOG_LEVEL")? {
+            self.log.level = level;
+        }
+
+        if let Some(backend) = parse_env(&env, "DATABASE_BACKEND")? {
+            self.database.backend = backend;
+        }
+
+        self.apply_mongo_env(&env)?;
+        self.apply_sql_env(&env)?;
+        self.apply_argon2_env(&env)?;
+        self.apply_jwt_env(&env)?;
+        self.apply_password_policy_env(&env)?;
+
+        if let Some(lowercase) = parse_env(&env, "EMAIL_NORMALIZER_LOWERCASE_LOCAL_PART")? {
+            self.email_normalizer.lowercase_local_part = lowercase;
+        }
+
+        if let Some(provider_rules) = parse_env(&env, "EMAIL_NORMALIZER_PROVIDER_RULES")? {
+            self.email_normalizer.provider_rules = provider_rules;
+        }
+
+        self.apply_email_policy_env(&env)?;
+
+        if let Some(backend) = parse_env(&env, "MAILER_BACKEND")? {
+            self.mailer.backend = backend;
+        }
+
+        self.apply_smtp_env(&env)?;
+
+        if let Some(token_ttl_secs) = parse_env(&env, "EMAIL_VERIFICATION_TOKEN_TTL_SECS")? {
+            self.email_verification.token_ttl_secs = token_ttl_secs;
+        }
+
+        if let Some(verify_url) = env("EMAIL_VERIFICATION_VERIFY_URL") {
+            self.email_verification.verify_url = verify_url;
+        }
+
+        if let Some(cooldown_secs) = parse_env(&env, "EMAIL_VERIFICATION_COOLDOWN_SECS")? {
+            self.email_verification.cooldown_secs = cooldown_secs;
+        }
+
+        if let Some(required) = parse_env(&env, "EMAIL_VERIFICATION_REQUIRED")? {
+            self.email_verification.required = required;
+        }
+
+        if let Some(token_ttl_secs) = parse_env(&env, "PASSWORD_RESET_TOKEN_TTL_SECS")? {
+            self.password_reset.token_ttl_secs = token_ttl_secs;
+        }
+
+        if let Some(reset_url) = env("PASSWORD_RESET_URL") {
+            self.password_reset.reset_url = reset_url;
+        }
+
+        if let Some(cooldown_secs) = parse_env(&env, "PASSWORD_RESET_COOLDOWN_SECS")? {
+            self.password_reset.cooldown_secs = cooldown_secs;
+        }
+
+        Ok(())
+    }
+
+    fn apply_mongo_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mongo = &mut self.mongo;
+
+        if let Some(uri) = env("MONGO_URI") {
+            mongo.uri = uri;
+        }
+
+        if let Some(database) = env("MONGO_DATABASE") {
+            mongo.database = database;
+        }
+
+        if let Some(min_pool_size) = parse_env(env, "MONGO_MIN_POOL_SIZE")? {
+            mongo.min_pool_size = Some(min_pool_size);
+        }
+
+        if let Some(max_pool_size) = parse_env(env, "MONGO_MAX_POOL_SIZE")? {
+            mongo.max_pool_size = Some(max_pool_size);
+        }
+
+        if let Some(connect_timeout_ms) = parse_env(env, "MONGO_CONNECT_TIMEOUT_MS")? {
+            mongo.connect_timeout_ms = Some(connect_timeout_ms);
+        }
+
+        if let Some(timeout_ms) = parse_env(env, "MONGO_SERVER_SELECTION_TIMEOUT_MS")? {
+            mongo.server_selection_timeout_ms = Some(timeout_ms);
+        }
+
+        Ok(())
+    }
+
+    fn apply_sql_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let sql = &mut self.sql;
+
+        if let Some(url) = env("SQL_URL") {
+            sql.url = url;
+        }
+
+        if let Some(max_connections) = parse_env(env, "SQL_MAX_CONNECTIONS")? {
+            sql.max_connections = Some(max_connections);
+        }
+
+        if let Some(connect_timeout_ms) = parse_env(env, "SQL_CONNECT_TIMEOUT_MS")? {
+            sql.connect_timeout_ms = Some(connect_timeout_ms);
+        }
+
+        Ok(())
+    }
+
+    fn apply_argon2_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let argon2 = &mut self.argon2;
+
+        if let Some(memory_cost) = parse_env(env, "ARGON2_MEMORY_COST")? {
+            argon2.memory_cost = memory_cost;
+        }
+
+        if let Some(time_cost) = parse_env(env, "ARGON2_TIME_COST")? {
+            argon2.time_cost = time_cost;
+        }
+
+        if let Some(parallelism) = parse_env(env, "ARGON2_PARALLELISM")? {
+            argon2.parallelism = parallelism;
+        }
+
+        Ok(())
+    }
+
+    fn apply_jwt_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let jwt = &mut self.jwt;
+
+        if let Some(algorithm) = parse_env(env, "JWT_ALGORITHM")? {
+            jwt.algorithm = algorithm;
+        }
+
+        if let Some(secret) = env("JWT_SECRET") {
+            jwt.secret = Some(secret);
+        }
+
+        if let Some(private_key_path) = env("JWT_PRIVATE_KEY_PATH") {
+            jwt.private_key_path = Some(private_key_path);
+        }
+
+        if let Some(public_key_path) = env("JWT_PUBLIC_KEY_PATH") {
+            jwt.public_key_path = Some(public_key_path);
+        }
+
+        if let Some(issuer) = env("JWT_ISSUER") {
+            jwt.issuer = Some(issuer);
+        }
+
+        if let Some(audience) = env("JWT_AUDIENCE") {
+            jwt.audience = Some(audience);
+        }
+
+        if let Some(expires_in_secs) = parse_env(env, "JWT_EXPIRES_IN_SECS")? {
+            jwt.expires_in_secs = expires_in_secs;
+        }
+
+        Ok(())
+    }
+
+    fn apply_email_policy_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let email_policy = &mut self.email_policy;
+
+        if let Some(allowed_domains) = env("EMAIL_POLICY_ALLOWED_DOMAINS") {
+            email_policy.allowed_domains = split_list(&allowed_domains);
+        }
+
+        if let Some(denied_domains) = env("EMAIL_POLICY_DENIED_DOMAINS") {
+            email_policy.denied_domains = split_list(&denied_domains);
+        }
+
+        if let Some(reject_disposable) = parse_env(env, "EMAIL_POLICY_REJECT_DISPOSABLE")? {
+            email_policy.reject_disposable = reject_disposable;
+        }
+
+        Ok(())
+    }
+
+    fn apply_smtp_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let smtp = &mut self.smtp;
+
+        if let Some(host) = env("SMTP_HOST") {
+            smtp.host = host;
+        }
+
+        if let Some(port) = parse_env(env, "SMTP_PORT")? {
+            smtp.port = Some(port);
+        }
+
+        if let Some(username) = env("SMTP_USERNAME") {
+            smtp.username = Some(username);
+        }
+
+        if let Some(password) = env("SMTP_PASSWORD") {
+            smtp.password = Some(password);
+        }
+
+        if let Some(from) = env("SMTP_FROM") {
+            smtp.from = from;
+        }
+
+        if let Some(starttls) = parse_env(env, "SMTP_STARTTLS")? {
+            smtp.starttls = starttls;
+        }
+
+        Ok(())
+    }
+
+    fn apply_password_policy_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let policy = &mut self.password_policy;
+
+        if let Some(min_length) = parse_env(env, "PASSWORD_MIN_LENGTH")? {
+            policy.min_length = min_length;
+        }
+
+        if let Some(max_length) = parse_env(env, "PASSWORD_MAX_LENGTH")? {
+            policy.max_length = max_length;
+        }
+
+        if let Some(require_lowercase) = parse_env(env, "PASSWORD_REQUIRE_LOWERCASE")? {
+            policy.require_lowercase = require_lowercase;
+        }
+
+        if let Some(require_uppercase) = parse_env(env, "PASSWORD_REQUIRE_UPPERCASE")? {
+            policy.require_uppercase = require_uppercase;
+        }
+
+        if let Some(require_digit) = parse_env(env, "PASSWORD_REQUIRE_DIGIT")? {
+            policy.require_digit = require_digit;
+        }
+
+        if let Some(require_symbol) = parse_env(env, "PASSWORD_REQUIRE_SYMBOL")? {
+            policy.require_symbol = require_symbol;
+        }
+
+        if let Some(reject_common) = parse_env(env, "PASSWORD_REJECT_COMMON")? {
+            policy.reject_common = reject_common;
+        }
+
+        if let Some(reject_personal_info) = parse_env(env, "PASSWORD_REJECT_PERSONAL_INFO")? {
+            policy.reject_personal_info = reject_personal_info;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct LogConfig {
+    /// Most verbose level written to stderr, `off` to `trace`.
+    pub level: LevelFilter,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            level: LevelFilter::Warn,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct DatabaseConfig {
+    pub backend: DatabaseBackend,
+}
+
+/// Storage used by the account repositories.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    #[default]
+    Mongo,
+    /// SQLite or Postgres, depending on the `[sql]` url.
+    Sql,
+    /// Accounts are kept in memory and lost on restart.
+    Memory,
+}
+
+impl FromStr for DatabaseBackend {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "mongo" => Ok(Self::Mongo),
+            "sql" => Ok(Self::Sql),
+            "memory" => Ok(Self::Memory),
+            _ => Err(String::from("expected mongo, sql or memory")),
+        }
+    }
+}
+
+/// Signing of the access tokens. There is no default secret, the jwt factory
+/// refuses to start without the keys of the chosen algorithm.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct JwtSettings {
+    pub algorithm: JwtAlgorithm,
+    /// Shared secret of HS256.
+    pub secret: Option<String>,
+    /// Path of the PEM encoded RSA private key of RS256.
+    pub private_key_path: Option<String>,
+    /// Path of the PEM encoded RSA public key of RS256.
+    pub public_key_path: Option<String>,
+    pub issuer: Option<String>,
+    pub audience: Option<String>,
+    pub expires_in_secs: u64,
+}
+
+impl Default for JwtSettings {
+    fn default() -> Self {
+        Self {
+            algorithm: JwtAlgorithm::default(),
+            secret: None,
+            private_key_path: None,
+            public_key_path: None,
+            issuer: None,
+            audience: None,
+            expires_in_secs: 3600,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum JwtAlgorithm {
+    #[default]
+    Hs256,
+    Rs256,
+}
+
+impl FromStr for JwtAlgorithm {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "HS256" => Ok(Self::Hs256),
+            "RS256" => Ok(Self::Rs256),
+            _ => Err(String::from("expected HS256 or RS256")),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct MailerConfig {
+    pub backend: MailerBackend,
+}
+
+/// Transport of the mails sent to the account owners.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MailerBackend {
+    /// Through the relay of the `[smtp]` section.
+    #[default]
+    Smtp,
+    /// Mails are kept in memory and never delivered.
+    Memory,
+}
+
+impl FromStr for MailerBackend {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "smtp" => Ok(Self::Smtp),
+            "memory" => Ok(Self::Memory),
+            _ => Err(String::from("expected smtp or memory")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct EmailVerificationConfig {
+    pub token_ttl_secs: u64,
+    /// Page the mailed link points to, the token is appended as the `token`
+    /// query param.
+    pub verify_url: String,
+    /// Minimum time between two verification mails to the same account.
+    pub cooldown_secs: u64,
+    /// Refuses logins to accounts whose email is not verified yet.
+    pub required: bool,
+}
+
+impl Default for EmailVerificationConfig {
+    fn default() -> Self {
+        Self {
+            token_ttl_secs: 24 * 60 * 60,
+            verify_url: String::from("http://localhost:8000/verify-email"),
+            cooldown_secs: 60,
+            required: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PasswordResetConfig {
+    pub token_ttl_secs: u64,
+    /// Page the mailed link points to, the token is appended as the `token`
+    /// query param.
+    pub reset_url: String,
+    /// Minimum time between two reset mails to the same account.
+    pub cooldown_secs: u64,
+}
+
+impl Default for PasswordResetConfig {
+    fn default() -> Self {
+        Self {
+            token_ttl_secs: 60 * 60,
+            reset_url: String::from("http://localhost:8000/reset-password"),
+            cooldown_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    /// Number of worker threads, defaults to the number of physical cores.
+    pub workers: Option<usize>,
+    pub keep_alive_secs: Option<u64>,
+    pub client_request_timeout_ms: Option<u64>,
+    pub client_disconnect_timeout_ms: Option<u64>,
+    /// Serves HTTPS with rustls when set.
+    pub tls: Option<TlsConfig>,
+}
+
+impl ServerConfig {
+    fn apply_env<F>(&mut self, env: &F) -> GenericResult<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(host) = env("SERVER_HOST") {
+            self.host = host;
+        }
+
+        if let Some(port) = parse_env(env, "SERVER_PORT")? {
+            self.port = port;
+        }
+
+        if let Some(workers) = parse_env(env, "SERVER_WORKERS")? {
+            self.workers = Some(workers);
+        }
+
+        if let Some(keep_alive_secs) = parse_env(env, "SERVER_KEEP_ALIVE_SECS")? {
+            self.keep_alive_secs = Some(keep_alive_secs);
+        }
+
+        if let Some(timeout_ms) = parse_env(env, "SERVER_CLIENT_REQUEST_TIMEOUT_MS")? {
+            self.client_request_timeout_ms = Some(timeout_ms);
+        }
+
+        if let Some(timeout_ms) = parse_env(env, "SERVER_CLIENT_DISCONNECT_TIMEOUT_MS")? {
+            self.client_disconnect_timeout_ms = Some(timeout_ms);
+        }
+
+        match (env("SERVER_TLS_CERT_PATH"), env("SERVER_TLS_KEY_PATH")) {
+            (Some(cert_path), Some(key_path)) => {
+                self.tls = Some(TlsConfig {
+                    cert_path,
+                    key_path,
+                });
+            }
+            (None, None) => {}
+            _ => return Err(ConfigError::IncompleteTlsEnv),
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            host: String::from("127.0.0.1"),
+            port: 8000,
+            workers: None,
+            keep_alive_secs: None,
+            client_request_timeout_ms: None,
+            client_disconnect_timeout_ms: None,
+            tls: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TlsConfig {
+    /// Path of the PEM encoded certificate chain.
+    pub cert_path: String,
+    /// Path of the PEM encoded PKCS#8 or RSA private key.
+    pub key_path: String,
+}
+
+fn parse_env<F, T>(env: &F, key: &str) -> GenericResult<Option<T>, ConfigError>
+where
+    F: Fn(&str) -> Option<String>,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    match env(key) {
+        Some(value) => match value.parse() {
+            Ok(value) => Ok(Some(value)),
+            Err(err) => Err(ConfigError::InvalidEnv {
+                key: String::from(key),
+                msg: err.to_string(),
+            }),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Splits a comma separated env value, skipping empty items.
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use log::LevelFilter;
+
+    use crate::infra::crypto::Argon2Config;
+    use crate::infra::db::{MongoConfig, SqlConfig};
+    use crate::infra::mail::SmtpConfig;
+    use crate::utils::{EmailNormalizerConfig, EmailPolicyConfig};
+    use crate::validation::validators::PasswordPolicy;
+
+    use super::{
+        Config, ConfigError, DatabaseBackend, EmailVerificationConfig, JwtAlgorithm, JwtSettings,
+        MailerBackend, PasswordResetConfig, ServerConfig, TlsConfig,
+    };
+
+    fn make_env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn uses_the_defaults_without_file_or_env() {
+        let config = Config::from_sources(None, make_env(&[])).unwrap();
+
+        assert_eq!(config, Config::default());
+        assert_eq!(config.mongo.database, "clean-rust-api");
+        assert_eq!(config.server.host, "127.0.0.1");
+        assert_eq!(config.server.port, 8000);
+    }
+
+    #[test]
+    fn reads_the_toml_file() {
+        let contents = r#"
+            [mongo]
+            uri = "mongodb://mongo:27017"
+            database = "any_database"
+            max_pool_size = 20
+            connect_timeout_ms = 500
+        "#;
+
+        let config = Config::from_sources(Some(contents), make_env(&[])).unwrap();
+
+        assert_eq!(
+            config.mongo,
+            MongoConfig {
+                uri: String::from("mongodb://mongo:27017"),
+                database: String::from("any_database"),
+                max_pool_size: Some(20),
+                connect_timeout_ms: Some(500),
+                ..MongoConfig::default()
+            }
+        );
+    }
+
+    #[test]
+    fn env_overrides_the_toml_file() {
+        let contents = r#"
+            [mongo]
+            uri = "mongodb://mongo:27017"
+            database = "any_database"
+        "#;
+
+        let env = make_env(&[
+            ("MONGO_DATABASE", "other_database"),
+            ("MONGO_MIN_POOL_SIZE", "2"),
+            ("MONGO_SERVER_SELECTION_TIMEOUT_MS", "1000"),
+        ]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(config.mongo.uri, "mongodb://mongo:27017");
+        assert_eq!(config.mongo.database, "other_database");
+        assert_eq!(config.mongo.min_pool_size, Some(2));
+        assert_eq!(config.mongo.server_selection_timeout_ms, Some(1000));
+    }
+
+    #[test]
+    fn returns_err_if_toml_file_is_invalid() {
+        let result = Config::from_sources(Some("[mongo"), make_env(&[]));
+
+        assert!(matches!(result.unwrap_err(), ConfigError::Parse(_)));
+    }
+
+    #[test]
+    fn returns_err_if_env_value_is_invalid() {
+        let env = make_env(&[("MONGO_MAX_POOL_SIZE", "many")]);
+
+        let result = Config::from_sources(None, env);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid value for MONGO_MAX_POOL_SIZE"));
+    }
+
+    #[test]
+    fn reads_the_server_section() {
+        let contents = r#"
+            [server]
+            host = "0.0.0.0"
+            port = 8443
+            workers = 4
+            keep_alive_secs = 75
+
+            [server.tls]
+            cert_path = "cert.pem"
+            key_path = "key.pem"
+        "#;
+
+        let config = Config::from_sources(Some(contents), make_env(&[])).unwrap();
+
+        assert_eq!(
+            config.server,
+            ServerConfig {
+                host: String::from("0.0.0.0"),
+                port: 8443,
+                workers: Some(4),
+                keep_alive_secs: Some(75),
+                tls: Some(TlsConfig {
+                    cert_path: String::from("cert.pem"),
+                    key_path: String::from("key.pem"),
+                }),
+                ..ServerConfig::default()
+            }
+        );
+    }
+
+    #[test]
+    fn env_overrides_the_server_section() {
+        let env = make_env(&[
+            ("SERVER_HOST", "0.0.0.0"),
+            ("SERVER_PORT", "80"),
+            ("SERVER_CLIENT_REQUEST_TIMEOUT_MS", "3000"),
+            ("SERVER_CLIENT_DISCONNECT_TIMEOUT_MS", "1000"),
+            ("SERVER_TLS_CERT_PATH", "cert.pem"),
+            ("SERVER_TLS_KEY_PATH", "key.pem"),
+        ]);
+
+        let config = Config::from_sources(None, env).unwrap();
+
+        assert_eq!(config.server.host, "0.0.0.0");
+        assert_eq!(config.server.port, 80);
+        assert_eq!(config.server.client_request_timeout_ms, Some(3000));
+        assert_eq!(config.server.client_disconnect_timeout_ms, Some(1000));
+        assert_eq!(
+            config.server.tls,
+            Some(TlsConfig {
+                cert_path: String::from("cert.pem"),
+                key_path: String::from("key.pem"),
+            })
+        );
+    }
+
+    #[test]
+    fn returns_err_if_only_one_tls_path_is_set() {
+        let env = make_env(&[("SERVER_TLS_CERT_PATH", "cert.pem")]);
+
+        let result = Config::from_sources(None, env);
+
+        assert!(matches!(result.unwrap_err(), ConfigError::IncompleteTlsEnv));
+    }
+
+    #[test]
+    fn reads_the_database_and_sql_sections() {
+        let contents = r#"
+            [database]
+            backend = "sql"
+
+            [sql]
+            url = "postgres://localhost/any_database"
+            max_connections = 5
+        "#;
+
+        let config = Config::from_sources(Some(contents), make_env(&[])).unwrap();
+
+        assert_eq!(config.database.backend, DatabaseBackend::Sql);
+        assert_eq!(
+            config.sql,
+            SqlConfig {
+                url: String::from("postgres://localhost/any_database"),
+                max_connections: Some(5),
+                ..SqlConfig::default()
+            }
+        );
+    }
+
+    #[test]
+    fn env_overrides_the_database_and_sql_sections() {
+        let env = make_env(&[
+            ("DATABASE_BACKEND", "memory"),
+            ("SQL_URL", "sqlite::memory:"),
+            ("SQL_CONNECT_TIMEOUT_MS", "1000"),
+        ]);
+
+        let config = Config::from_sources(None, env).unwrap();
+
+        assert_eq!(config.database.backend, DatabaseBackend::Memory);
+        assert_eq!(config.sql.url, "sqlite::memory:");
+        assert_eq!(config.sql.connect_timeout_ms, Some(1000));
+    }
+
+    #[test]
+    fn returns_err_if_database_backend_is_unknown() {
+        let env = make_env(&[("DATABASE_BACKEND", "redis")]);
+
+        let result = Config::from_sources(None, env);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid value for DATABASE_BACKEND: expected mongo, sql or memory"
+        );
+    }
+
+    #[test]
+    fn reads_the_password_policy_section() {
+        let contents = r#"
+            [password_policy]
+            min_length = 12
+            require_symbol = true
+        "#;
+
+        let config = Config::from_sources(Some(contents), make_env(&[])).unwrap();
+
+        assert_eq!(
+            config.password_policy,
+            PasswordPolicy {
+                min_length: 12,
+                require_symbol: true,
+                ..PasswordPolicy::default()
+            }
+        );
+    }
+
+    #[test]
+    fn env_overrides_the_password_policy_section() {
+        let env = make_env(&[
+            ("PASSWORD_MAX_LENGTH", "64"),
+            ("PASSWORD_REQUIRE_UPPERCASE", "false"),
+            ("PASSWORD_REJECT_COMMON", "false"),
+        ]);
+
+        let config = Config::from_sources(None, env).unwrap();
+
+        assert_eq!(
+            config.password_policy,
+            PasswordPolicy {
+                max_length: 64,
+                require_uppercase: false,
+                reject_common: false,
+                ..PasswordPolicy::default()
+            }
+        );
+    }
+
+    #[test]
+    fn reads_the_email_normalizer_section() {
+        let contents = r#"
+            [email_normalizer]
+            provider_rules = true
+        "#;
+
+        let env = make_env(&[("EMAIL_NORMALIZER_LOWERCASE_LOCAL_PART", "false")]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(
+            config.email_normalizer,
+            EmailNormalizerConfig {
+                lowercase_local_part: false,
+                provider_rules: true,
+            }
+        );
+    }
+
+    #[test]
+    fn reads_the_email_policy_section() {
+        let contents = r#"
+            [email_policy]
+            allowed_domains = ["company.com"]
+        "#;
+
+        let env = make_env(&[
+            ("EMAIL_POLICY_DENIED_DOMAINS", "spam.com, ,junk.com"),
+            ("EMAIL_POLICY_REJECT_DISPOSABLE", "false"),
+        ]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(
+            config.email_policy,
+            EmailPolicyConfig {
+                allowed_domains: vec![String::from("company.com")],
+                denied_domains: vec![String::from("spam.com"), String::from("junk.com")],
+                reject_disposable: false,
+            }
+        );
+    }
+
+    #[test]
+    fn reads_the_mailer_and_smtp_sections() {
+        let contents = r#"
+            [mailer]
+            backend = "smtp"
+
+            [smtp]
+            host = "smtp.mail.com"
+            port = 2525
+            from = "Any <no-reply@mail.com>"
+        "#;
+
+        let env = make_env(&[
+            ("SMTP_USERNAME", "any_username"),
+            ("SMTP_PASSWORD", "any_password"),
+            ("SMTP_STARTTLS", "false"),
+        ]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(config.mailer.backend, MailerBackend::Smtp);
+        assert_eq!(
+            config.smtp,
+            SmtpConfig {
+                host: String::from("smtp.mail.com"),
+                port: Some(2525),
+                username: Some(String::from("any_username")),
+                password: Some(String::from("any_password")),
+                from: String::from("Any <no-reply@mail.com>"),
+                starttls: false,
+            }
+        );
+    }
+
+    #[test]
+    fn returns_err_if_mailer_backend_is_unknown() {
+        let env = make_env(&[("MAILER_BACKEND", "pigeon")]);
+
+        let result = Config::from_sources(None, env);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid value for MAILER_BACKEND: expected smtp or memory"
+        );
+    }
+
+    #[test]
+    fn reads_the_email_verification_section() {
+        let contents = r#"
+            [email_verification]
+            token_ttl_secs = 3600
+        "#;
+
+        let env = make_env(&[
+            (
+                "EMAIL_VERIFICATION_VERIFY_URL",
+                "https://app.com/verify-email",
+            ),
+            ("EMAIL_VERIFICATION_COOLDOWN_SECS", "120"),
+            ("EMAIL_VERIFICATION_REQUIRED", "false"),
+        ]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(
+            config.email_verification,
+            EmailVerificationConfig {
+                token_ttl_secs: 3600,
+                verify_url: String::from("https://app.com/verify-email"),
+                cooldown_secs: 120,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn reads_the_password_reset_section() {
+        let contents = r#"
+            [password_reset]
+            reset_url = "https://app.com/reset-password"
+        "#;
+
+        let env = make_env(&[
+            ("PASSWORD_RESET_TOKEN_TTL_SECS", "900"),
+            ("PASSWORD_RESET_COOLDOWN_SECS", "30"),
+        ]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(
+            config.password_reset,
+            PasswordResetConfig {
+                token_ttl_secs: 900,
+                reset_url: String::from("https://app.com/reset-password"),
+                cooldown_secs: 30,
+            }
+        );
+    }
+
+    #[test]
+    fn env_overrides_the_argon2_section() {
+        let contents = r#"
+            [argon2]
+            memory_cost = 65536
+            time_cost = 3
+        "#;
+
+        let env = make_env(&[("ARGON2_TIME_COST", "4"), ("ARGON2_PARALLELISM", "2")]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(
+            config.argon2,
+            Argon2Config {
+                memory_cost: 65536,
+                time_cost: 4,
+                parallelism: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn reads_the_jwt_section() {
+        let contents = r#"
+            [jwt]
+            algorithm = "RS256"
+            private_key_path = "private.pem"
+            public_key_path = "public.pem"
+            issuer = "clean-rust-api"
+        "#;
+
+        let env = make_env(&[
+            ("JWT_AUDIENCE", "any_audience"),
+            ("JWT_EXPIRES_IN_SECS", "900"),
+        ]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(
+            config.jwt,
+            JwtSettings {
+                algorithm: JwtAlgorithm::Rs256,
+                secret: None,
+                private_key_path: Some(String::from("private.pem")),
+                public_key_path: Some(String::from("public.pem")),
+                issuer: Some(String::from("clean-rust-api")),
+                audience: Some(String::from("any_audience")),
+                expires_in_secs: 900,
+            }
+        );
+    }
+
+    #[test]
+    fn env_overrides_the_jwt_secret() {
+        let contents = r#"
+            [jwt]
+            secret = "file_secret"
+        "#;
+
+        let env = make_env(&[("JWT_ALGORITHM", "HS256"), ("JWT_SECRET", "env_secret")]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(config.jwt.algorithm, JwtAlgorithm::Hs256);
+        assert_eq!(config.jwt.secret.as_deref(), Some("env_secret"));
+        assert_eq!(config.jwt.expires_in_secs, 3600);
+    }
+
+    #[test]
+    fn returns_err_if_jwt_algorithm_is_unknown() {
+        let env = make_env(&[("JWT_ALGORITHM", "none")]);
+
+        let result = Config::from_sources(None, env);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid value for JWT_ALGORITHM: expected HS256 or RS256"
+        );
+    }
+
+    #[test]
+    fn reads_the_log_section() {
+        let contents = r#"
+            [log]
+            level = "info"
+        "#;
+
+        let config = Config::from_sources(Some(contents), make_env(&[])).unwrap();
+
+        assert_eq!(config.log.level, LevelFilter::Info);
+
+        let env = make_env(&[("LOG_LEVEL", "debug")]);
+
+        let config = Config::from_sources(Some(contents), env).unwrap();
+
+        assert_eq!(config.log.level, LevelFilter::Debug);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::app::config::{Config, DatabaseBackend};
+use crate::infra::db::protocols::{AccountRepository, VerificationTokenRepository};
+use crate::infra::db::{
+    AccountInMemoryRepository, AccountMongoRepository, AccountSqlRepository, MongoHelper,
+    SqlHelper, VerificationTokenInMemoryRepository, VerificationTokenMongoRepository,
+    VerificationTokenSqlRepository,
+};
+use crate::GenericResult;
+
+/// Connection to the storage picked by the `[database]` config, shared by the
+/// repositories the factories build.
+#[derive(Clone)]
+pub enum Database {
+    Mongo(MongoHelper),
+    Sql(SqlHelper),
+    Memory(
+        AccountInMemoryRepository,
+        VerificationTokenInMemoryRepository,
+    ),
+}
+
+impl Database {
+    /// Connects to the configured backend and prepares it, creating the mongo
+    /// indexes or running the sql migrations.
+    pub async fn connect(config: &Config) -> GenericResult<Self> {
+        let database = match config.database.backend {
+            DatabaseBackend::Mongo => Self::Mongo(MongoHelper::connect(&config.mongo).await?),
+            DatabaseBackend::Sql => Self::Sql(SqlHelper::connect(&config.sql).await?),
+            DatabaseBackend::Memory => Self::Memory(
+                AccountInMemoryRepository::new(),
+                VerificationTokenInMemoryRepository::new(),
+            ),
+        };
+
+        database.account_repository().create_indexes().await?;
+        database
+            .verification_token_repository()
+            .create_indexes()
+            .await?;
+
+        Ok(database)
+    }
+
+    pub fn account_repository(&self) -> Box<dyn AccountRepository> {
+        match self {
+            Self::Mongo(mongo) => Box::new(AccountMongoRepository::new(mongo.clone())),
+            Self::Sql(sql) => Box::new(AccountSqlRepository::new(sql.clone())),
+            Self::Memory(repository, _) => Box::new(repository.clone()),
+        }
+    }
+
+    pub fn verification_token_repository(&self) -> Box<dyn VerificationTokenRepository> {
+        match self {
+            Self::Mongo(mongo) => Box::new(VerificationTokenMongoRepository::new(mongo.clone())),
+            Self::Sql(sql) => Box::new(VerificationTokenSqlRepository::new(sql.clone())),
+            Self::Memory(_, repository) => Box::new(repository.clone()),
+        }
+    }
+
+    pub async fn disconnect(&self) {
+        match self {
+            Self::Mongo(mongo) => mongo.disconnect().await,
+            Self::Sql(sql) => sql.disconnect().await,
+            Self::Memory(..) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::app::config::{Config, DatabaseBackend};
+    use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+    use crate::domain::usecases::AddAccountDto;
+    use crate::infra::db::SqlConfig;
+
+    use super::Database;
+
+    fn make_config(backend: DatabaseBackend) -> Config {
+        let mut config = Config::default();
+        config.database.backend = backend;
+        config.sql = SqlConfig {
+            url: String::from("sqlite::memory:"),
+            max_connections: Some(1),
+            ..SqlConfig::default()
+        };
+
+        config
+    }
+
+    fn make_account_dto() -> AddAccountDto {
+        AddAccountDto {
+            name: String::from("any_name"),
+            email: String::from("any_email@mail.com"),
+            password: String::from("hashed_password"),
+        }
+    }
+
+    #[tokio::test]
+    async fn connects_to_the_configured_backend() {
+        let sut = Database::connect(&make_config(DatabaseBackend::Sql))
+            .await
+            .unwrap();
+        assert!(matches!(sut, Database::Sql(_)));
+
+        let sut = Database::connect(&make_config(DatabaseBackend::Memory))
+            .await
+            .unwrap();
+        assert!(matches!(sut, Database::Memory(..)));
+    }
+
+    #[tokio::test]
+    async fn repositories_share_the_connection() {
+        for backend in [DatabaseBackend::Sql, DatabaseBackend::Memory] {
+            let sut = Database::connect(&make_config(backend)).await.unwrap();
+
+            sut.account_repository()
+                .add(make_account_dto())
+                .await
+                .unwrap();
+            let account = sut
+                .account_repository()
+                .load_by_email("any_email@mail.com")
+                .await
+                .unwrap();
+
+            assert!(account.is_some());
+
+            let token = VerificationTokenEntity::new(
+                account.unwrap().id(),
+                "hashed_token",
+                TokenPurpose::EmailVerification,
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            );
+            sut.verification_token_repository()
+                .add(token)
+                .await
+                .unwrap();
+            let token = sut
+                .verification_token_repository()
+                .consume(TokenPurpose::EmailVerification, "hashed_token")
+                .await
+                .unwrap();
+
+            assert!(token.is_some());
+        }
+    }
+}
@@ -0,0 +1,34 @@
+pub mod argon2;
+pub mod auth_middleware;
+pub mod change_password_validation;
+pub mod duration;
+pub mod jwt;
+pub mod login;
+pub mod mailer;
+pub mod password;
+pub mod reset_password_validation;
+pub mod send_email_verification;
+pub mod signup;
+pub mod signup_validation;
+pub mod verification_token;
+pub mod verify_email;
+
+pub use argon2::make_argon2_adapter;
+pub use auth_middleware::make_auth_middleware;
+pub use change_password_validation::make_change_password_validation;
+pub use duration::make_duration;
+pub use jwt::make_jwt_adapter;
+pub use login::make_login_controller;
+pub use mailer::make_mailer;
+pub use password::{
+    make_change_password_controller, make_forgot_password_controller,
+    make_reset_password_controller,
+};
+pub use reset_password_validation::make_reset_password_validation;
+pub use send_email_verification::make_send_email_verification;
+pub use signup::make_signup_controller;
+pub use signup_validation::make_signup_validation;
+pub use verification_token::{
+    make_email_verification_mail, make_issue_verification_token, make_password_reset_mail,
+};
+pub use verify_email::{make_resend_email_verification_controller, make_verify_email_controller};
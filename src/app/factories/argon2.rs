@@ -0,0 +1,9 @@
+use crate::app::config::Config;
+use crate::infra::crypto::Argon2Adapter;
+use crate::GenericResult;
+
+/// Argon2 adapter hashing with the `[argon2]` costs, shared by the factories
+/// hashing and comparing passwords.
+pub fn make_argon2_adapter(config: &Config) -> GenericResult<Argon2Adapter> {
+    Argon2Adapter::new(config.argon2)
+}
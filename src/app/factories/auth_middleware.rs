@@ -0,0 +1,16 @@
+use crate::app::config::Config;
+use crate::app::database::Database;
+use crate::data::usecases::DbLoadAccountByToken;
+use crate::presentation::middlewares::AuthMiddleware;
+use crate::GenericResult;
+
+use super::make_jwt_adapter;
+
+pub fn make_auth_middleware(database: &Database, config: &Config) -> GenericResult<AuthMiddleware> {
+    let load_account_by_token = Box::new(DbLoadAccountByToken::new(
+        Box::new(make_jwt_adapter(config)?),
+        database.account_repository(),
+    ));
+
+    Ok(AuthMiddleware::new(load_account_by_token))
+}
@@ -0,0 +1,31 @@
+use chrono::Duration;
+
+use crate::GenericResult;
+
+/// Duration of `secs` seconds read from the `setting` named in the error, so
+/// out of range settings fail at startup instead of wrapping around.
+pub fn make_duration(secs: u64, setting: &str) -> GenericResult<Duration> {
+    Duration::from_std(std::time::Duration::from_secs(secs))
+        .map_err(|_| format!("{} is out of range: {} seconds", setting, secs).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::make_duration;
+
+    #[test]
+    fn converts_the_seconds() {
+        let duration = make_duration(90, "ANY_SECS").unwrap();
+
+        assert_eq!(duration, Duration::seconds(90));
+    }
+
+    #[test]
+    fn returns_err_naming_the_setting_if_out_of_range() {
+        let err = make_duration(u64::MAX, "ANY_SECS").unwrap_err();
+
+        assert!(err.to_string().contains("ANY_SECS"));
+    }
+}
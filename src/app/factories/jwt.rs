@@ -0,0 +1,107 @@
+use std::fs;
+
+use crate::app::config::{Config, JwtAlgorithm};
+use crate::infra::crypto::{JwtAdapter, JwtConfig, JwtKeys};
+use crate::GenericResult;
+
+use super::make_duration;
+
+/// Jwt adapter signing with the keys of the `[jwt]` section, shared by the
+/// factories issuing and verifying access tokens. Fails when the keys of the
+/// chosen algorithm are missing, tokens signed with a guessable secret could
+/// be forged.
+pub fn make_jwt_adapter(config: &Config) -> GenericResult<JwtAdapter> {
+    let jwt = &config.jwt;
+
+    let keys = match jwt.algorithm {
+        JwtAlgorithm::Hs256 => match jwt.secret.as_deref() {
+            Some(secret) if !secret.is_empty() => JwtKeys::Hs256 {
+                secret: String::from(secret),
+            },
+            _ => return Err("JWT_SECRET or [jwt] secret must be set to a non-empty secret".into()),
+        },
+        JwtAlgorithm::Rs256 => match (&jwt.private_key_path, &jwt.public_key_path) {
+            (Some(private_key_path), Some(public_key_path)) => JwtKeys::Rs256 {
+                private_key_pem: fs::read_to_string(private_key_path)?,
+                public_key_pem: fs::read_to_string(public_key_path)?,
+            },
+            _ => {
+                return Err("JWT_PRIVATE_KEY_PATH and JWT_PUBLIC_KEY_PATH (or [jwt] \
+                     private_key_path and public_key_path) must be set for RS256"
+                    .into())
+            }
+        },
+    };
+
+    JwtAdapter::new(JwtConfig {
+        keys,
+        issuer: jwt.issuer.clone(),
+        audience: jwt.audience.clone(),
+        expires_in: make_duration(
+            jwt.expires_in_secs,
+            "JWT_EXPIRES_IN_SECS or [jwt] expires_in_secs",
+        )?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::config::{Config, JwtAlgorithm};
+
+    use super::make_jwt_adapter;
+
+    fn make_config(secret: Option<&str>) -> Config {
+        let mut config = Config::default();
+        config.jwt.secret = secret.map(String::from);
+        config
+    }
+
+    #[test]
+    fn returns_err_if_secret_is_missing() {
+        let result = make_jwt_adapter(&make_config(None));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn names_both_ways_to_set_the_secret() {
+        let err = make_jwt_adapter(&make_config(None)).err().unwrap();
+
+        assert!(err.to_string().contains("JWT_SECRET"));
+        assert!(err.to_string().contains("[jwt] secret"));
+    }
+
+    #[test]
+    fn returns_err_if_secret_is_empty() {
+        let result = make_jwt_adapter(&make_config(Some("")));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn returns_err_if_rs256_key_paths_are_missing() {
+        let mut config = make_config(Some("any_secret"));
+        config.jwt.algorithm = JwtAlgorithm::Rs256;
+
+        let result = make_jwt_adapter(&config);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn returns_err_if_expires_in_is_out_of_range() {
+        let mut config = make_config(Some("any_secret"));
+        config.jwt.expires_in_secs = u64::MAX;
+
+        let result = make_jwt_adapter(&config);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn returns_adapter_if_secret_is_set() {
+        let result = make_jwt_adapter(&make_config(Some("any_secret")));
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::app::config::Config;
+use crate::app::database::Database;
+use crate::data::usecases::DbAuthentication;
+use crate::presentation::controllers::LoginController;
+use crate::utils::{EmailNormalizerAdapter, EmailValidatorAdapter};
+use crate::GenericResult;
+
+use super::{make_argon2_adapter, make_jwt_adapter};
+
+pub fn make_login_controller(
+    database: &Database,
+    config: &Config,
+) -> GenericResult<LoginController> {
+    let load_account_by_email_repository = database.account_repository();
+    let hash_comparer = Box::new(make_argon2_adapter(config)?);
+    let token_generator = Box::new(make_jwt_adapter(config)?);
+    let authentication = Box::new(DbAuthentication::new(
+        config.email_verification.required,
+        load_account_by_email_repository,
+        hash_comparer,
+        token_generator,
+    ));
+    let email_normalizer = Box::new(EmailNormalizerAdapter::new(config.email_normalizer.clone()));
+    let email_validator = Box::new(EmailValidatorAdapter::new());
+
+    Ok(LoginController::new(
+        email_normalizer,
+        email_validator,
+        authentication,
+    ))
+}
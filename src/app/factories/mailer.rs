@@ -0,0 +1,13 @@
+use crate::app::config::{Config, MailerBackend};
+use crate::data::protocols::Mailer;
+use crate::infra::mail::{InMemoryMailer, SmtpMailer};
+use crate::GenericResult;
+
+pub fn make_mailer(config: &Config) -> GenericResult<Box<dyn Mailer>> {
+    let mailer: Box<dyn Mailer> = match config.mailer.backend {
+        MailerBackend::Smtp => Box::new(SmtpMailer::new(&config.smtp)?),
+        MailerBackend::Memory => Box::new(InMemoryMailer::default()),
+    };
+
+    Ok(mailer)
+}
@@ -0,0 +1,71 @@
+use crate::app::config::Config;
+use crate::app::database::Database;
+use crate::data::usecases::{DbChangePassword, DbRequestPasswordReset, DbResetPassword};
+use crate::infra::clock::SystemClock;
+use crate::infra::crypto::Sha2Adapter;
+use crate::presentation::controllers::{
+    ChangePasswordController, ForgotPasswordController, ResetPasswordController,
+};
+use crate::utils::{EmailNormalizerAdapter, EmailValidatorAdapter};
+use crate::GenericResult;
+
+use super::{
+    make_argon2_adapter, make_change_password_validation, make_issue_verification_token,
+    make_jwt_adapter, make_password_reset_mail, make_reset_password_validation,
+};
+
+pub fn make_forgot_password_controller(
+    database: &Database,
+    config: &Config,
+) -> GenericResult<ForgotPasswordController> {
+    let mail = make_password_reset_mail(config)?;
+    let request_password_reset = Box::new(DbRequestPasswordReset::new(
+        database.account_repository(),
+        Box::new(make_issue_verification_token(database, config, mail)?),
+    ));
+    let email_normalizer = Box::new(EmailNormalizerAdapter::new(config.email_normalizer.clone()));
+    let email_validator = Box::new(EmailValidatorAdapter::new());
+
+    Ok(ForgotPasswordController::new(
+        email_normalizer,
+        email_validator,
+        request_password_reset,
+    ))
+}
+
+pub fn make_reset_password_controller(
+    database: &Database,
+    config: &Config,
+) -> GenericResult<ResetPasswordController> {
+    let reset_password = Box::new(DbResetPassword::new(
+        config.password_policy.reject_personal_info,
+        Box::new(Sha2Adapter::new()),
+        database.verification_token_repository(),
+        database.account_repository(),
+        Box::new(make_argon2_adapter(config)?),
+        database.verification_token_repository(),
+        database.account_repository(),
+        Box::new(SystemClock),
+    ));
+    let validation = Box::new(make_reset_password_validation(config));
+
+    Ok(ResetPasswordController::new(validation, reset_password))
+}
+
+pub fn make_change_password_controller(
+    database: &Database,
+    config: &Config,
+) -> GenericResult<ChangePasswordController> {
+    let change_password = Box::new(DbChangePassword::new(
+        config.password_policy.reject_personal_info,
+        database.account_repository(),
+        Box::new(make_argon2_adapter(config)?),
+        Box::new(make_argon2_adapter(config)?),
+        database.account_repository(),
+        Box::new(make_jwt_adapter(config)?),
+        Box::new(SystemClock),
+    ));
+    let validation = Box::new(make_change_password_validation(config));
+
+    Ok(ChangePasswordController::new(validation, change_password))
+}
@@ -0,0 +1,91 @@
+use crate::app::config::Config;
+use crate::presentation::protocols::Validation;
+use crate::validation::validators::{
+    CompareFieldsValidation, PasswordPolicyValidation, RequiredFieldValidation, ValidationComposite,
+};
+
+pub fn make_reset_password_validation(config: &Config) -> ValidationComposite {
+    let mut validations: Vec<Box<dyn Validation>> = Vec::new();
+
+    for field in ["token", "password", "password_confirmation"] {
+        validations.push(Box::new(RequiredFieldValidation::new(field)));
+    }
+
+    validations.push(Box::new(PasswordPolicyValidation::new(
+        "password",
+        &[],
+        config.password_policy.clone(),
+    )));
+    validations.push(Box::new(CompareFieldsValidation::new(
+        "password_confirmation",
+        "password",
+    )));
+
+    ValidationComposite::new(validations)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::config::Config;
+    use crate::presentation::controllers::ResetPasswordReqBodyBuilder;
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::Validation;
+    use crate::validation::validators::ValidationComposite;
+
+    use super::make_reset_password_validation;
+
+    fn make_sut() -> ValidationComposite {
+        make_reset_password_validation(&Config::default())
+    }
+
+    #[test]
+    fn accepts_a_valid_body() {
+        let body = ResetPasswordReqBodyBuilder::new()
+            .set_token("any_token")
+            .set_password("Any_passw0rd")
+            .set_password_confirmation("Any_passw0rd")
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn reports_every_field_at_once() {
+        let body = ResetPasswordReqBodyBuilder::new()
+            .set_password("Any_passw0rd")
+            .set_password_confirmation("Other_passw0rd")
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                FieldError::new("token", "missing param"),
+                FieldError::new("password_confirmation", "invalid param"),
+            ]
+        );
+    }
+
+    #[test]
+    fn enforces_the_password_policy() {
+        let body = ResetPasswordReqBodyBuilder::new()
+            .set_token("any_token")
+            .set_password("password")
+            .set_password_confirmation("password")
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                FieldError::new("password", "missing uppercase letter"),
+                FieldError::new("password", "missing digit"),
+                FieldError::new("password", "too common"),
+            ]
+        );
+    }
+}
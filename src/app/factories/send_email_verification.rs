@@ -0,0 +1,18 @@
+use crate::app::config::Config;
+use crate::app::database::Database;
+use crate::data::usecases::DbSendEmailVerification;
+use crate::GenericResult;
+
+use super::{make_email_verification_mail, make_issue_verification_token};
+
+pub fn make_send_email_verification(
+    database: &Database,
+    config: &Config,
+) -> GenericResult<DbSendEmailVerification> {
+    let mail = make_email_verification_mail(config)?;
+
+    Ok(DbSendEmailVerification::new(
+        database.account_repository(),
+        Box::new(make_issue_verification_token(database, config, mail)?),
+    ))
+}
@@ -0,0 +1,32 @@
+use crate::app::config::Config;
+use crate::app::database::Database;
+use crate::data::usecases::DbAddAccount;
+use crate::presentation::controllers::SignUpController;
+use crate::utils::EmailNormalizerAdapter;
+use crate::GenericResult;
+
+use super::{make_argon2_adapter, make_send_email_verification, make_signup_validation};
+
+pub fn make_signup_controller(
+    database: &Database,
+    config: &Config,
+) -> GenericResult<SignUpController> {
+    let encrypter = Box::new(make_argon2_adapter(config)?);
+    let add_account_repository = database.account_repository();
+    let load_account_by_email_repository = database.account_repository();
+    let add_account = Box::new(DbAddAccount::new(
+        encrypter,
+        add_account_repository,
+        load_account_by_email_repository,
+    ));
+    let email_normalizer = Box::new(EmailNormalizerAdapter::new(config.email_normalizer.clone()));
+    let validation = Box::new(make_signup_validation(config));
+    let send_email_verification = Box::new(make_send_email_verification(database, config)?);
+
+    Ok(SignUpController::new(
+        email_normalizer,
+        validation,
+        add_account,
+        send_email_verification,
+    ))
+}
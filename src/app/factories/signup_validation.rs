@@ -0,0 +1,148 @@
+use crate::app::config::Config;
+use crate::presentation::protocols::Validation;
+use crate::utils::EmailPolicyValidator;
+use crate::validation::validators::{
+    CompareFieldsValidation, EmailValidation, MaxLengthValidation, PasswordPolicyValidation,
+    RequiredFieldValidation, ValidationComposite,
+};
+
+pub const MAX_NAME_LENGTH: usize = 100;
+pub const MAX_EMAIL_LENGTH: usize = 254;
+
+pub fn make_signup_validation(config: &Config) -> ValidationComposite {
+    let mut validations: Vec<Box<dyn Validation>> = Vec::new();
+
+    for field in ["name", "email", "password", "password_confirmation"] {
+        validations.push(Box::new(RequiredFieldValidation::new(field)));
+    }
+
+    validations.push(Box::new(MaxLengthValidation::new("name", MAX_NAME_LENGTH)));
+    validations.push(Box::new(MaxLengthValidation::new(
+        "email",
+        MAX_EMAIL_LENGTH,
+    )));
+    validations.push(Box::new(EmailValidation::new(
+        "email",
+        Box::new(EmailPolicyValidator::new(config.email_policy.clone())),
+    )));
+    validations.push(Box::new(PasswordPolicyValidation::new(
+        "password",
+        &["name", "email"],
+        config.password_policy.clone(),
+    )));
+    validations.push(Box::new(CompareFieldsValidation::new(
+        "password_confirmation",
+        "password",
+    )));
+
+    ValidationComposite::new(validations)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presentation::controllers::SignUpReqBodyBuilder;
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::Validation;
+    use crate::validation::validators::ValidationComposite;
+
+    use crate::app::config::Config;
+    use crate::validation::validators::PasswordPolicy;
+
+    use super::{make_signup_validation, MAX_EMAIL_LENGTH, MAX_NAME_LENGTH};
+
+    fn make_sut() -> ValidationComposite {
+        make_signup_validation(&Config::default())
+    }
+
+    #[test]
+    fn accepts_a_valid_body() {
+        let body = SignUpReqBodyBuilder::new()
+            .set_name("any_name")
+            .set_email("any_email@mail.com")
+            .set_password("Any_passw0rd")
+            .set_password_confirmation("Any_passw0rd")
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn reports_every_field_at_once() {
+        let body = SignUpReqBodyBuilder::new()
+            .set_email("invalid_email")
+            .set_password("Any_passw0rd")
+            .set_password_confirmation("Other_passw0rd")
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                FieldError::new("name", "missing param"),
+                FieldError::new("email", "invalid param"),
+                FieldError::new("password_confirmation", "invalid param"),
+            ]
+        );
+    }
+
+    #[test]
+    fn enforces_the_password_policy() {
+        let body = SignUpReqBodyBuilder::new()
+            .set_name("any_name")
+            .set_email("any_email@mail.com")
+            .set_password("any_name")
+            .set_password_confirmation("any_name")
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                FieldError::new("password", "missing uppercase letter"),
+                FieldError::new("password", "missing digit"),
+                FieldError::new("password", "contains personal info"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_disposable_emails() {
+        let body = SignUpReqBodyBuilder::new()
+            .set_name("any_name")
+            .set_email("any_email@mailinator.com")
+            .set_password("Any_passw0rd")
+            .set_password_confirmation("Any_passw0rd")
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("email", "disposable domain")]);
+    }
+
+    #[test]
+    fn limits_the_length_of_the_fields() {
+        let password = format!("Aa1{}", "a".repeat(PasswordPolicy::default().max_length));
+
+        let body = SignUpReqBodyBuilder::new()
+            .set_name(&"a".repeat(MAX_NAME_LENGTH + 1))
+            .set_email(&format!("{}@mail.com", "a".repeat(MAX_EMAIL_LENGTH)))
+            .set_password(&password)
+            .set_password_confirmation(&password)
+            .build();
+
+        let errors = make_sut().validate(&body).unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                FieldError::new("name", "too long"),
+                FieldError::new("email", "too long"),
+                FieldError::new("password", "too long"),
+            ]
+        );
+    }
+}
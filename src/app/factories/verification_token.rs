@@ -0,0 +1,136 @@
+use crate::app::config::Config;
+use crate::app::database::Database;
+use crate::data::usecases::{DbIssueVerificationToken, VerificationTokenMail};
+use crate::domain::entities::TokenPurpose;
+use crate::infra::clock::SystemClock;
+use crate::infra::crypto::{RandomTokenAdapter, Sha2Adapter};
+use crate::GenericResult;
+
+use super::{make_duration, make_mailer};
+
+const EMAIL_VERIFICATION_TEMPLATE: &str = "Hi {name},\n\n\
+     Confirm your email by opening the link below:\n\n\
+     {link}\n\n\
+     The link expires in {minutes} minutes and can only be used once.\n";
+
+const PASSWORD_RESET_TEMPLATE: &str = "Hi {name},\n\n\
+     Choose a new password by opening the link below:\n\n\
+     {link}\n\n\
+     The link expires in {minutes} minutes and can only be used once. \
+     If you did not ask for a new password, ignore this mail.\n";
+
+pub fn make_email_verification_mail(config: &Config) -> GenericResult<VerificationTokenMail> {
+    let email_verification = &config.email_verification;
+
+    Ok(VerificationTokenMail {
+        purpose: TokenPurpose::EmailVerification,
+        ttl: make_duration(
+            email_verification.token_ttl_secs,
+            "EMAIL_VERIFICATION_TOKEN_TTL_SECS or [email_verification] token_ttl_secs",
+        )?,
+        cooldown: make_duration(
+            email_verification.cooldown_secs,
+            "EMAIL_VERIFICATION_COOLDOWN_SECS or [email_verification] cooldown_secs",
+        )?,
+        link_url: email_verification.verify_url.clone(),
+        subject: String::from("Verify your email"),
+        template: String::from(EMAIL_VERIFICATION_TEMPLATE),
+    })
+}
+
+pub fn make_password_reset_mail(config: &Config) -> GenericResult<VerificationTokenMail> {
+    let password_reset = &config.password_reset;
+
+    Ok(VerificationTokenMail {
+        purpose: TokenPurpose::PasswordReset,
+        ttl: make_duration(
+            password_reset.token_ttl_secs,
+            "PASSWORD_RESET_TOKEN_TTL_SECS or [password_reset] token_ttl_secs",
+        )?,
+        cooldown: make_duration(
+            password_reset.cooldown_secs,
+            "PASSWORD_RESET_COOLDOWN_SECS or [password_reset] cooldown_secs",
+        )?,
+        link_url: password_reset.reset_url.clone(),
+        subject: String::from("Reset your password"),
+        template: String::from(PASSWORD_RESET_TEMPLATE),
+    })
+}
+
+pub fn make_issue_verification_token(
+    database: &Database,
+    config: &Config,
+    mail: VerificationTokenMail,
+) -> GenericResult<DbIssueVerificationToken> {
+    Ok(DbIssueVerificationToken::new(
+        mail,
+        database.verification_token_repository(),
+        Box::new(RandomTokenAdapter::new()),
+        Box::new(Sha2Adapter::new()),
+        database.verification_token_repository(),
+        make_mailer(config)?,
+        Box::new(SystemClock),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::app::config::Config;
+
+    use super::{make_email_verification_mail, make_password_reset_mail};
+
+    #[test]
+    fn places_the_link_and_its_lifetime_in_both_mails() {
+        for mail in [
+            make_email_verification_mail(&Config::default()).unwrap(),
+            make_password_reset_mail(&Config::default()).unwrap(),
+        ] {
+            assert!(mail.template.contains("{link}"));
+            assert!(mail.template.contains("{minutes}"));
+        }
+    }
+
+    #[test]
+    fn reads_the_token_lifetimes_from_config() {
+        let mut config = Config::default();
+        config.email_verification.token_ttl_secs = 60;
+        config.password_reset.token_ttl_secs = 120;
+
+        assert_eq!(
+            make_email_verification_mail(&config).unwrap().ttl,
+            Duration::minutes(1)
+        );
+        assert_eq!(
+            make_password_reset_mail(&config).unwrap().ttl,
+            Duration::minutes(2)
+        );
+    }
+
+    #[test]
+    fn returns_err_if_a_lifetime_is_out_of_range() {
+        let mut config = Config::default();
+        config.email_verification.cooldown_secs = u64::MAX;
+        config.password_reset.token_ttl_secs = u64::MAX;
+
+        assert!(make_email_verification_mail(&config).is_err());
+        assert!(make_password_reset_mail(&config).is_err());
+    }
+
+    #[test]
+    fn reads_the_cooldowns_from_config() {
+        let mut config = Config::default();
+        config.email_verification.cooldown_secs = 30;
+        config.password_reset.cooldown_secs = 90;
+
+        assert_eq!(
+            make_email_verification_mail(&config).unwrap().cooldown,
+            Duration::seconds(30)
+        );
+        assert_eq!(
+            make_password_reset_mail(&config).unwrap().cooldown,
+            Duration::seconds(90)
+        );
+    }
+}
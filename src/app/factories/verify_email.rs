@@ -0,0 +1,36 @@
+use crate::app::config::Config;
+use crate::app::database::Database;
+use crate::data::usecases::DbVerifyEmail;
+use crate::infra::clock::SystemClock;
+use crate::infra::crypto::Sha2Adapter;
+use crate::presentation::controllers::{ResendEmailVerificationController, VerifyEmailController};
+use crate::utils::{EmailNormalizerAdapter, EmailValidatorAdapter};
+use crate::GenericResult;
+
+use super::make_send_email_verification;
+
+pub fn make_verify_email_controller(database: &Database) -> VerifyEmailController {
+    let verify_email = Box::new(DbVerifyEmail::new(
+        Box::new(Sha2Adapter::new()),
+        database.verification_token_repository(),
+        database.account_repository(),
+        Box::new(SystemClock),
+    ));
+
+    VerifyEmailController::new(verify_email)
+}
+
+pub fn make_resend_email_verification_controller(
+    database: &Database,
+    config: &Config,
+) -> GenericResult<ResendEmailVerificationController> {
+    let email_normalizer = Box::new(EmailNormalizerAdapter::new(config.email_normalizer.clone()));
+    let email_validator = Box::new(EmailValidatorAdapter::new());
+    let send_email_verification = Box::new(make_send_email_verification(database, config)?);
+
+    Ok(ResendEmailVerificationController::new(
+        email_normalizer,
+        email_validator,
+        send_email_verification,
+    ))
+}
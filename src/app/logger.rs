@@ -0,0 +1,34 @@
+use chrono::{SecondsFormat, Utc};
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+/// Writes the log records to stderr, one line each.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{} {:<5} {}: {}",
+                Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the stderr logger, dropping the records above `level`. Fails if a
+/// logger is already installed.
+pub fn init_logger(level: LevelFilter) -> Result<(), SetLoggerError> {
+    log::set_logger(&StderrLogger)?;
+    log::set_max_level(level);
+
+    Ok(())
+}
@@ -1,14 +1,26 @@
 use actix_web::web::{self, ServiceConfig};
 
+use self::routes::account::setup_account_routes;
+use self::routes::login::setup_login_routes;
+use self::routes::password::setup_password_routes;
 use self::routes::signup::setup_signup_routes;
+use self::routes::verify_email::setup_verify_email_routes;
 
+pub mod adapters;
+pub mod config;
+pub mod database;
+pub mod factories;
+pub mod logger;
 pub mod routes;
+pub mod server;
 
 pub fn setup_app(cfg: &mut ServiceConfig) {
-    set_scope_api(cfg);
-    cfg.configure(setup_signup_routes);
-}
-
-pub(crate) fn set_scope_api(cfg: &mut ServiceConfig) {
-    cfg.service(web::scope("/api"));
+    cfg.service(
+        web::scope("/api")
+            .configure(setup_signup_routes)
+            .configure(setup_login_routes)
+            .configure(setup_verify_email_routes)
+            .configure(setup_password_routes)
+            .configure(setup_account_routes),
+    );
 }
@@ -1 +1,5 @@
+pub mod account;
+pub mod login;
+pub mod password;
 pub mod signup;
+pub mod verify_email;
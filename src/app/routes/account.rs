@@ -0,0 +1,168 @@
+use actix_web::web::{self, ServiceConfig};
+
+use crate::app::adapters::adapt_protected_route;
+use crate::presentation::controllers::{
+    ChangePasswordController, ChangePasswordReqBody, ChangePasswordResBody,
+};
+
+pub fn setup_account_routes(cfg: &mut ServiceConfig) {
+    cfg.route(
+        "/account/password",
+        adapt_protected_route::<
+            ChangePasswordController,
+            ChangePasswordReqBody,
+            ChangePasswordResBody,
+        >(web::put()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{dev::Service, test, web};
+    use actix_web::{http, App};
+    use chrono::{DateTime, Utc};
+    use serde_json::{json, Value};
+
+    use crate::app;
+    use crate::app::config::Config;
+    use crate::app::factories::make_change_password_validation;
+    use crate::data::protocols::{
+        AddAccountRepository, Encrypter, LoadAccountByEmailRepository, MockClock, TokenGenerator,
+    };
+    use crate::data::usecases::{DbChangePassword, DbLoadAccountByToken};
+    use crate::domain::usecases::AddAccountDto;
+    use crate::infra::crypto::{JwtAdapter, JwtConfig, Sha2Adapter};
+    use crate::infra::db::AccountInMemoryRepository;
+    use crate::presentation::controllers::{
+        ChangePasswordController, ChangePasswordReqBodyBuilder,
+    };
+    use crate::presentation::middlewares::AuthMiddleware;
+
+    /// Clock frozen at `now`, so tokens and the password change all share the
+    /// same second.
+    fn make_clock(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::default();
+        clock.expect_now().returning(move || now);
+        clock
+    }
+
+    fn make_jwt(now: DateTime<Utc>) -> JwtAdapter {
+        JwtAdapter::with_clock(JwtConfig::hs256("secret"), Arc::new(make_clock(now))).unwrap()
+    }
+
+    fn make_req(authorization: Option<&str>, current_password: &str) -> test::TestRequest {
+        let req_data = ChangePasswordReqBodyBuilder::new()
+            .set_current_password(current_password)
+            .set_password("New_passw0rd")
+            .set_password_confirmation("New_passw0rd")
+            .build();
+
+        let req = test::TestRequest::put()
+            .uri("/api/account/password")
+            .set_json(req_data);
+
+        match authorization {
+            Some(authorization) => req.insert_header(("authorization", authorization)),
+            None => req,
+        }
+    }
+
+    #[actix_web::test]
+    async fn changes_the_password_and_revokes_older_tokens() {
+        let now = Utc::now();
+        let accounts = AccountInMemoryRepository::new();
+
+        let account = accounts
+            .add(AddAccountDto {
+                name: String::from("Foo"),
+                email: String::from("foo@gmail.com"),
+                password: Sha2Adapter::new().encrypt("Old_passw0rd").await.unwrap(),
+            })
+            .await
+            .unwrap();
+
+        let auth_middleware = AuthMiddleware::new(Box::new(DbLoadAccountByToken::new(
+            Box::new(make_jwt(now)),
+            Box::new(accounts.clone()),
+        )));
+        let change_password_controller = ChangePasswordController::new(
+            Box::new(make_change_password_validation(&Config::default())),
+            Box::new(DbChangePassword::new(
+                true,
+                Box::new(accounts.clone()),
+                Box::new(Sha2Adapter::new()),
+                Box::new(Sha2Adapter::new()),
+                Box::new(accounts.clone()),
+                Box::new(make_jwt(now)),
+                Box::new(make_clock(now)),
+            )),
+        );
+
+        let app = App::new()
+            .app_data(web::Data::new(auth_middleware))
+            .app_data(web::Data::new(change_password_controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let res: ServiceResponse = app
+            .call(make_req(None, "Old_passw0rd").to_request())
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+        let old_token = make_jwt(now)
+            .generate(account.id(), account.token_version())
+            .await
+            .unwrap();
+        let old_token = format!("Bearer {}", old_token);
+
+        let res: ServiceResponse = app
+            .call(make_req(Some(&old_token), "Wrong_passw0rd").to_request())
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(
+            body["errors"],
+            json!([{ "field": "current_password", "detail": "incorrect password" }])
+        );
+
+        let res: ServiceResponse = app
+            .call(make_req(Some(&old_token), "Old_passw0rd").to_request())
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+        let new_token = format!("Bearer {}", body["access_token"].as_str().unwrap());
+
+        let account = accounts.load_by_email("foo@gmail.com").await.unwrap();
+
+        assert_eq!(
+            account.unwrap().password(),
+            Sha2Adapter::new().encrypt("New_passw0rd").await.unwrap()
+        );
+
+        let res: ServiceResponse = app
+            .call(make_req(Some(&old_token), "New_passw0rd").to_request())
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+        let res: ServiceResponse = app
+            .call(make_req(Some(&new_token), "New_passw0rd").to_request())
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+    }
+}
@@ -0,0 +1,86 @@
+use actix_web::web::{self, ServiceConfig};
+
+use crate::app::adapters::adapt_route;
+use crate::presentation::controllers::{LoginController, LoginReqBody, LoginResBody};
+
+pub fn setup_login_routes(cfg: &mut ServiceConfig) {
+    cfg.route(
+        "/login",
+        adapt_route::<LoginController, LoginReqBody, LoginResBody>(web::post()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{dev::Service, test, web};
+    use actix_web::{http, App};
+    use serde_json::{json, Value};
+
+    use crate::app;
+    use crate::domain::errors::DomainError;
+    use crate::domain::usecases::MockAuthentication;
+    use crate::presentation::controllers::{LoginController, LoginReqBodyBuilder};
+    use crate::presentation::protocols::MockEmailValidator;
+    use crate::utils::EmailNormalizerAdapter;
+
+    fn make_controller(access_token: Option<&'static str>) -> LoginController {
+        let mut email_validator = MockEmailValidator::default();
+        email_validator.expect_is_valid().returning(|_| Ok(true));
+
+        let mut authentication = MockAuthentication::default();
+        authentication.expect_auth().returning(move |_| {
+            access_token
+                .map(String::from)
+                .ok_or(DomainError::InvalidCredentials)
+        });
+
+        LoginController::new(
+            Box::new(EmailNormalizerAdapter::default()),
+            Box::new(email_validator),
+            Box::new(authentication),
+        )
+    }
+
+    async fn call(controller: LoginController) -> ServiceResponse {
+        let app = App::new()
+            .app_data(web::Data::new(controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let req_data = LoginReqBodyBuilder::new()
+            .set_email("foo@gmail.com")
+            .set_password("123")
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri("/api/login")
+            .set_json(req_data)
+            .to_request();
+
+        app.call(req).await.unwrap()
+    }
+
+    #[actix_web::test]
+    async fn returns_an_access_token_on_success() {
+        let res = call(make_controller(Some("any_token"))).await;
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body, json!({ "access_token": "any_token" }));
+    }
+
+    #[actix_web::test]
+    async fn returns_401_on_invalid_credentials() {
+        let res = call(make_controller(None)).await;
+
+        assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body["status"], 401);
+        assert_eq!(body["detail"], "unauthorized");
+    }
+}
@@ -0,0 +1,288 @@
+use actix_web::web::{self, ServiceConfig};
+
+use crate::app::adapters::adapt_route;
+use crate::presentation::controllers::{
+    ForgotPasswordController, ForgotPasswordReqBody, ForgotPasswordResBody,
+    ResetPasswordController, ResetPasswordReqBody, ResetPasswordResBody,
+};
+
+pub fn setup_password_routes(cfg: &mut ServiceConfig) {
+    cfg.route(
+        "/password/forgot",
+        adapt_route::<ForgotPasswordController, ForgotPasswordReqBody, ForgotPasswordResBody>(
+            web::post(),
+        ),
+    )
+    .route(
+        "/password/reset",
+        adapt_route::<ResetPasswordController, ResetPasswordReqBody, ResetPasswordResBody>(
+            web::post(),
+        ),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{dev::Service, test, web};
+    use actix_web::{http, App};
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+    use crate::app;
+    use crate::app::config::Config;
+    use crate::app::factories::{make_password_reset_mail, make_reset_password_validation};
+    use crate::data::protocols::{AddAccountRepository, LoadAccountByEmailRepository};
+    use crate::data::usecases::{
+        DbIssueVerificationToken, DbRequestPasswordReset, DbResetPassword,
+    };
+    use crate::domain::errors::DomainError;
+    use crate::domain::usecases::{AddAccountDto, RequestPasswordReset};
+    use crate::infra::clock::SystemClock;
+    use crate::infra::crypto::{RandomTokenAdapter, Sha2Adapter};
+    use crate::infra::db::{AccountInMemoryRepository, VerificationTokenInMemoryRepository};
+    use crate::infra::mail::InMemoryMailer;
+    use crate::presentation::controllers::{
+        ForgotPasswordController, ForgotPasswordReqBody, ResetPasswordController,
+        ResetPasswordReqBodyBuilder,
+    };
+    use crate::utils::{EmailNormalizerAdapter, EmailValidatorAdapter};
+    use crate::GenericResult;
+
+    /// Reports every finished request, so the tests can wait for the work
+    /// the forgot password controller runs in the background.
+    struct RequestPasswordResetSpy {
+        request_password_reset: DbRequestPasswordReset,
+        finished: UnboundedSender<()>,
+    }
+
+    #[async_trait]
+    impl RequestPasswordReset for RequestPasswordResetSpy {
+        async fn request(&self, email: &str) -> GenericResult<(), DomainError> {
+            let result = self.request_password_reset.request(email).await;
+            self.finished.send(()).unwrap();
+            result
+        }
+    }
+
+    struct Sut {
+        accounts: AccountInMemoryRepository,
+        mailer: InMemoryMailer,
+        requests_finished: UnboundedReceiver<()>,
+        forgot_password_controller: ForgotPasswordController,
+        reset_password_controller: ResetPasswordController,
+    }
+
+    fn make_sut() -> Sut {
+        let accounts = AccountInMemoryRepository::new();
+        let tokens = VerificationTokenInMemoryRepository::new();
+        let mailer = InMemoryMailer::new();
+        let (finished, requests_finished) = mpsc::unbounded_channel();
+
+        let issue_verification_token = DbIssueVerificationToken::new(
+            make_password_reset_mail(&Config::default()).unwrap(),
+            Box::new(tokens.clone()),
+            Box::new(RandomTokenAdapter::new()),
+            Box::new(Sha2Adapter::new()),
+            Box::new(tokens.clone()),
+            Box::new(mailer.clone()),
+            Box::new(SystemClock),
+        );
+        let request_password_reset = RequestPasswordResetSpy {
+            request_password_reset: DbRequestPasswordReset::new(
+                Box::new(accounts.clone()),
+                Box::new(issue_verification_token),
+            ),
+            finished,
+        };
+        let reset_password = DbResetPassword::new(
+            true,
+            Box::new(Sha2Adapter::new()),
+            Box::new(tokens.clone()),
+            Box::new(accounts.clone()),
+            Box::new(Sha2Adapter::new()),
+            Box::new(tokens),
+            Box::new(accounts.clone()),
+            Box::new(SystemClock),
+        );
+
+        Sut {
+            accounts,
+            mailer,
+            requests_finished,
+            forgot_password_controller: ForgotPasswordController::new(
+                Box::new(EmailNormalizerAdapter::default()),
+                Box::new(EmailValidatorAdapter::new()),
+                Box::new(request_password_reset),
+            ),
+            reset_password_controller: ResetPasswordController::new(
+                Box::new(make_reset_password_validation(&Config::default())),
+                Box::new(reset_password),
+            ),
+        }
+    }
+
+    #[actix_web::test]
+    async fn resets_the_password_with_the_mailed_token_once_a_valid_password_is_sent() {
+        let Sut {
+            accounts,
+            mailer,
+            mut requests_finished,
+            forgot_password_controller,
+            reset_password_controller,
+        } = make_sut();
+
+        accounts
+            .add(AddAccountDto {
+                name: String::from("Foo Bar 42"),
+                email: String::from("foo@gmail.com"),
+                password: String::from("old_hashed_password"),
+            })
+            .await
+            .unwrap();
+
+        let app = App::new()
+            .app_data(web::Data::new(forgot_password_controller))
+            .app_data(web::Data::new(reset_password_controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/password/forgot")
+            .set_json(ForgotPasswordReqBody::new("foo@gmail.com"))
+            .to_request();
+        let res: ServiceResponse = app.call(req).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::ACCEPTED);
+
+        requests_finished.recv().await;
+
+        let mails = mailer.sent().await;
+        let token = mails[0]
+            .body
+            .lines()
+            .find_map(|line| line.split("?token=").nth(1))
+            .unwrap();
+
+        let make_req = |password: &str| {
+            let req_data = ResetPasswordReqBodyBuilder::new()
+                .set_token(token)
+                .set_password(password)
+                .set_password_confirmation(password)
+                .build();
+
+            test::TestRequest::post()
+                .uri("/api/password/reset")
+                .set_json(req_data)
+                .to_request()
+        };
+
+        let res: ServiceResponse = app.call(make_req("Foo Bar 42")).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(
+            body["errors"],
+            json!([{ "field": "password", "detail": "contains personal info" }])
+        );
+
+        let res: ServiceResponse = app.call(make_req("New_passw0rd")).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body, json!({ "message": "password reset" }));
+
+        let account = accounts.load_by_email("foo@gmail.com").await.unwrap();
+
+        assert_ne!(account.unwrap().password(), "old_hashed_password");
+
+        let res: ServiceResponse = app.call(make_req("New_passw0rd")).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body["detail"], "invalid or expired token");
+    }
+
+    #[actix_web::test]
+    async fn forgot_mails_a_single_link_during_the_cooldown() {
+        let Sut {
+            accounts,
+            mailer,
+            mut requests_finished,
+            forgot_password_controller,
+            reset_password_controller,
+        } = make_sut();
+
+        accounts
+            .add(AddAccountDto {
+                name: String::from("Foo Bar 42"),
+                email: String::from("foo@gmail.com"),
+                password: String::from("old_hashed_password"),
+            })
+            .await
+            .unwrap();
+
+        let app = App::new()
+            .app_data(web::Data::new(forgot_password_controller))
+            .app_data(web::Data::new(reset_password_controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        for _ in 0..2 {
+            let req = test::TestRequest::post()
+                .uri("/api/password/forgot")
+                .set_json(ForgotPasswordReqBody::new("foo@gmail.com"))
+                .to_request();
+            let res: ServiceResponse = app.call(req).await.unwrap();
+
+            assert_eq!(res.status(), http::StatusCode::ACCEPTED);
+
+            requests_finished.recv().await;
+        }
+
+        assert_eq!(mailer.sent().await.len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn rejects_a_password_breaking_the_policy() {
+        let Sut {
+            forgot_password_controller,
+            reset_password_controller,
+            ..
+        } = make_sut();
+
+        let app = App::new()
+            .app_data(web::Data::new(forgot_password_controller))
+            .app_data(web::Data::new(reset_password_controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let req_data = ResetPasswordReqBodyBuilder::new()
+            .set_token("any_token")
+            .set_password("Sh0rt")
+            .set_password_confirmation("Sh0rt")
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri("/api/password/reset")
+            .set_json(req_data)
+            .to_request();
+        let res: ServiceResponse = app.call(req).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(
+            body["errors"],
+            json!([{ "field": "password", "detail": "too short" }])
+        );
+    }
+}
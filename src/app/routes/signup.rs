@@ -1,55 +1,210 @@
-use actix_web::post;
 use actix_web::web::{self, ServiceConfig};
-use serde::Serialize;
 
-pub fn setup_signup_routes(cfg: &mut ServiceConfig) {
-    cfg.service(create_account);
-}
-
-#[post("/signup")]
-async fn create_account() -> web::Json<CreateAccountResponse> {
-    let res = CreateAccountResponse {
-        ok: String::from("ok"),
-    };
-    web::Json(res)
-}
+use crate::app::adapters::adapt_route;
+use crate::presentation::controllers::{SignUpController, SignUpReqBody, SignUpResBody};
 
-#[derive(Serialize)]
-struct CreateAccountResponse {
-    pub ok: String,
+pub fn setup_signup_routes(cfg: &mut ServiceConfig) {
+    cfg.route(
+        "/signup",
+        adapt_route::<SignUpController, SignUpReqBody, SignUpResBody>(web::post()),
+    );
 }
 
 #[cfg(test)]
 mod tests {
     use actix_web::dev::ServiceResponse;
-    use actix_web::{dev::Service, test};
+    use actix_web::{dev::Service, test, web};
     use actix_web::{http, App};
+    use chrono::{TimeZone, Utc};
+    use serde_json::{json, Value};
 
     use crate::app;
-    use crate::presentation::controllers::SignUpReqBodyBuilder;
+    use crate::app::config::Config;
+    use crate::app::factories::make_signup_validation;
+    use crate::data::usecases::DbAddAccount;
+    use crate::domain::entities::AccountEntity;
+    use crate::domain::usecases::{AddAccountDto, MockAddAccount, MockSendEmailVerification};
+    use crate::infra::crypto::Sha2Adapter;
+    use crate::infra::db::AccountInMemoryRepository;
+    use crate::presentation::controllers::{SignUpController, SignUpReqBodyBuilder};
+    use crate::presentation::protocols::MockValidation;
+    use crate::utils::EmailNormalizerAdapter;
+
+    fn make_controller() -> SignUpController {
+        let mut validation = MockValidation::default();
+        validation.expect_validate().returning(|_| Ok(vec![]));
+
+        let mut add_account = MockAddAccount::default();
+        add_account.expect_add().returning(|account_dto| {
+            let AddAccountDto {
+                name,
+                email,
+                password,
+            } = &account_dto;
 
-    use super::create_account;
+            Ok(AccountEntity::new(
+                "valid_id",
+                name,
+                email,
+                password,
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            ))
+        });
+
+        let mut send_email_verification = MockSendEmailVerification::default();
+        send_email_verification.expect_send().returning(|_| Ok(()));
+
+        SignUpController::new(
+            Box::new(EmailNormalizerAdapter::default()),
+            Box::new(validation),
+            Box::new(add_account),
+            Box::new(send_email_verification),
+        )
+    }
+
+    fn make_in_memory_controller() -> SignUpController {
+        let repository = AccountInMemoryRepository::new();
+        let add_account = DbAddAccount::new(
+            Box::new(Sha2Adapter::new()),
+            Box::new(repository.clone()),
+            Box::new(repository),
+        );
+
+        let mut send_email_verification = MockSendEmailVerification::default();
+        send_email_verification.expect_send().returning(|_| Ok(()));
+
+        SignUpController::new(
+            Box::new(EmailNormalizerAdapter::default()),
+            Box::new(make_signup_validation(&Config::default())),
+            Box::new(add_account),
+            Box::new(send_email_verification),
+        )
+    }
 
     #[actix_web::test]
     async fn returns_an_account_on_success() {
         let app = App::new()
-            .configure(app::set_scope_api)
-            .service(create_account);
+            .app_data(web::Data::new(make_controller()))
+            .configure(app::setup_app);
         let app = test::init_service(app).await;
 
         let req_data = SignUpReqBodyBuilder::new()
             .set_name("Foo")
             .set_email("foo@gmail.com")
-            .set_password("123")
-            .set_password_confirmation("123")
+            .set_password("Any_passw0rd")
+            .set_password_confirmation("Any_passw0rd")
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri("/api/signup")
+            .set_json(req_data)
+            .to_request();
+        let res: ServiceResponse = app.call(req).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body["id"], "valid_id");
+        assert_eq!(body["name"], "Foo");
+        assert_eq!(body["email"], "foo@gmail.com");
+        assert_eq!(body["email_verified"], false);
+        assert_eq!(body["created_at"], "2022-01-01T00:00:00Z");
+        assert!(body.get("password").is_none());
+    }
+
+    #[actix_web::test]
+    async fn returns_400_if_body_is_missing() {
+        let app = App::new()
+            .app_data(web::Data::new(make_controller()))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let req = test::TestRequest::post().uri("/api/signup").to_request();
+        let res: ServiceResponse = app.call(req).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            res.headers().get("content-type").unwrap(),
+            "application/problem+json"
+        );
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(
+            body,
+            json!({
+                "type": "about:blank",
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "missing body"
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn returns_every_field_error_at_once() {
+        let app = App::new()
+            .app_data(web::Data::new(make_in_memory_controller()))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let req_data = SignUpReqBodyBuilder::new()
+            .set_email("invalid_email")
+            .set_password("Any_passw0rd")
             .build();
 
         let req = test::TestRequest::post()
-            .uri("/signup")
+            .uri("/api/signup")
             .set_json(req_data)
             .to_request();
         let res: ServiceResponse = app.call(req).await.unwrap();
 
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(
+            body["errors"],
+            json!([
+                { "field": "name", "detail": "missing param" },
+                { "field": "password_confirmation", "detail": "missing param" },
+                { "field": "email", "detail": "invalid param" }
+            ])
+        );
+    }
+
+    #[actix_web::test]
+    async fn registers_an_email_only_once_regardless_of_case() {
+        let app = App::new()
+            .app_data(web::Data::new(make_in_memory_controller()))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let make_req = |email: &str| {
+            let req_data = SignUpReqBodyBuilder::new()
+                .set_name("Foo")
+                .set_email(email)
+                .set_password("Any_passw0rd")
+                .set_password_confirmation("Any_passw0rd")
+                .build();
+
+            test::TestRequest::post()
+                .uri("/api/signup")
+                .set_json(req_data)
+                .to_request()
+        };
+
+        let res: ServiceResponse = app.call(make_req("foo@gmail.com")).await.unwrap();
+
         assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body["email"], "foo@gmail.com");
+
+        let res: ServiceResponse = app.call(make_req(" Foo@Gmail.com ")).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::CONFLICT);
     }
 }
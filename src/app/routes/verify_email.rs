@@ -0,0 +1,250 @@
+use actix_web::web::{self, ServiceConfig};
+
+use crate::app::adapters::adapt_route;
+use crate::presentation::controllers::{
+    ResendEmailVerificationController, ResendEmailVerificationReqBody,
+    ResendEmailVerificationResBody, VerifyEmailController, VerifyEmailReqBody, VerifyEmailResBody,
+};
+
+pub fn setup_verify_email_routes(cfg: &mut ServiceConfig) {
+    cfg.route(
+        "/verify-email",
+        adapt_route::<VerifyEmailController, VerifyEmailReqBody, VerifyEmailResBody>(web::post()),
+    )
+    .route(
+        "/verify-email/resend",
+        adapt_route::<
+            ResendEmailVerificationController,
+            ResendEmailVerificationReqBody,
+            ResendEmailVerificationResBody,
+        >(web::post()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::dev::ServiceResponse;
+    use actix_web::{dev::Service, test, web};
+    use actix_web::{http, App};
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+    use crate::app;
+    use crate::app::config::Config;
+    use crate::app::factories::make_email_verification_mail;
+    use crate::data::protocols::{AddAccountRepository, LoadAccountByEmailRepository};
+    use crate::data::usecases::{DbIssueVerificationToken, DbSendEmailVerification, DbVerifyEmail};
+    use crate::domain::errors::DomainError;
+    use crate::domain::usecases::{AddAccountDto, SendEmailVerification};
+    use crate::infra::clock::SystemClock;
+    use crate::infra::crypto::{RandomTokenAdapter, Sha2Adapter};
+    use crate::infra::db::{AccountInMemoryRepository, VerificationTokenInMemoryRepository};
+    use crate::infra::mail::InMemoryMailer;
+    use crate::presentation::controllers::{
+        ResendEmailVerificationController, ResendEmailVerificationReqBody, VerifyEmailController,
+        VerifyEmailReqBody,
+    };
+    use crate::utils::{EmailNormalizerAdapter, EmailValidatorAdapter};
+    use crate::GenericResult;
+
+    /// Reports every finished send, so the tests can wait for the work the
+    /// resend controller runs in the background.
+    struct SendEmailVerificationSpy {
+        send_email_verification: DbSendEmailVerification,
+        finished: UnboundedSender<()>,
+    }
+
+    #[async_trait]
+    impl SendEmailVerification for SendEmailVerificationSpy {
+        async fn send(&self, email: &str) -> GenericResult<(), DomainError> {
+            let result = self.send_email_verification.send(email).await;
+            self.finished.send(()).unwrap();
+            result
+        }
+    }
+
+    struct Sut {
+        accounts: AccountInMemoryRepository,
+        mailer: InMemoryMailer,
+        sends_finished: UnboundedReceiver<()>,
+        verify_email_controller: VerifyEmailController,
+        resend_controller: ResendEmailVerificationController,
+    }
+
+    fn make_sut() -> Sut {
+        let accounts = AccountInMemoryRepository::new();
+        let tokens = VerificationTokenInMemoryRepository::new();
+        let mailer = InMemoryMailer::new();
+        let (finished, sends_finished) = mpsc::unbounded_channel();
+
+        let issue_verification_token = DbIssueVerificationToken::new(
+            make_email_verification_mail(&Config::default()).unwrap(),
+            Box::new(tokens.clone()),
+            Box::new(RandomTokenAdapter::new()),
+            Box::new(Sha2Adapter::new()),
+            Box::new(tokens.clone()),
+            Box::new(mailer.clone()),
+            Box::new(SystemClock),
+        );
+        let send_email_verification = SendEmailVerificationSpy {
+            send_email_verification: DbSendEmailVerification::new(
+                Box::new(accounts.clone()),
+                Box::new(issue_verification_token),
+            ),
+            finished,
+        };
+        let verify_email = DbVerifyEmail::new(
+            Box::new(Sha2Adapter::new()),
+            Box::new(tokens),
+            Box::new(accounts.clone()),
+            Box::new(SystemClock),
+        );
+
+        Sut {
+            accounts,
+            mailer,
+            sends_finished,
+            verify_email_controller: VerifyEmailController::new(Box::new(verify_email)),
+            resend_controller: ResendEmailVerificationController::new(
+                Box::new(EmailNormalizerAdapter::default()),
+                Box::new(EmailValidatorAdapter::new()),
+                Box::new(send_email_verification),
+            ),
+        }
+    }
+
+    async fn add_account(accounts: &AccountInMemoryRepository) {
+        accounts
+            .add(AddAccountDto {
+                name: String::from("Foo"),
+                email: String::from("foo@gmail.com"),
+                password: String::from("hashed_password"),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn verifies_the_email_with_the_mailed_token_once() {
+        let Sut {
+            accounts,
+            mailer,
+            mut sends_finished,
+            verify_email_controller,
+            resend_controller,
+        } = make_sut();
+        add_account(&accounts).await;
+
+        let app = App::new()
+            .app_data(web::Data::new(verify_email_controller))
+            .app_data(web::Data::new(resend_controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/verify-email/resend")
+            .set_json(ResendEmailVerificationReqBody::new("foo@gmail.com"))
+            .to_request();
+        let res: ServiceResponse = app.call(req).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::ACCEPTED);
+
+        sends_finished.recv().await;
+
+        let mails = mailer.sent().await;
+        let token = mails[0]
+            .body
+            .lines()
+            .find_map(|line| line.split("?token=").nth(1))
+            .unwrap();
+
+        let make_req = || {
+            test::TestRequest::post()
+                .uri("/api/verify-email")
+                .set_json(VerifyEmailReqBody::new(token))
+                .to_request()
+        };
+
+        let res: ServiceResponse = app.call(make_req()).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body, json!({ "message": "email verified" }));
+
+        let account = accounts.load_by_email("foo@gmail.com").await.unwrap();
+
+        assert!(account.unwrap().email_verified());
+
+        let res: ServiceResponse = app.call(make_req()).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(res).await;
+
+        assert_eq!(body["detail"], "invalid or expired token");
+    }
+
+    #[actix_web::test]
+    async fn resend_answers_the_same_for_unknown_emails() {
+        let Sut {
+            mailer,
+            mut sends_finished,
+            verify_email_controller,
+            resend_controller,
+            ..
+        } = make_sut();
+
+        let app = App::new()
+            .app_data(web::Data::new(verify_email_controller))
+            .app_data(web::Data::new(resend_controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/verify-email/resend")
+            .set_json(ResendEmailVerificationReqBody::new("unknown@gmail.com"))
+            .to_request();
+        let res: ServiceResponse = app.call(req).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::ACCEPTED);
+
+        sends_finished.recv().await;
+
+        assert!(mailer.sent().await.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn resend_mails_a_single_link_during_the_cooldown() {
+        let Sut {
+            accounts,
+            mailer,
+            mut sends_finished,
+            verify_email_controller,
+            resend_controller,
+        } = make_sut();
+        add_account(&accounts).await;
+
+        let app = App::new()
+            .app_data(web::Data::new(verify_email_controller))
+            .app_data(web::Data::new(resend_controller))
+            .configure(app::setup_app);
+        let app = test::init_service(app).await;
+
+        for _ in 0..2 {
+            let req = test::TestRequest::post()
+                .uri("/api/verify-email/resend")
+                .set_json(ResendEmailVerificationReqBody::new("foo@gmail.com"))
+                .to_request();
+            let res: ServiceResponse = app.call(req).await.unwrap();
+
+            assert_eq!(res.status(), http::StatusCode::ACCEPTED);
+
+            sends_finished.recv().await;
+        }
+
+        assert_eq!(mailer.sent().await.len(), 1);
+    }
+}
@@ -0,0 +1,104 @@
+use std::fs::File;
+use std::io::{self, BufReader};
+
+use rustls::{Certificate, PrivateKey, ServerConfig};
+use rustls_pemfile::Item;
+use thiserror::Error;
+
+use crate::app::config::TlsConfig;
+use crate::GenericResult;
+
+#[derive(Debug, Error)]
+pub enum TlsError {
+    #[error("failed to read the tls files: {0}")]
+    Read(#[from] io::Error),
+    #[error("no certificate found in {0}")]
+    NoCertificate(String),
+    #[error("no private key found in {0}")]
+    NoPrivateKey(String),
+    #[error("invalid certificate or key: {0}")]
+    Rustls(#[from] rustls::Error),
+}
+
+/// Builds the rustls server config from the PEM files named in `tls`.
+pub fn load_rustls_config(tls: &TlsConfig) -> GenericResult<ServerConfig, TlsError> {
+    let mut cert_reader = BufReader::new(File::open(&tls.cert_path)?);
+    let certs: Vec<Certificate> = rustls_pemfile::certs(&mut cert_reader)?
+        .into_iter()
+        .map(Certificate)
+        .collect();
+
+    if certs.is_empty() {
+        return Err(TlsError::NoCertificate(tls.cert_path.clone()));
+    }
+
+    let mut key_reader = BufReader::new(File::open(&tls.key_path)?);
+    let key = rustls_pemfile::read_all(&mut key_reader)?
+        .into_iter()
+        .find_map(|item| match item {
+            Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        });
+
+    let key = match key {
+        Some(key) => key,
+        None => return Err(TlsError::NoPrivateKey(tls.key_path.clone())),
+    };
+
+    let config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::config::TlsConfig;
+
+    use super::{load_rustls_config, TlsError};
+
+    fn fixture(name: &str) -> String {
+        format!(
+            "{}/src/app/server/fixtures/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        )
+    }
+
+    #[test]
+    fn loads_the_certificate_and_key() {
+        let tls = TlsConfig {
+            cert_path: fixture("cert.pem"),
+            key_path: fixture("key.pem"),
+        };
+
+        assert!(load_rustls_config(&tls).is_ok());
+    }
+
+    #[test]
+    fn returns_err_if_a_file_is_missing() {
+        let tls = TlsConfig {
+            cert_path: fixture("cert.pem"),
+            key_path: fixture("missing.pem"),
+        };
+
+        assert!(matches!(
+            load_rustls_config(&tls).unwrap_err(),
+            TlsError::Read(_)
+        ));
+    }
+
+    #[test]
+    fn returns_err_if_the_key_file_has_no_key() {
+        let tls = TlsConfig {
+            cert_path: fixture("cert.pem"),
+            key_path: fixture("cert.pem"),
+        };
+
+        let err = load_rustls_config(&tls).unwrap_err();
+
+        assert!(matches!(err, TlsError::NoPrivateKey(_)));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod data_error;
+
+pub use data_error::DataError;
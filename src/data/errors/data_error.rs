@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use crate::domain::errors::DomainError;
+use crate::BoxError;
+
+/// Errors returned by the data protocols' implementations.
+#[derive(Debug, Error)]
+pub enum DataError {
+    /// The database could not be reached.
+    #[error("database unavailable")]
+    DatabaseUnavailable(#[source] BoxError),
+    /// A database query or write failed.
+    #[error("database operation failed")]
+    DatabaseFailed(#[source] BoxError),
+    /// An account is stored with an email that is already registered.
+    #[error("email already in use")]
+    DuplicateEmail,
+    /// Hashing or comparing a value failed.
+    #[error("hashing failed")]
+    HashingFailed(#[source] BoxError),
+    /// Signing or verifying an access token failed.
+    #[error("token operation failed")]
+    TokenFailed(#[source] BoxError),
+    /// The mailer could not send a message.
+    #[error("sending mail failed")]
+    MailFailed(#[source] BoxError),
+}
+
+impl From<DataError> for DomainError {
+    fn from(err: DataError) -> Self {
+        match err {
+            DataError::DuplicateEmail => DomainError::EmailInUse,
+            err => DomainError::Unexpected(Box::new(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::domain::errors::DomainError;
+
+    use super::DataError;
+
+    #[test]
+    fn maps_duplicate_email_to_email_in_use() {
+        let err = DomainError::from(DataError::DuplicateEmail);
+
+        assert!(matches!(err, DomainError::EmailInUse));
+    }
+
+    #[test]
+    fn keeps_the_source_chain_of_unexpected_errors() {
+        let err = DomainError::from(DataError::DatabaseUnavailable("any_error".into()));
+
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "database unavailable");
+        assert_eq!(source.source().unwrap().to_string(), "any_error");
+    }
+}
@@ -1,2 +1,3 @@
+pub mod errors;
 pub mod protocols;
 pub mod usecases;
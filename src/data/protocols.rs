@@ -1,5 +1,49 @@
 pub mod add_account_repository;
+pub mod add_verification_token_repository;
+pub mod clock;
+pub mod consume_verification_token_repository;
 pub mod encrypter;
+pub mod hash_comparer;
+pub mod issue_verification_token;
+pub mod load_account_by_email_repository;
+pub mod load_account_by_id_repository;
+pub mod load_latest_verification_token_repository;
+pub mod load_verification_token_by_hash_repository;
+pub mod mailer;
+pub mod mark_email_verified_repository;
+pub mod random_token_generator;
+pub mod token_generator;
+pub mod token_verifier;
+pub mod update_account_password_repository;
 
 pub use add_account_repository::{AddAccountRepository, MockAddAccountRepository};
+pub use add_verification_token_repository::{
+    AddVerificationTokenRepository, MockAddVerificationTokenRepository,
+};
+pub use clock::{Clock, MockClock};
+pub use consume_verification_token_repository::{
+    ConsumeVerificationTokenRepository, MockConsumeVerificationTokenRepository,
+};
 pub use encrypter::{Encrypter, MockEncrypter};
+pub use hash_comparer::{HashComparer, MockHashComparer};
+pub use issue_verification_token::{IssueVerificationToken, MockIssueVerificationToken};
+pub use load_account_by_email_repository::{
+    LoadAccountByEmailRepository, MockLoadAccountByEmailRepository,
+};
+pub use load_account_by_id_repository::{LoadAccountByIdRepository, MockLoadAccountByIdRepository};
+pub use load_latest_verification_token_repository::{
+    LoadLatestVerificationTokenRepository, MockLoadLatestVerificationTokenRepository,
+};
+pub use load_verification_token_by_hash_repository::{
+    LoadVerificationTokenByHashRepository, MockLoadVerificationTokenByHashRepository,
+};
+pub use mailer::{Mail, Mailer, MockMailer};
+pub use mark_email_verified_repository::{
+    MarkEmailVerifiedRepository, MockMarkEmailVerifiedRepository,
+};
+pub use random_token_generator::{MockRandomTokenGenerator, RandomTokenGenerator};
+pub use token_generator::{MockTokenGenerator, TokenGenerator};
+pub use token_verifier::{MockTokenVerifier, TokenVerifier, VerifiedToken};
+pub use update_account_password_repository::{
+    MockUpdateAccountPasswordRepository, UpdateAccountPasswordRepository,
+};
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use mockall::automock;
 
+use crate::data::errors::DataError;
 use crate::domain::entities::AccountEntity;
 use crate::domain::usecases::AddAccountDto;
 use crate::GenericResult;
@@ -8,5 +9,5 @@ use crate::GenericResult;
 #[automock]
 #[async_trait]
 pub trait AddAccountRepository: Send + Sync {
-    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity>;
+    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DataError>;
 }
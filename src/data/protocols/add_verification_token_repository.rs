@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::VerificationTokenEntity;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait AddVerificationTokenRepository: Send + Sync {
+    async fn add(&self, token: VerificationTokenEntity) -> GenericResult<(), DataError>;
+}
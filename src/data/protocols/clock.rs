@@ -0,0 +1,7 @@
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[automock]
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
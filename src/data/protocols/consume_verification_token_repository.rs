@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait ConsumeVerificationTokenRepository: Send + Sync {
+    /// Removes and returns the token with `token_hash` issued for `purpose`,
+    /// so it can only be redeemed once. Expired tokens are returned too.
+    async fn consume(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError>;
+}
@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use mockall::automock;
 
+use crate::data::errors::DataError;
 use crate::GenericResult;
 
 #[automock]
 #[async_trait]
 pub trait Encrypter: Send + Sync {
-    async fn encrypt(&self, value: &str) -> GenericResult<String>;
+    async fn encrypt(&self, value: &str) -> GenericResult<String, DataError>;
 }
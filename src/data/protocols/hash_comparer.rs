@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait HashComparer: Send + Sync {
+    async fn compare(&self, value: &str, hash: &str) -> GenericResult<bool, DataError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::AccountEntity;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait IssueVerificationToken: Send + Sync {
+    /// Stores a new single-use token for `account` and mails its owner the
+    /// link redeeming it.
+    async fn issue(&self, account: &AccountEntity) -> GenericResult<(), DataError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::AccountEntity;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait LoadAccountByIdRepository: Send + Sync {
+    async fn load_by_id(&self, id: &str) -> GenericResult<Option<AccountEntity>, DataError>;
+}
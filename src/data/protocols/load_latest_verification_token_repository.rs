@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait LoadLatestVerificationTokenRepository: Send + Sync {
+    /// Returns the token last issued to `account_id` for `purpose`, expired
+    /// or not. Tokens without an issue date are left out.
+    async fn load_latest(
+        &self,
+        account_id: &str,
+        purpose: TokenPurpose,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait LoadVerificationTokenByHashRepository: Send + Sync {
+    /// Returns the token with `token_hash` issued for `purpose` without
+    /// consuming it. Expired tokens are returned too.
+    async fn load_by_hash(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait MarkEmailVerifiedRepository: Send + Sync {
+    async fn mark_email_verified(&self, account_id: &str) -> GenericResult<(), DataError>;
+}
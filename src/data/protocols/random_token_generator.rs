@@ -0,0 +1,7 @@
+use mockall::automock;
+
+#[automock]
+pub trait RandomTokenGenerator: Send + Sync {
+    /// Returns a new unguessable, URL safe token.
+    fn generate(&self) -> String;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait TokenGenerator: Send + Sync {
+    /// Issues an access token for the account `id`, valid while the account's
+    /// token version stays `token_version`.
+    async fn generate(&self, id: &str, token_version: i64) -> GenericResult<String, DataError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait TokenVerifier: Send + Sync {
+    /// Returns what the token was issued for, or `None` if the token is
+    /// invalid or expired.
+    async fn verify(&self, token: &str) -> GenericResult<Option<VerifiedToken>, DataError>;
+}
+
+/// Claims of a token that passed verification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedToken {
+    /// Id the token was issued for.
+    pub subject: String,
+    /// Token version of the account when the token was issued.
+    pub token_version: i64,
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+use crate::data::errors::DataError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait UpdateAccountPasswordRepository: Send + Sync {
+    /// Replaces the account's password with `password`, which is already
+    /// hashed, records `changed_at` as the date it last changed and bumps the
+    /// account's token version by one.
+    async fn update_password(
+        &self,
+        account_id: &str,
+        password: &str,
+        changed_at: DateTime<Utc>,
+    ) -> GenericResult<(), DataError>;
+}
@@ -1,3 +1,19 @@
 pub mod add_account;
+pub mod authentication;
+pub mod change_password;
+pub mod issue_verification_token;
+pub mod load_account_by_token;
+pub mod request_password_reset;
+pub mod reset_password;
+pub mod send_email_verification;
+pub mod verify_email;
 
 pub use add_account::DbAddAccount;
+pub use authentication::DbAuthentication;
+pub use change_password::DbChangePassword;
+pub use issue_verification_token::{DbIssueVerificationToken, VerificationTokenMail};
+pub use load_account_by_token::DbLoadAccountByToken;
+pub use request_password_reset::DbRequestPasswordReset;
+pub use reset_password::DbResetPassword;
+pub use send_email_verification::DbSendEmailVerification;
+pub use verify_email::DbVerifyEmail;
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 
-use crate::data::protocols::{AddAccountRepository, Encrypter};
+use crate::data::protocols::{AddAccountRepository, Encrypter, LoadAccountByEmailRepository};
 use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
 use crate::domain::usecases::{AddAccount, AddAccountDto};
 use crate::GenericResult;
 
@@ -11,16 +12,19 @@ pub mod tests;
 pub struct DbAddAccount {
     encrypter: Box<dyn Encrypter>,
     add_account_repository: Box<dyn AddAccountRepository>,
+    load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
 }
 
 impl DbAddAccount {
     pub fn new(
         encrypter: Box<dyn Encrypter>,
         add_account_repository: Box<dyn AddAccountRepository>,
+        load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
     ) -> Self {
         Self {
             encrypter,
             add_account_repository,
+            load_account_by_email_repository,
         }
     }
 
@@ -36,18 +40,38 @@ impl DbAddAccount {
     ) {
         self.add_account_repository = add_account_repository;
     }
+
+    /// Set the db add account's load account by email repository.
+    pub fn set_load_account_by_email_repository(
+        &mut self,
+        load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
+    ) {
+        self.load_account_by_email_repository = load_account_by_email_repository;
+    }
 }
 
 #[async_trait]
 impl AddAccount for DbAddAccount {
-    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity> {
+    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DomainError> {
+        let account = self
+            .load_account_by_email_repository
+            .load_by_email(&account_dto.email)
+            .await?;
+
+        if account.is_some() {
+            return Err(DomainError::EmailInUse);
+        }
+
         let hashed_password = self.encrypter.encrypt(&account_dto.password).await?;
 
-        self.add_account_repository
+        let account = self
+            .add_account_repository
             .add(AddAccountDto {
                 password: hashed_password,
                 ..account_dto
             })
-            .await
+            .await?;
+
+        Ok(account)
     }
 }
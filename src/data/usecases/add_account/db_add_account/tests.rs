@@ -1,3 +1,4 @@
+use chrono::{TimeZone, Utc};
 use mockall::predicate;
 use mockall_double::double;
 
@@ -5,10 +6,13 @@ use mockall_double::double;
 use crate::data::protocols::AddAccountRepository;
 #[double]
 use crate::data::protocols::Encrypter;
+#[double]
+use crate::data::protocols::LoadAccountByEmailRepository;
 
+use crate::data::errors::DataError;
 use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
 use crate::domain::usecases::{AddAccount, AddAccountDto};
-use crate::ErrorMsg;
 
 use super::DbAddAccount;
 
@@ -27,7 +31,13 @@ macro_rules! add_account_repository_add_default {
                 password,
             } = &account_dto;
 
-            Ok(AccountEntity::new("valid_id", name, email, password))
+            Ok(AccountEntity::new(
+                "valid_id",
+                name,
+                email,
+                password,
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            ))
         }
     };
 }
@@ -43,8 +53,16 @@ fn make_sut() -> DbAddAccount {
         .expect_add()
         .returning(add_account_repository_add_default!());
 
-    let sut = DbAddAccount::new(encrypter, add_account_repository);
-    sut
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|_| Ok(None));
+
+    DbAddAccount::new(
+        encrypter,
+        add_account_repository,
+        load_account_by_email_repository,
+    )
 }
 
 fn make_encrypter() -> Box<Encrypter> {
@@ -55,6 +73,10 @@ fn make_add_account_repository() -> Box<AddAccountRepository> {
     Box::new(AddAccountRepository::default())
 }
 
+fn make_load_account_by_email_repository() -> Box<LoadAccountByEmailRepository> {
+    Box::new(LoadAccountByEmailRepository::default())
+}
+
 #[tokio::test]
 async fn calls_encrypter() {
     let mut encrypter = make_encrypter();
@@ -72,10 +94,7 @@ async fn calls_encrypter() {
         password: String::from("valid_password"),
     };
 
-    match sut.add(account_dto).await {
-        Ok(_) => {}
-        Err(_) => {}
-    }
+    let _ = sut.add(account_dto).await;
 }
 
 #[tokio::test]
@@ -95,10 +114,7 @@ async fn calls_encrypter_with_correct_password() {
         password: String::from("valid_password"),
     };
 
-    match sut.add(account_dto).await {
-        Ok(_) => {}
-        Err(_) => {}
-    };
+    let _ = sut.add(account_dto).await;
 }
 
 #[tokio::test]
@@ -107,7 +123,7 @@ async fn returns_err_if_encryter_returns_err() {
     encrypter
         .expect_encrypt()
         .with(predicate::eq("valid_password"))
-        .returning(|_| ErrorMsg::default().into());
+        .returning(|_| Err(DataError::HashingFailed("any_error".into())));
 
     let mut sut = make_sut();
     sut.set_encrypter(encrypter);
@@ -118,11 +134,9 @@ async fn returns_err_if_encryter_returns_err() {
         password: String::from("valid_password"),
     };
 
-    if let Some(err) = sut.add(account_dto).await.err() {
-        assert_eq!(err.to_string(), ErrorMsg::default().to_string())
-    } else {
-        assert!(false);
-    }
+    let err = sut.add(account_dto).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
 }
 
 #[tokio::test]
@@ -142,10 +156,7 @@ async fn calls_add_account_repository() {
         password: String::from("valid_password"),
     };
 
-    match sut.add(account_dto).await {
-        Ok(_) => {}
-        Err(_) => {}
-    }
+    let _ = sut.add(account_dto).await;
 }
 
 #[tokio::test]
@@ -169,10 +180,7 @@ async fn calls_add_account_repository_with_correct_data() {
         password: String::from("valid_password"),
     };
 
-    match sut.add(account_dto).await {
-        Ok(_) => {}
-        Err(_) => {}
-    };
+    let _ = sut.add(account_dto).await;
 }
 
 #[tokio::test]
@@ -181,7 +189,7 @@ async fn returns_err_if_add_account_repository_returns_err() {
     add_account_repository
         .expect_add()
         .once()
-        .returning(|_| ErrorMsg::default().into());
+        .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
 
     let mut sut = make_sut();
     sut.set_add_account_repository(add_account_repository);
@@ -192,11 +200,9 @@ async fn returns_err_if_add_account_repository_returns_err() {
         password: String::from("valid_password"),
     };
 
-    if let Some(err) = sut.add(account_dto).await.err() {
-        assert_eq!(err.to_string(), ErrorMsg::default().to_string())
-    } else {
-        assert!(false);
-    }
+    let err = sut.add(account_dto).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
 }
 
 #[tokio::test]
@@ -216,3 +222,103 @@ async fn returns_an_account_on_success() {
     assert_eq!(account.email(), "valid_email@mail.com");
     assert_eq!(account.password(), "hashed_password");
 }
+
+#[tokio::test]
+async fn calls_load_account_by_email_repository_with_correct_email() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .once()
+        .with(predicate::eq("valid_email@mail.com"))
+        .returning(|_| Ok(None));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+
+    let account_dto = AddAccountDto {
+        name: String::from("valid_name"),
+        email: String::from("valid_email@mail.com"),
+        password: String::from("valid_password"),
+    };
+
+    let _ = sut.add(account_dto).await;
+}
+
+#[tokio::test]
+async fn returns_err_if_load_account_by_email_repository_returns_err() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|_| Err(DataError::DatabaseUnavailable("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+
+    let account_dto = AddAccountDto {
+        name: String::from("valid_name"),
+        email: String::from("valid_email@mail.com"),
+        password: String::from("valid_password"),
+    };
+
+    let err = sut.add(account_dto).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_email_in_use_error_if_email_is_already_registered() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|email| {
+            Ok(Some(AccountEntity::new(
+                "any_id",
+                "any_name",
+                email,
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        });
+
+    let mut encrypter = make_encrypter();
+    encrypter.expect_encrypt().never();
+
+    let mut add_account_repository = make_add_account_repository();
+    add_account_repository.expect_add().never();
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+    sut.set_encrypter(encrypter);
+    sut.set_add_account_repository(add_account_repository);
+
+    let account_dto = AddAccountDto {
+        name: String::from("valid_name"),
+        email: String::from("valid_email@mail.com"),
+        password: String::from("valid_password"),
+    };
+
+    let err = sut.add(account_dto).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::EmailInUse));
+}
+
+#[tokio::test]
+async fn returns_email_in_use_error_if_add_account_repository_finds_a_duplicate() {
+    let mut add_account_repository = make_add_account_repository();
+    add_account_repository
+        .expect_add()
+        .returning(|_| Err(DataError::DuplicateEmail));
+
+    let mut sut = make_sut();
+    sut.set_add_account_repository(add_account_repository);
+
+    let account_dto = AddAccountDto {
+        name: String::from("valid_name"),
+        email: String::from("valid_email@mail.com"),
+        password: String::from("valid_password"),
+    };
+
+    let err = sut.add(account_dto).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::EmailInUse));
+}
@@ -0,0 +1,3 @@
+pub mod db_authentication;
+
+pub use db_authentication::DbAuthentication;
@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+
+use crate::data::protocols::{HashComparer, LoadAccountByEmailRepository, TokenGenerator};
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::{Authentication, AuthenticationDto};
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct DbAuthentication {
+    require_verified_email: bool,
+    load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
+    hash_comparer: Box<dyn HashComparer>,
+    token_generator: Box<dyn TokenGenerator>,
+}
+
+impl DbAuthentication {
+    /// Accounts whose email is not verified are refused a token when
+    /// `require_verified_email` is set.
+    pub fn new(
+        require_verified_email: bool,
+        load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
+        hash_comparer: Box<dyn HashComparer>,
+        token_generator: Box<dyn TokenGenerator>,
+    ) -> Self {
+        Self {
+            require_verified_email,
+            load_account_by_email_repository,
+            hash_comparer,
+            token_generator,
+        }
+    }
+
+    /// Set the db authentication's require verified email.
+    pub fn set_require_verified_email(&mut self, require_verified_email: bool) {
+        self.require_verified_email = require_verified_email;
+    }
+
+    /// Set the db authentication's load account by email repository.
+    pub fn set_load_account_by_email_repository(
+        &mut self,
+        load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
+    ) {
+        self.load_account_by_email_repository = load_account_by_email_repository;
+    }
+
+    /// Set the db authentication's hash comparer.
+    pub fn set_hash_comparer(&mut self, hash_comparer: Box<dyn HashComparer>) {
+        self.hash_comparer = hash_comparer;
+    }
+
+    /// Set the db authentication's token generator.
+    pub fn set_token_generator(&mut self, token_generator: Box<dyn TokenGenerator>) {
+        self.token_generator = token_generator;
+    }
+}
+
+#[async_trait]
+impl Authentication for DbAuthentication {
+    async fn auth(
+        &self,
+        authentication_dto: AuthenticationDto,
+    ) -> GenericResult<String, DomainError> {
+        let AuthenticationDto { email, password } = &authentication_dto;
+
+        let account = self
+            .load_account_by_email_repository
+            .load_by_email(email)
+            .await?;
+
+        let account = match account {
+            Some(account) => account,
+            None => return Err(DomainError::InvalidCredentials),
+        };
+
+        let is_valid = self
+            .hash_comparer
+            .compare(password, account.password())
+            .await?;
+
+        if !is_valid {
+            return Err(DomainError::InvalidCredentials);
+        }
+
+        // Only checked once the password matches, so the answer can't tell
+        // whether someone else's email is verified.
+        if self.require_verified_email && !account.email_verified() {
+            return Err(DomainError::EmailNotVerified);
+        }
+
+        let access_token = self
+            .token_generator
+            .generate(account.id(), account.token_version())
+            .await?;
+
+        Ok(access_token)
+    }
+}
@@ -0,0 +1,302 @@
+use chrono::{TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::HashComparer;
+#[double]
+use crate::data::protocols::LoadAccountByEmailRepository;
+#[double]
+use crate::data::protocols::TokenGenerator;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::{Authentication, AuthenticationDto};
+
+use super::DbAuthentication;
+
+macro_rules! load_account_by_email_repository_load_by_email_default {
+    () => {
+        |email| {
+            Ok(Some(
+                AccountEntity::new(
+                    "any_id",
+                    "any_name",
+                    email,
+                    "hashed_password",
+                    Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+                )
+                .with_email_verified(true)
+                .with_token_version(3),
+            ))
+        }
+    };
+}
+
+macro_rules! hash_comparer_compare_default {
+    () => {
+        |_, _| Ok(true)
+    };
+}
+
+macro_rules! token_generator_generate_default {
+    () => {
+        |_, _| Ok(String::from("any_token"))
+    };
+}
+
+fn make_sut() -> DbAuthentication {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(load_account_by_email_repository_load_by_email_default!());
+
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(hash_comparer_compare_default!());
+
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .returning(token_generator_generate_default!());
+
+    DbAuthentication::new(
+        true,
+        load_account_by_email_repository,
+        hash_comparer,
+        token_generator,
+    )
+}
+
+fn make_load_account_by_email_repository() -> Box<LoadAccountByEmailRepository> {
+    Box::new(LoadAccountByEmailRepository::default())
+}
+
+fn make_hash_comparer() -> Box<HashComparer> {
+    Box::new(HashComparer::default())
+}
+
+fn make_token_generator() -> Box<TokenGenerator> {
+    Box::new(TokenGenerator::default())
+}
+
+fn make_authentication_dto() -> AuthenticationDto {
+    AuthenticationDto {
+        email: String::from("any_email@mail.com"),
+        password: String::from("any_password"),
+    }
+}
+
+#[tokio::test]
+async fn calls_load_account_by_email_repository_with_correct_email() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .once()
+        .with(predicate::eq("any_email@mail.com"))
+        .returning(load_account_by_email_repository_load_by_email_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+
+    let _ = sut.auth(make_authentication_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_err_if_load_account_by_email_repository_returns_err() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|_| Err(DataError::DatabaseUnavailable("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+
+    let err = sut.auth(make_authentication_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_invalid_credentials_if_load_account_by_email_repository_returns_none() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|_| Ok(None));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+
+    let err = sut.auth(make_authentication_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidCredentials));
+}
+
+#[tokio::test]
+async fn calls_hash_comparer_with_correct_values() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .once()
+        .with(
+            predicate::eq("any_password"),
+            predicate::eq("hashed_password"),
+        )
+        .returning(hash_comparer_compare_default!());
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let _ = sut.auth(make_authentication_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_err_if_hash_comparer_returns_err() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(|_, _| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let err = sut.auth(make_authentication_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_invalid_credentials_if_hash_comparer_returns_false() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer.expect_compare().returning(|_, _| Ok(false));
+
+    let mut token_generator = make_token_generator();
+    token_generator.expect_generate().never();
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+    sut.set_token_generator(token_generator);
+
+    let err = sut.auth(make_authentication_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidCredentials));
+}
+
+#[tokio::test]
+async fn returns_email_not_verified_if_the_account_email_is_not_verified() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|email| {
+            Ok(Some(AccountEntity::new(
+                "any_id",
+                "any_name",
+                email,
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        });
+
+    let mut token_generator = make_token_generator();
+    token_generator.expect_generate().never();
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+    sut.set_token_generator(token_generator);
+
+    let err = sut.auth(make_authentication_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::EmailNotVerified));
+}
+
+#[tokio::test]
+async fn returns_invalid_credentials_before_checking_the_email_verification() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|email| {
+            Ok(Some(AccountEntity::new(
+                "any_id",
+                "any_name",
+                email,
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        });
+
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer.expect_compare().returning(|_, _| Ok(false));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+    sut.set_hash_comparer(hash_comparer);
+
+    let err = sut.auth(make_authentication_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidCredentials));
+}
+
+#[tokio::test]
+async fn authenticates_unverified_accounts_if_require_verified_email_is_unset() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|email| {
+            Ok(Some(AccountEntity::new(
+                "any_id",
+                "any_name",
+                email,
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        });
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+    sut.set_require_verified_email(false);
+
+    let access_token = sut.auth(make_authentication_dto()).await.unwrap();
+
+    assert_eq!(access_token, "any_token");
+}
+
+#[tokio::test]
+async fn calls_token_generator_with_correct_values() {
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .once()
+        .with(predicate::eq("any_id"), predicate::eq(3))
+        .returning(token_generator_generate_default!());
+
+    let mut sut = make_sut();
+    sut.set_token_generator(token_generator);
+
+    let _ = sut.auth(make_authentication_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_err_if_token_generator_returns_err() {
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .returning(|_, _| Err(DataError::TokenFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_token_generator(token_generator);
+
+    let err = sut.auth(make_authentication_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_an_access_token_on_success() {
+    let sut = make_sut();
+
+    let access_token = sut.auth(make_authentication_dto()).await.unwrap();
+
+    assert_eq!(access_token, "any_token");
+}
@@ -0,0 +1,3 @@
+pub mod db_change_password;
+
+pub use db_change_password::DbChangePassword;
@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+
+use crate::data::protocols::{
+    Clock, Encrypter, HashComparer, LoadAccountByIdRepository, TokenGenerator,
+    UpdateAccountPasswordRepository,
+};
+use crate::domain::errors::DomainError;
+use crate::domain::password::is_personal_info;
+use crate::domain::usecases::{ChangePassword, ChangePasswordDto};
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct DbChangePassword {
+    reject_personal_info: bool,
+    load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+    hash_comparer: Box<dyn HashComparer>,
+    encrypter: Box<dyn Encrypter>,
+    update_account_password_repository: Box<dyn UpdateAccountPasswordRepository>,
+    token_generator: Box<dyn TokenGenerator>,
+    clock: Box<dyn Clock>,
+}
+
+impl DbChangePassword {
+    /// Rejects new passwords equal to the owner's name or email when
+    /// `reject_personal_info` is set, the body validation can't see them.
+    pub fn new(
+        reject_personal_info: bool,
+        load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+        hash_comparer: Box<dyn HashComparer>,
+        encrypter: Box<dyn Encrypter>,
+        update_account_password_repository: Box<dyn UpdateAccountPasswordRepository>,
+        token_generator: Box<dyn TokenGenerator>,
+        clock: Box<dyn Clock>,
+    ) -> Self {
+        Self {
+            reject_personal_info,
+            load_account_by_id_repository,
+            hash_comparer,
+            encrypter,
+            update_account_password_repository,
+            token_generator,
+            clock,
+        }
+    }
+
+    /// Set the db change password's reject personal info.
+    pub fn set_reject_personal_info(&mut self, reject_personal_info: bool) {
+        self.reject_personal_info = reject_personal_info;
+    }
+
+    /// Set the db change password's load account by id repository.
+    pub fn set_load_account_by_id_repository(
+        &mut self,
+        load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+    ) {
+        self.load_account_by_id_repository = load_account_by_id_repository;
+    }
+
+    /// Set the db change password's hash comparer.
+    pub fn set_hash_comparer(&mut self, hash_comparer: Box<dyn HashComparer>) {
+        self.hash_comparer = hash_comparer;
+    }
+
+    /// Set the db change password's encrypter.
+    pub fn set_encrypter(&mut self, encrypter: Box<dyn Encrypter>) {
+        self.encrypter = encrypter;
+    }
+
+    /// Set the db change password's update account password repository.
+    pub fn set_update_account_password_repository(
+        &mut self,
+        update_account_password_repository: Box<dyn UpdateAccountPasswordRepository>,
+    ) {
+        self.update_account_password_repository = update_account_password_repository;
+    }
+
+    /// Set the db change password's token generator.
+    pub fn set_token_generator(&mut self, token_generator: Box<dyn TokenGenerator>) {
+        self.token_generator = token_generator;
+    }
+
+    /// Set the db change password's clock.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+}
+
+#[async_trait]
+impl ChangePassword for DbChangePassword {
+    async fn change(
+        &self,
+        change_password_dto: ChangePasswordDto,
+    ) -> GenericResult<String, DomainError> {
+        let ChangePasswordDto {
+            account_id,
+            current_password,
+            new_password,
+        } = &change_password_dto;
+
+        let account = self
+            .load_account_by_id_repository
+            .load_by_id(account_id)
+            .await?
+            .ok_or(DomainError::InvalidCredentials)?;
+
+        let is_valid = self
+            .hash_comparer
+            .compare(current_password, account.password())
+            .await?;
+
+        if !is_valid {
+            return Err(DomainError::IncorrectPassword);
+        }
+
+        if self.reject_personal_info
+            && is_personal_info(new_password, &[account.name(), account.email()])
+        {
+            return Err(DomainError::PasswordContainsPersonalInfo);
+        }
+
+        let hashed_password = self.encrypter.encrypt(new_password).await?;
+
+        // Bumping the token version revokes every access token issued before.
+        self.update_account_password_repository
+            .update_password(account.id(), &hashed_password, self.clock.now())
+            .await?;
+
+        // A change racing this one bumps the version again, which only revokes
+        // this token as well.
+        let access_token = self
+            .token_generator
+            .generate(account.id(), account.token_version() + 1)
+            .await?;
+
+        Ok(access_token)
+    }
+}
@@ -0,0 +1,380 @@
+use chrono::{TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::Clock;
+#[double]
+use crate::data::protocols::Encrypter;
+#[double]
+use crate::data::protocols::HashComparer;
+#[double]
+use crate::data::protocols::LoadAccountByIdRepository;
+#[double]
+use crate::data::protocols::TokenGenerator;
+#[double]
+use crate::data::protocols::UpdateAccountPasswordRepository;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::{ChangePassword, ChangePasswordDto};
+
+use super::DbChangePassword;
+
+macro_rules! load_account_by_id_repository_load_by_id_default {
+    () => {
+        |id| {
+            Ok(Some(
+                AccountEntity::new(
+                    id,
+                    "any_name",
+                    "any_email@mail.com",
+                    "hashed_password",
+                    Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+                )
+                .with_token_version(3),
+            ))
+        }
+    };
+}
+
+macro_rules! hash_comparer_compare_default {
+    () => {
+        |_, _| Ok(true)
+    };
+}
+
+macro_rules! encrypter_encrypt_default {
+    () => {
+        |_| Ok(String::from("new_hashed_password"))
+    };
+}
+
+macro_rules! update_account_password_repository_update_password_default {
+    () => {
+        |_, _, _| Ok(())
+    };
+}
+
+macro_rules! token_generator_generate_default {
+    () => {
+        |_, _| Ok(String::from("any_token"))
+    };
+}
+
+macro_rules! clock_now_default {
+    () => {
+        || Utc.ymd(2022, 1, 2).and_hms(0, 0, 0)
+    };
+}
+
+fn make_sut() -> DbChangePassword {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(load_account_by_id_repository_load_by_id_default!());
+
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(hash_comparer_compare_default!());
+
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .returning(encrypter_encrypt_default!());
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .returning(update_account_password_repository_update_password_default!());
+
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .returning(token_generator_generate_default!());
+
+    let mut clock = make_clock();
+    clock.expect_now().returning(clock_now_default!());
+
+    DbChangePassword::new(
+        true,
+        load_account_by_id_repository,
+        hash_comparer,
+        encrypter,
+        update_account_password_repository,
+        token_generator,
+        clock,
+    )
+}
+
+fn make_load_account_by_id_repository() -> Box<LoadAccountByIdRepository> {
+    Box::new(LoadAccountByIdRepository::default())
+}
+
+fn make_hash_comparer() -> Box<HashComparer> {
+    Box::new(HashComparer::default())
+}
+
+fn make_encrypter() -> Box<Encrypter> {
+    Box::new(Encrypter::default())
+}
+
+fn make_update_account_password_repository() -> Box<UpdateAccountPasswordRepository> {
+    Box::new(UpdateAccountPasswordRepository::default())
+}
+
+fn make_token_generator() -> Box<TokenGenerator> {
+    Box::new(TokenGenerator::default())
+}
+
+fn make_clock() -> Box<Clock> {
+    Box::new(Clock::default())
+}
+
+fn make_change_password_dto() -> ChangePasswordDto {
+    ChangePasswordDto {
+        account_id: String::from("any_id"),
+        current_password: String::from("current_password"),
+        new_password: String::from("new_password"),
+    }
+}
+
+#[tokio::test]
+async fn calls_load_account_by_id_repository_with_correct_id() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .with(predicate::eq("any_id"))
+        .once()
+        .returning(load_account_by_id_repository_load_by_id_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    let _ = sut.change(make_change_password_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_invalid_credentials_if_the_account_is_not_found() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(|_| Ok(None));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    let err = sut.change(make_change_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidCredentials));
+}
+
+#[tokio::test]
+async fn returns_err_if_load_account_by_id_repository_returns_err() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    let err = sut.change(make_change_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn compares_the_current_password_with_the_account_hash() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .with(
+            predicate::eq("current_password"),
+            predicate::eq("hashed_password"),
+        )
+        .once()
+        .returning(hash_comparer_compare_default!());
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let _ = sut.change(make_change_password_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_incorrect_password_if_the_current_password_does_not_match() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer.expect_compare().returning(|_, _| Ok(false));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.change(make_change_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::IncorrectPassword));
+}
+
+#[tokio::test]
+async fn returns_err_if_hash_comparer_returns_err() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(|_, _| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let err = sut.change(make_change_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_password_contains_personal_info_if_the_new_password_is_the_name_or_email() {
+    let mut encrypter = make_encrypter();
+    encrypter.expect_encrypt().never();
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+
+    for new_password in ["Any_Email@mail.com", "any_email", "ANY_NAME"] {
+        let change_password_dto = ChangePasswordDto {
+            new_password: String::from(new_password),
+            ..make_change_password_dto()
+        };
+
+        let err = sut.change(change_password_dto).await.unwrap_err();
+
+        assert!(matches!(err, DomainError::PasswordContainsPersonalInfo));
+    }
+}
+
+#[tokio::test]
+async fn accepts_personal_info_if_reject_personal_info_is_unset() {
+    let mut sut = make_sut();
+    sut.set_reject_personal_info(false);
+
+    let change_password_dto = ChangePasswordDto {
+        new_password: String::from("any_email"),
+        ..make_change_password_dto()
+    };
+
+    let result = sut.change(change_password_dto).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn hashes_the_new_password() {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .with(predicate::eq("new_password"))
+        .once()
+        .returning(encrypter_encrypt_default!());
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+
+    let _ = sut.change(make_change_password_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_err_if_encrypter_returns_err() {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .returning(|_| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.change(make_change_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn updates_the_password_and_its_change_date() {
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .with(
+            predicate::eq("any_id"),
+            predicate::eq("new_hashed_password"),
+            predicate::eq(Utc.ymd(2022, 1, 2).and_hms(0, 0, 0)),
+        )
+        .once()
+        .returning(update_account_password_repository_update_password_default!());
+
+    let mut sut = make_sut();
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let _ = sut.change(make_change_password_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_err_if_update_account_password_repository_returns_err() {
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .returning(|_, _, _| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut token_generator = make_token_generator();
+    token_generator.expect_generate().never();
+
+    let mut sut = make_sut();
+    sut.set_update_account_password_repository(update_account_password_repository);
+    sut.set_token_generator(token_generator);
+
+    let err = sut.change(make_change_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn generates_a_new_access_token_for_the_bumped_token_version() {
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .with(predicate::eq("any_id"), predicate::eq(4))
+        .once()
+        .returning(token_generator_generate_default!());
+
+    let mut sut = make_sut();
+    sut.set_token_generator(token_generator);
+
+    let access_token = sut.change(make_change_password_dto()).await.unwrap();
+
+    assert_eq!(access_token, "any_token");
+}
+
+#[tokio::test]
+async fn returns_err_if_token_generator_returns_err() {
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .returning(|_, _| Err(DataError::TokenFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_token_generator(token_generator);
+
+    let err = sut.change(make_change_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
@@ -0,0 +1,3 @@
+pub mod db_issue_verification_token;
+
+pub use db_issue_verification_token::{DbIssueVerificationToken, VerificationTokenMail};
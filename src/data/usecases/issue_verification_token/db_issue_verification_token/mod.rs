@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddVerificationTokenRepository, Clock, Encrypter, IssueVerificationToken,
+    LoadLatestVerificationTokenRepository, Mail, Mailer, RandomTokenGenerator,
+};
+use crate::domain::entities::{AccountEntity, TokenPurpose, VerificationTokenEntity};
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+/// What the tokens are issued for and how they are mailed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationTokenMail {
+    pub purpose: TokenPurpose,
+    pub ttl: Duration,
+    /// No new token is mailed while the last one is unexpired and was
+    /// issued less than this long ago, so an address can't be flooded.
+    pub cooldown: Duration,
+    /// Page the mailed link points to, the token is appended as the `token`
+    /// query param.
+    pub link_url: String,
+    pub subject: String,
+    /// Body of the mail, where `{name}`, `{link}` and `{minutes}` stand for
+    /// the account name, the link and the token lifetime.
+    pub template: String,
+}
+
+impl VerificationTokenMail {
+    fn render(&self, account: &AccountEntity, token: &str) -> String {
+        let link = format!("{}?token={}", self.link_url, token);
+
+        self.template
+            .replace("{link}", &link)
+            .replace("{minutes}", &self.ttl.num_minutes().to_string())
+            .replace("{name}", account.name())
+    }
+}
+
+pub struct DbIssueVerificationToken {
+    mail: VerificationTokenMail,
+    load_latest_verification_token_repository: Box<dyn LoadLatestVerificationTokenRepository>,
+    random_token_generator: Box<dyn RandomTokenGenerator>,
+    encrypter: Box<dyn Encrypter>,
+    add_verification_token_repository: Box<dyn AddVerificationTokenRepository>,
+    mailer: Box<dyn Mailer>,
+    clock: Box<dyn Clock>,
+}
+
+impl DbIssueVerificationToken {
+    /// The `encrypter` hashes the tokens before storing them. It must be
+    /// deterministic (e.g. sha2) for the tokens to be found again.
+    pub fn new(
+        mail: VerificationTokenMail,
+        load_latest_verification_token_repository: Box<dyn LoadLatestVerificationTokenRepository>,
+        random_token_generator: Box<dyn RandomTokenGenerator>,
+        encrypter: Box<dyn Encrypter>,
+        add_verification_token_repository: Box<dyn AddVerificationTokenRepository>,
+        mailer: Box<dyn Mailer>,
+        clock: Box<dyn Clock>,
+    ) -> Self {
+        Self {
+            mail,
+            load_latest_verification_token_repository,
+            random_token_generator,
+            encrypter,
+            add_verification_token_repository,
+            mailer,
+            clock,
+        }
+    }
+
+    /// Set the db issue verification token's load latest verification token repository.
+    pub fn set_load_latest_verification_token_repository(
+        &mut self,
+        load_latest_verification_token_repository: Box<dyn LoadLatestVerificationTokenRepository>,
+    ) {
+        self.load_latest_verification_token_repository = load_latest_verification_token_repository;
+    }
+
+    /// Set the db issue verification token's random token generator.
+    pub fn set_random_token_generator(
+        &mut self,
+        random_token_generator: Box<dyn RandomTokenGenerator>,
+    ) {
+        self.random_token_generator = random_token_generator;
+    }
+
+    /// Set the db issue verification token's encrypter.
+    pub fn set_encrypter(&mut self, encrypter: Box<dyn Encrypter>) {
+        self.encrypter = encrypter;
+    }
+
+    /// Set the db issue verification token's add verification token repository.
+    pub fn set_add_verification_token_repository(
+        &mut self,
+        add_verification_token_repository: Box<dyn AddVerificationTokenRepository>,
+    ) {
+        self.add_verification_token_repository = add_verification_token_repository;
+    }
+
+    /// Set the db issue verification token's mailer.
+    pub fn set_mailer(&mut self, mailer: Box<dyn Mailer>) {
+        self.mailer = mailer;
+    }
+
+    /// Set the db issue verification token's clock.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+}
+
+#[async_trait]
+impl IssueVerificationToken for DbIssueVerificationToken {
+    /// Does nothing while the cooldown of the last token issued for the
+    /// same purpose runs.
+    async fn issue(&self, account: &AccountEntity) -> GenericResult<(), DataError> {
+        let now = self.clock.now();
+
+        let latest_token = self
+            .load_latest_verification_token_repository
+            .load_latest(account.id(), self.mail.purpose)
+            .await?;
+
+        if let Some(latest_token) = latest_token {
+            let cooling_down = matches!(
+                latest_token.issued_at(),
+                Some(issued_at) if now < issued_at + self.mail.cooldown
+            );
+
+            if cooling_down && !latest_token.is_expired(now) {
+                return Ok(());
+            }
+        }
+
+        let token = self.random_token_generator.generate();
+        let token_hash = self.encrypter.encrypt(&token).await?;
+
+        self.add_verification_token_repository
+            .add(
+                VerificationTokenEntity::new(
+                    account.id(),
+                    &token_hash,
+                    self.mail.purpose,
+                    now + self.mail.ttl,
+                )
+                .with_issued_at(Some(now)),
+            )
+            .await?;
+
+        self.mailer
+            .send(Mail {
+                to: account.email().to_string(),
+                subject: self.mail.subject.clone(),
+                body: self.mail.render(account, &token),
+            })
+            .await
+    }
+}
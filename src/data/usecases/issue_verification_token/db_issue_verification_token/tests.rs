@@ -0,0 +1,383 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::AddVerificationTokenRepository;
+#[double]
+use crate::data::protocols::Clock;
+#[double]
+use crate::data::protocols::Encrypter;
+#[double]
+use crate::data::protocols::LoadLatestVerificationTokenRepository;
+#[double]
+use crate::data::protocols::Mailer;
+#[double]
+use crate::data::protocols::RandomTokenGenerator;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{IssueVerificationToken, Mail};
+use crate::domain::entities::{AccountEntity, TokenPurpose, VerificationTokenEntity};
+
+use super::{DbIssueVerificationToken, VerificationTokenMail};
+
+macro_rules! load_latest_verification_token_repository_load_latest_default {
+    () => {
+        |_, _| Ok(None)
+    };
+}
+
+macro_rules! random_token_generator_generate_default {
+    () => {
+        || String::from("any_token")
+    };
+}
+
+macro_rules! encrypter_encrypt_default {
+    () => {
+        |_| Ok(String::from("hashed_token"))
+    };
+}
+
+macro_rules! add_verification_token_repository_add_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+macro_rules! mailer_send_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+macro_rules! clock_now_default {
+    () => {
+        || Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+    };
+}
+
+fn make_sut() -> DbIssueVerificationToken {
+    let mut load_latest_verification_token_repository =
+        make_load_latest_verification_token_repository();
+    load_latest_verification_token_repository
+        .expect_load_latest()
+        .returning(load_latest_verification_token_repository_load_latest_default!());
+
+    let mut random_token_generator = make_random_token_generator();
+    random_token_generator
+        .expect_generate()
+        .returning(random_token_generator_generate_default!());
+
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .returning(encrypter_encrypt_default!());
+
+    let mut add_verification_token_repository = make_add_verification_token_repository();
+    add_verification_token_repository
+        .expect_add()
+        .returning(add_verification_token_repository_add_default!());
+
+    let mut mailer = make_mailer();
+    mailer.expect_send().returning(mailer_send_default!());
+
+    let mut clock = make_clock();
+    clock.expect_now().returning(clock_now_default!());
+
+    DbIssueVerificationToken::new(
+        VerificationTokenMail {
+            purpose: TokenPurpose::PasswordReset,
+            ttl: Duration::minutes(30),
+            cooldown: Duration::minutes(1),
+            link_url: String::from("http://any_url/reset-password"),
+            subject: String::from("any_subject"),
+            template: String::from("Hi {name}, open {link} within {minutes} minutes."),
+        },
+        load_latest_verification_token_repository,
+        random_token_generator,
+        encrypter,
+        add_verification_token_repository,
+        mailer,
+        clock,
+    )
+}
+
+fn make_load_latest_verification_token_repository() -> Box<LoadLatestVerificationTokenRepository> {
+    Box::new(LoadLatestVerificationTokenRepository::default())
+}
+
+fn make_random_token_generator() -> Box<RandomTokenGenerator> {
+    Box::new(RandomTokenGenerator::default())
+}
+
+fn make_encrypter() -> Box<Encrypter> {
+    Box::new(Encrypter::default())
+}
+
+fn make_add_verification_token_repository() -> Box<AddVerificationTokenRepository> {
+    Box::new(AddVerificationTokenRepository::default())
+}
+
+fn make_mailer() -> Box<Mailer> {
+    Box::new(Mailer::default())
+}
+
+fn make_clock() -> Box<Clock> {
+    Box::new(Clock::default())
+}
+
+fn make_account() -> AccountEntity {
+    AccountEntity::new(
+        "any_id",
+        "any_name",
+        "any_email@mail.com",
+        "hashed_password",
+        Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+    )
+}
+
+/// A token issued for the account at `issued_at` and expiring 30 minutes
+/// later.
+fn make_latest_token(issued_at: DateTime<Utc>) -> VerificationTokenEntity {
+    VerificationTokenEntity::new(
+        "any_id",
+        "latest_hashed_token",
+        TokenPurpose::PasswordReset,
+        issued_at + Duration::minutes(30),
+    )
+    .with_issued_at(Some(issued_at))
+}
+
+#[tokio::test]
+async fn loads_the_latest_token_of_the_account_for_the_purpose() {
+    let mut load_latest_verification_token_repository =
+        make_load_latest_verification_token_repository();
+    load_latest_verification_token_repository
+        .expect_load_latest()
+        .with(
+            predicate::eq("any_id"),
+            predicate::eq(TokenPurpose::PasswordReset),
+        )
+        .once()
+        .returning(load_latest_verification_token_repository_load_latest_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_latest_verification_token_repository(load_latest_verification_token_repository);
+
+    let _ = sut.issue(&make_account()).await;
+}
+
+#[tokio::test]
+async fn does_nothing_within_the_cooldown_of_the_latest_token() {
+    let mut load_latest_verification_token_repository =
+        make_load_latest_verification_token_repository();
+    load_latest_verification_token_repository
+        .expect_load_latest()
+        .returning(|_, _| {
+            Ok(Some(make_latest_token(
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0) - Duration::seconds(59),
+            )))
+        });
+
+    let mut add_verification_token_repository = make_add_verification_token_repository();
+    add_verification_token_repository.expect_add().never();
+
+    let mut mailer = make_mailer();
+    mailer.expect_send().never();
+
+    let mut sut = make_sut();
+    sut.set_load_latest_verification_token_repository(load_latest_verification_token_repository);
+    sut.set_add_verification_token_repository(add_verification_token_repository);
+    sut.set_mailer(mailer);
+
+    assert!(sut.issue(&make_account()).await.is_ok());
+}
+
+#[tokio::test]
+async fn issues_a_new_token_once_the_cooldown_is_over() {
+    let mut load_latest_verification_token_repository =
+        make_load_latest_verification_token_repository();
+    load_latest_verification_token_repository
+        .expect_load_latest()
+        .returning(|_, _| {
+            Ok(Some(make_latest_token(
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0) - Duration::minutes(1),
+            )))
+        });
+
+    let mut mailer = make_mailer();
+    mailer
+        .expect_send()
+        .once()
+        .returning(mailer_send_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_latest_verification_token_repository(load_latest_verification_token_repository);
+    sut.set_mailer(mailer);
+
+    assert!(sut.issue(&make_account()).await.is_ok());
+}
+
+#[tokio::test]
+async fn issues_a_new_token_if_the_latest_one_is_expired() {
+    let mut load_latest_verification_token_repository =
+        make_load_latest_verification_token_repository();
+    load_latest_verification_token_repository
+        .expect_load_latest()
+        .returning(|_, _| {
+            let issued_at = Utc.ymd(2022, 1, 1).and_hms(0, 0, 0) - Duration::seconds(1);
+            let token = VerificationTokenEntity::new(
+                "any_id",
+                "latest_hashed_token",
+                TokenPurpose::PasswordReset,
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            );
+            Ok(Some(token.with_issued_at(Some(issued_at))))
+        });
+
+    let mut mailer = make_mailer();
+    mailer
+        .expect_send()
+        .once()
+        .returning(mailer_send_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_latest_verification_token_repository(load_latest_verification_token_repository);
+    sut.set_mailer(mailer);
+
+    assert!(sut.issue(&make_account()).await.is_ok());
+}
+
+#[tokio::test]
+async fn returns_err_if_load_latest_verification_token_repository_returns_err() {
+    let mut load_latest_verification_token_repository =
+        make_load_latest_verification_token_repository();
+    load_latest_verification_token_repository
+        .expect_load_latest()
+        .returning(|_, _| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut mailer = make_mailer();
+    mailer.expect_send().never();
+
+    let mut sut = make_sut();
+    sut.set_load_latest_verification_token_repository(load_latest_verification_token_repository);
+    sut.set_mailer(mailer);
+
+    let err = sut.issue(&make_account()).await.unwrap_err();
+
+    assert!(matches!(err, DataError::DatabaseFailed(_)));
+}
+
+#[tokio::test]
+async fn hashes_the_generated_token() {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .with(predicate::eq("any_token"))
+        .once()
+        .returning(encrypter_encrypt_default!());
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+
+    let _ = sut.issue(&make_account()).await;
+}
+
+#[tokio::test]
+async fn stores_the_token_hash_with_its_purpose_and_dates() {
+    let mut add_verification_token_repository = make_add_verification_token_repository();
+    add_verification_token_repository
+        .expect_add()
+        .with(predicate::eq(
+            VerificationTokenEntity::new(
+                "any_id",
+                "hashed_token",
+                TokenPurpose::PasswordReset,
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0) + Duration::minutes(30),
+            )
+            .with_issued_at(Some(Utc.ymd(2022, 1, 1).and_hms(0, 0, 0))),
+        ))
+        .once()
+        .returning(add_verification_token_repository_add_default!());
+
+    let mut sut = make_sut();
+    sut.set_add_verification_token_repository(add_verification_token_repository);
+
+    let _ = sut.issue(&make_account()).await;
+}
+
+#[tokio::test]
+async fn mails_the_link_to_the_account_email() {
+    let mut mailer = make_mailer();
+    mailer
+        .expect_send()
+        .with(predicate::eq(Mail {
+            to: String::from("any_email@mail.com"),
+            subject: String::from("any_subject"),
+            body: String::from(
+                "Hi any_name, open http://any_url/reset-password?token=any_token \
+                 within 30 minutes.",
+            ),
+        }))
+        .once()
+        .returning(mailer_send_default!());
+
+    let mut sut = make_sut();
+    sut.set_mailer(mailer);
+
+    assert!(sut.issue(&make_account()).await.is_ok());
+}
+
+#[tokio::test]
+async fn returns_err_if_encrypter_returns_err() {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .returning(|_| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut add_verification_token_repository = make_add_verification_token_repository();
+    add_verification_token_repository.expect_add().never();
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+    sut.set_add_verification_token_repository(add_verification_token_repository);
+
+    let err = sut.issue(&make_account()).await.unwrap_err();
+
+    assert!(matches!(err, DataError::HashingFailed(_)));
+}
+
+#[tokio::test]
+async fn does_not_mail_if_storing_the_token_fails() {
+    let mut add_verification_token_repository = make_add_verification_token_repository();
+    add_verification_token_repository
+        .expect_add()
+        .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut mailer = make_mailer();
+    mailer.expect_send().never();
+
+    let mut sut = make_sut();
+    sut.set_add_verification_token_repository(add_verification_token_repository);
+    sut.set_mailer(mailer);
+
+    let err = sut.issue(&make_account()).await.unwrap_err();
+
+    assert!(matches!(err, DataError::DatabaseFailed(_)));
+}
+
+#[tokio::test]
+async fn returns_err_if_mailer_returns_err() {
+    let mut mailer = make_mailer();
+    mailer
+        .expect_send()
+        .returning(|_| Err(DataError::MailFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_mailer(mailer);
+
+    let err = sut.issue(&make_account()).await.unwrap_err();
+
+    assert!(matches!(err, DataError::MailFailed(_)));
+}
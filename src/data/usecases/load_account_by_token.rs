@@ -0,0 +1,3 @@
+pub mod db_load_account_by_token;
+
+pub use db_load_account_by_token::DbLoadAccountByToken;
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+
+use crate::data::protocols::{LoadAccountByIdRepository, TokenVerifier};
+use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::LoadAccountByToken;
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct DbLoadAccountByToken {
+    token_verifier: Box<dyn TokenVerifier>,
+    load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+}
+
+impl DbLoadAccountByToken {
+    pub fn new(
+        token_verifier: Box<dyn TokenVerifier>,
+        load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+    ) -> Self {
+        Self {
+            token_verifier,
+            load_account_by_id_repository,
+        }
+    }
+
+    /// Set the db load account by token's token verifier.
+    pub fn set_token_verifier(&mut self, token_verifier: Box<dyn TokenVerifier>) {
+        self.token_verifier = token_verifier;
+    }
+
+    /// Set the db load account by token's load account by id repository.
+    pub fn set_load_account_by_id_repository(
+        &mut self,
+        load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+    ) {
+        self.load_account_by_id_repository = load_account_by_id_repository;
+    }
+}
+
+#[async_trait]
+impl LoadAccountByToken for DbLoadAccountByToken {
+    async fn load(&self, access_token: &str) -> GenericResult<Option<AccountEntity>, DomainError> {
+        let token = match self.token_verifier.verify(access_token).await? {
+            Some(token) => token,
+            None => return Ok(None),
+        };
+
+        let account = match self
+            .load_account_by_id_repository
+            .load_by_id(&token.subject)
+            .await?
+        {
+            Some(account) => account,
+            None => return Ok(None),
+        };
+
+        // Every password change bumps the version, revoking older tokens.
+        if token.token_version != account.token_version() {
+            return Ok(None);
+        }
+
+        Ok(Some(account))
+    }
+}
@@ -0,0 +1,181 @@
+use chrono::{TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::LoadAccountByIdRepository;
+#[double]
+use crate::data::protocols::TokenVerifier;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::VerifiedToken;
+use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::LoadAccountByToken;
+
+use super::DbLoadAccountByToken;
+
+macro_rules! token_verifier_verify_default {
+    () => {
+        |_| {
+            Ok(Some(VerifiedToken {
+                subject: String::from("any_id"),
+                token_version: 1,
+            }))
+        }
+    };
+}
+
+macro_rules! load_account_by_id_repository_load_by_id_default {
+    () => {
+        |id| Ok(Some(make_account(id).with_token_version(1)))
+    };
+}
+
+fn make_sut() -> DbLoadAccountByToken {
+    let mut token_verifier = make_token_verifier();
+    token_verifier
+        .expect_verify()
+        .returning(token_verifier_verify_default!());
+
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(load_account_by_id_repository_load_by_id_default!());
+
+    DbLoadAccountByToken::new(token_verifier, load_account_by_id_repository)
+}
+
+fn make_token_verifier() -> Box<TokenVerifier> {
+    Box::new(TokenVerifier::default())
+}
+
+fn make_load_account_by_id_repository() -> Box<LoadAccountByIdRepository> {
+    Box::new(LoadAccountByIdRepository::default())
+}
+
+fn make_account(id: &str) -> AccountEntity {
+    AccountEntity::new(
+        id,
+        "any_name",
+        "any_email@mail.com",
+        "hashed_password",
+        Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+    )
+}
+
+fn make_sut_with_token_version(token_version: i64) -> DbLoadAccountByToken {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(move |id| Ok(Some(make_account(id).with_token_version(token_version))));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    sut
+}
+
+#[tokio::test]
+async fn calls_token_verifier_with_correct_token() {
+    let mut token_verifier = make_token_verifier();
+    token_verifier
+        .expect_verify()
+        .with(predicate::eq("any_token"))
+        .once()
+        .returning(token_verifier_verify_default!());
+
+    let mut sut = make_sut();
+    sut.set_token_verifier(token_verifier);
+
+    let _ = sut.load("any_token").await;
+}
+
+#[tokio::test]
+async fn returns_none_if_token_verifier_returns_none() {
+    let mut token_verifier = make_token_verifier();
+    token_verifier.expect_verify().returning(|_| Ok(None));
+
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository.expect_load_by_id().never();
+
+    let mut sut = make_sut();
+    sut.set_token_verifier(token_verifier);
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    assert_eq!(sut.load("any_token").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn returns_err_if_token_verifier_returns_err() {
+    let mut token_verifier = make_token_verifier();
+    token_verifier
+        .expect_verify()
+        .returning(|_| Err(DataError::TokenFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_token_verifier(token_verifier);
+
+    let err = sut.load("any_token").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn loads_the_account_the_token_was_issued_for() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .with(predicate::eq("any_id"))
+        .once()
+        .returning(load_account_by_id_repository_load_by_id_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    let _ = sut.load("any_token").await;
+}
+
+#[tokio::test]
+async fn returns_none_if_the_account_no_longer_exists() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(|_| Ok(None));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    assert_eq!(sut.load("any_token").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn returns_err_if_load_account_by_id_repository_returns_err() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    let err = sut.load("any_token").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_none_if_the_password_changed_since_the_token_was_issued() {
+    let sut = make_sut_with_token_version(2);
+
+    assert_eq!(sut.load("any_token").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn returns_the_account_on_success() {
+    let sut = make_sut();
+
+    let account = sut.load("any_token").await.unwrap();
+
+    assert_eq!(account, Some(make_account("any_id").with_token_version(1)));
+}
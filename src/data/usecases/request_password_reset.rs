@@ -0,0 +1,3 @@
+pub mod db_request_password_reset;
+
+pub use db_request_password_reset::DbRequestPasswordReset;
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+
+use crate::data::protocols::{IssueVerificationToken, LoadAccountByEmailRepository};
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::RequestPasswordReset;
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct DbRequestPasswordReset {
+    load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
+    issue_verification_token: Box<dyn IssueVerificationToken>,
+}
+
+impl DbRequestPasswordReset {
+    /// The `issue_verification_token` must issue password reset tokens.
+    pub fn new(
+        load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
+        issue_verification_token: Box<dyn IssueVerificationToken>,
+    ) -> Self {
+        Self {
+            load_account_by_email_repository,
+            issue_verification_token,
+        }
+    }
+
+    /// Set the db request password reset's load account by email repository.
+    pub fn set_load_account_by_email_repository(
+        &mut self,
+        load_account_by_email_repository: Box<dyn LoadAccountByEmailRepository>,
+    ) {
+        self.load_account_by_email_repository = load_account_by_email_repository;
+    }
+
+    /// Set the db request password reset's issue verification token.
+    pub fn set_issue_verification_token(
+        &mut self,
+        issue_verification_token: Box<dyn IssueVerificationToken>,
+    ) {
+        self.issue_verification_token = issue_verification_token;
+    }
+}
+
+#[async_trait]
+impl RequestPasswordReset for DbRequestPasswordReset {
+    async fn request(&self, email: &str) -> GenericResult<(), DomainError> {
+        let account = self
+            .load_account_by_email_repository
+            .load_by_email(email)
+            .await?;
+
+        let account = match account {
+            Some(account) => account,
+            None => return Ok(()),
+        };
+
+        self.issue_verification_token.issue(&account).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod db_reset_password;
+
+pub use db_reset_password::DbResetPassword;
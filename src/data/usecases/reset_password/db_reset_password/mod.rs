@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+
+use crate::data::protocols::{
+    Clock, ConsumeVerificationTokenRepository, Encrypter, LoadAccountByIdRepository,
+    LoadVerificationTokenByHashRepository, UpdateAccountPasswordRepository,
+};
+use crate::domain::entities::TokenPurpose;
+use crate::domain::errors::DomainError;
+use crate::domain::password::is_personal_info;
+use crate::domain::usecases::{ResetPassword, ResetPasswordDto};
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct DbResetPassword {
+    reject_personal_info: bool,
+    token_encrypter: Box<dyn Encrypter>,
+    load_verification_token_by_hash_repository: Box<dyn LoadVerificationTokenByHashRepository>,
+    load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+    password_encrypter: Box<dyn Encrypter>,
+    consume_verification_token_repository: Box<dyn ConsumeVerificationTokenRepository>,
+    update_account_password_repository: Box<dyn UpdateAccountPasswordRepository>,
+    clock: Box<dyn Clock>,
+}
+
+impl DbResetPassword {
+    /// The `token_encrypter` must hash the tokens the same way as the one
+    /// used to request the reset, the `password_encrypter` the same way as
+    /// the one used on signup. New passwords equal to the owner's name or
+    /// email are rejected when `reject_personal_info` is set. The token is
+    /// only consumed once the new password is accepted, so a rejected
+    /// password can be retried with the same link.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        reject_personal_info: bool,
+        token_encrypter: Box<dyn Encrypter>,
+        load_verification_token_by_hash_repository: Box<dyn LoadVerificationTokenByHashRepository>,
+        load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+        password_encrypter: Box<dyn Encrypter>,
+        consume_verification_token_repository: Box<dyn ConsumeVerificationTokenRepository>,
+        update_account_password_repository: Box<dyn UpdateAccountPasswordRepository>,
+        clock: Box<dyn Clock>,
+    ) -> Self {
+        Self {
+            reject_personal_info,
+            token_encrypter,
+            load_verification_token_by_hash_repository,
+            load_account_by_id_repository,
+            password_encrypter,
+            consume_verification_token_repository,
+            update_account_password_repository,
+            clock,
+        }
+    }
+
+    /// Set the db reset password's reject personal info.
+    pub fn set_reject_personal_info(&mut self, reject_personal_info: bool) {
+        self.reject_personal_info = reject_personal_info;
+    }
+
+    /// Set the db reset password's token encrypter.
+    pub fn set_token_encrypter(&mut self, token_encrypter: Box<dyn Encrypter>) {
+        self.token_encrypter = token_encrypter;
+    }
+
+    /// Set the db reset password's load verification token by hash repository.
+    pub fn set_load_verification_token_by_hash_repository(
+        &mut self,
+        load_verification_token_by_hash_repository: Box<dyn LoadVerificationTokenByHashRepository>,
+    ) {
+        self.load_verification_token_by_hash_repository =
+            load_verification_token_by_hash_repository;
+    }
+
+    /// Set the db reset password's load account by id repository.
+    pub fn set_load_account_by_id_repository(
+        &mut self,
+        load_account_by_id_repository: Box<dyn LoadAccountByIdRepository>,
+    ) {
+        self.load_account_by_id_repository = load_account_by_id_repository;
+    }
+
+    /// Set the db reset password's password encrypter.
+    pub fn set_password_encrypter(&mut self, password_encrypter: Box<dyn Encrypter>) {
+        self.password_encrypter = password_encrypter;
+    }
+
+    /// Set the db reset password's consume verification token repository.
+    pub fn set_consume_verification_token_repository(
+        &mut self,
+        consume_verification_token_repository: Box<dyn ConsumeVerificationTokenRepository>,
+    ) {
+        self.consume_verification_token_repository = consume_verification_token_repository;
+    }
+
+    /// Set the db reset password's update account password repository.
+    pub fn set_update_account_password_repository(
+        &mut self,
+        update_account_password_repository: Box<dyn UpdateAccountPasswordRepository>,
+    ) {
+        self.update_account_password_repository = update_account_password_repository;
+    }
+
+    /// Set the db reset password's clock.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+}
+
+#[async_trait]
+impl ResetPassword for DbResetPassword {
+    async fn reset(&self, reset_password_dto: ResetPasswordDto) -> GenericResult<(), DomainError> {
+        let ResetPasswordDto { token, password } = &reset_password_dto;
+
+        let token_hash = self.token_encrypter.encrypt(token).await?;
+
+        let token = self
+            .load_verification_token_by_hash_repository
+            .load_by_hash(TokenPurpose::PasswordReset, &token_hash)
+            .await?
+            .ok_or(DomainError::InvalidToken)?;
+
+        let now = self.clock.now();
+
+        if token.is_expired(now) {
+            return Err(DomainError::InvalidToken);
+        }
+
+        let account = self
+            .load_account_by_id_repository
+            .load_by_id(token.account_id())
+            .await?
+            .ok_or(DomainError::InvalidToken)?;
+
+        if self.reject_personal_info
+            && is_personal_info(password, &[account.name(), account.email()])
+        {
+            return Err(DomainError::PasswordContainsPersonalInfo);
+        }
+
+        let hashed_password = self.password_encrypter.encrypt(password).await?;
+
+        // Another request may have redeemed the token since it was loaded.
+        self.consume_verification_token_repository
+            .consume(TokenPurpose::PasswordReset, &token_hash)
+            .await?
+            .ok_or(DomainError::InvalidToken)?;
+
+        self.update_account_password_repository
+            .update_password(account.id(), &hashed_password, now)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,492 @@
+use chrono::{TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::Clock;
+#[double]
+use crate::data::protocols::ConsumeVerificationTokenRepository;
+#[double]
+use crate::data::protocols::Encrypter;
+#[double]
+use crate::data::protocols::LoadAccountByIdRepository;
+#[double]
+use crate::data::protocols::LoadVerificationTokenByHashRepository;
+#[double]
+use crate::data::protocols::UpdateAccountPasswordRepository;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::{AccountEntity, TokenPurpose, VerificationTokenEntity};
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::{ResetPassword, ResetPasswordDto};
+
+use super::DbResetPassword;
+
+macro_rules! token_encrypter_encrypt_default {
+    () => {
+        |_| Ok(String::from("hashed_token"))
+    };
+}
+
+macro_rules! load_verification_token_by_hash_repository_load_by_hash_default {
+    () => {
+        |purpose, token_hash| {
+            Ok(Some(VerificationTokenEntity::new(
+                "any_id",
+                token_hash,
+                purpose,
+                Utc.ymd(2022, 1, 2).and_hms(0, 0, 0),
+            )))
+        }
+    };
+}
+
+macro_rules! consume_verification_token_repository_consume_default {
+    () => {
+        |purpose, token_hash| {
+            Ok(Some(VerificationTokenEntity::new(
+                "any_id",
+                token_hash,
+                purpose,
+                Utc.ymd(2022, 1, 2).and_hms(0, 0, 0),
+            )))
+        }
+    };
+}
+
+macro_rules! load_account_by_id_repository_load_by_id_default {
+    () => {
+        |id| {
+            Ok(Some(AccountEntity::new(
+                id,
+                "any_name",
+                "any_email@mail.com",
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        }
+    };
+}
+
+macro_rules! password_encrypter_encrypt_default {
+    () => {
+        |_| Ok(String::from("hashed_password"))
+    };
+}
+
+macro_rules! update_account_password_repository_update_password_default {
+    () => {
+        |_, _, _| Ok(())
+    };
+}
+
+macro_rules! clock_now_default {
+    () => {
+        || Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+    };
+}
+
+fn make_sut() -> DbResetPassword {
+    let mut token_encrypter = make_encrypter();
+    token_encrypter
+        .expect_encrypt()
+        .returning(token_encrypter_encrypt_default!());
+
+    let mut load_verification_token_by_hash_repository =
+        make_load_verification_token_by_hash_repository();
+    load_verification_token_by_hash_repository
+        .expect_load_by_hash()
+        .returning(load_verification_token_by_hash_repository_load_by_hash_default!());
+
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(load_account_by_id_repository_load_by_id_default!());
+
+    let mut password_encrypter = make_encrypter();
+    password_encrypter
+        .expect_encrypt()
+        .returning(password_encrypter_encrypt_default!());
+
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .returning(consume_verification_token_repository_consume_default!());
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .returning(update_account_password_repository_update_password_default!());
+
+    let mut clock = make_clock();
+    clock.expect_now().returning(clock_now_default!());
+
+    DbResetPassword::new(
+        true,
+        token_encrypter,
+        load_verification_token_by_hash_repository,
+        load_account_by_id_repository,
+        password_encrypter,
+        consume_verification_token_repository,
+        update_account_password_repository,
+        clock,
+    )
+}
+
+fn make_encrypter() -> Box<Encrypter> {
+    Box::new(Encrypter::default())
+}
+
+fn make_load_verification_token_by_hash_repository() -> Box<LoadVerificationTokenByHashRepository> {
+    Box::new(LoadVerificationTokenByHashRepository::default())
+}
+
+fn make_consume_verification_token_repository() -> Box<ConsumeVerificationTokenRepository> {
+    Box::new(ConsumeVerificationTokenRepository::default())
+}
+
+fn make_load_account_by_id_repository() -> Box<LoadAccountByIdRepository> {
+    Box::new(LoadAccountByIdRepository::default())
+}
+
+fn make_update_account_password_repository() -> Box<UpdateAccountPasswordRepository> {
+    Box::new(UpdateAccountPasswordRepository::default())
+}
+
+fn make_clock() -> Box<Clock> {
+    Box::new(Clock::default())
+}
+
+fn make_reset_password_dto() -> ResetPasswordDto {
+    ResetPasswordDto {
+        token: String::from("any_token"),
+        password: String::from("new_password"),
+    }
+}
+
+#[tokio::test]
+async fn loads_the_password_reset_token_by_its_hash() {
+    let mut token_encrypter = make_encrypter();
+    token_encrypter
+        .expect_encrypt()
+        .with(predicate::eq("any_token"))
+        .once()
+        .returning(token_encrypter_encrypt_default!());
+
+    let mut load_verification_token_by_hash_repository =
+        make_load_verification_token_by_hash_repository();
+    load_verification_token_by_hash_repository
+        .expect_load_by_hash()
+        .with(
+            predicate::eq(TokenPurpose::PasswordReset),
+            predicate::eq("hashed_token"),
+        )
+        .once()
+        .returning(load_verification_token_by_hash_repository_load_by_hash_default!());
+
+    let mut sut = make_sut();
+    sut.set_token_encrypter(token_encrypter);
+    sut.set_load_verification_token_by_hash_repository(load_verification_token_by_hash_repository);
+
+    let _ = sut.reset(make_reset_password_dto()).await;
+}
+
+#[tokio::test]
+async fn consumes_the_token_once_the_new_password_is_accepted() {
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .with(
+            predicate::eq(TokenPurpose::PasswordReset),
+            predicate::eq("hashed_token"),
+        )
+        .once()
+        .returning(consume_verification_token_repository_consume_default!());
+
+    let mut sut = make_sut();
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+
+    assert!(sut.reset(make_reset_password_dto()).await.is_ok());
+}
+
+#[tokio::test]
+async fn keeps_the_token_if_the_new_password_is_rejected() {
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+
+    let reset_password_dto = ResetPasswordDto {
+        password: String::from("any_email"),
+        ..make_reset_password_dto()
+    };
+
+    let err = sut.reset(reset_password_dto).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::PasswordContainsPersonalInfo));
+}
+
+#[tokio::test]
+async fn keeps_the_token_if_password_encrypter_returns_err() {
+    let mut password_encrypter = make_encrypter();
+    password_encrypter
+        .expect_encrypt()
+        .returning(|_| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_password_encrypter(password_encrypter);
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_invalid_token_if_the_token_was_consumed_meanwhile() {
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .returning(|_, _| Ok(None));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidToken));
+}
+
+#[tokio::test]
+async fn returns_err_if_consume_verification_token_repository_returns_err() {
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .returning(|_, _| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn loads_the_account_the_token_belongs_to() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .with(predicate::eq("any_id"))
+        .once()
+        .returning(load_account_by_id_repository_load_by_id_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    let _ = sut.reset(make_reset_password_dto()).await;
+}
+
+#[tokio::test]
+async fn returns_invalid_token_if_the_account_is_not_found() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(|_| Ok(None));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidToken));
+}
+
+#[tokio::test]
+async fn returns_err_if_load_account_by_id_repository_returns_err() {
+    let mut load_account_by_id_repository = make_load_account_by_id_repository();
+    load_account_by_id_repository
+        .expect_load_by_id()
+        .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_id_repository(load_account_by_id_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_password_contains_personal_info_if_the_new_password_is_the_name_or_email() {
+    let mut password_encrypter = make_encrypter();
+    password_encrypter.expect_encrypt().never();
+
+    let mut sut = make_sut();
+    sut.set_password_encrypter(password_encrypter);
+
+    for password in ["Any_Email@mail.com", "any_email", "ANY_NAME"] {
+        let reset_password_dto = ResetPasswordDto {
+            password: String::from(password),
+            ..make_reset_password_dto()
+        };
+
+        let err = sut.reset(reset_password_dto).await.unwrap_err();
+
+        assert!(matches!(err, DomainError::PasswordContainsPersonalInfo));
+    }
+}
+
+#[tokio::test]
+async fn accepts_personal_info_if_reject_personal_info_is_unset() {
+    let mut sut = make_sut();
+    sut.set_reject_personal_info(false);
+
+    let reset_password_dto = ResetPasswordDto {
+        password: String::from("any_email"),
+        ..make_reset_password_dto()
+    };
+
+    let result = sut.reset(reset_password_dto).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn hashes_the_new_password() {
+    let mut password_encrypter = make_encrypter();
+    password_encrypter
+        .expect_encrypt()
+        .with(predicate::eq("new_password"))
+        .once()
+        .returning(password_encrypter_encrypt_default!());
+
+    let mut sut = make_sut();
+    sut.set_password_encrypter(password_encrypter);
+
+    let _ = sut.reset(make_reset_password_dto()).await;
+}
+
+#[tokio::test]
+async fn updates_the_password_of_the_token_account_and_its_change_date() {
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .with(
+            predicate::eq("any_id"),
+            predicate::eq("hashed_password"),
+            predicate::eq(Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)),
+        )
+        .once()
+        .returning(update_account_password_repository_update_password_default!());
+
+    let mut sut = make_sut();
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    assert!(sut.reset(make_reset_password_dto()).await.is_ok());
+}
+
+#[tokio::test]
+async fn returns_invalid_token_if_the_token_is_not_found() {
+    let mut load_verification_token_by_hash_repository =
+        make_load_verification_token_by_hash_repository();
+    load_verification_token_by_hash_repository
+        .expect_load_by_hash()
+        .returning(|_, _| Ok(None));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_load_verification_token_by_hash_repository(load_verification_token_by_hash_repository);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidToken));
+}
+
+#[tokio::test]
+async fn returns_invalid_token_if_the_token_is_expired() {
+    let mut clock = make_clock();
+    clock
+        .expect_now()
+        .returning(|| Utc.ymd(2022, 1, 2).and_hms(0, 0, 0));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_clock(clock);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidToken));
+}
+
+#[tokio::test]
+async fn returns_err_if_password_encrypter_returns_err() {
+    let mut password_encrypter = make_encrypter();
+    password_encrypter
+        .expect_encrypt()
+        .returning(|_| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_password_encrypter(password_encrypter);
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_err_if_update_account_password_repository_returns_err() {
+    let mut update_account_password_repository = make_update_account_password_repository();
+    update_account_password_repository
+        .expect_update_password()
+        .returning(|_, _, _| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_update_account_password_repository(update_account_password_repository);
+
+    let err = sut.reset(make_reset_password_dto()).await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
@@ -0,0 +1,3 @@
+pub mod db_send_email_verification;
+
+pub use db_send_email_verification::DbSendEmailVerification;
@@ -0,0 +1,160 @@
+use chrono::{TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::IssueVerificationToken;
+#[double]
+use crate::data::protocols::LoadAccountByEmailRepository;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::SendEmailVerification;
+
+use super::DbSendEmailVerification;
+
+macro_rules! load_account_by_email_repository_load_by_email_default {
+    () => {
+        |email| {
+            Ok(Some(AccountEntity::new(
+                "any_id",
+                "any_name",
+                email,
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        }
+    };
+}
+
+macro_rules! issue_verification_token_issue_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+fn make_sut() -> DbSendEmailVerification {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(load_account_by_email_repository_load_by_email_default!());
+
+    let mut issue_verification_token = make_issue_verification_token();
+    issue_verification_token
+        .expect_issue()
+        .returning(issue_verification_token_issue_default!());
+
+    DbSendEmailVerification::new(load_account_by_email_repository, issue_verification_token)
+}
+
+fn make_load_account_by_email_repository() -> Box<LoadAccountByEmailRepository> {
+    Box::new(LoadAccountByEmailRepository::default())
+}
+
+fn make_issue_verification_token() -> Box<IssueVerificationToken> {
+    Box::new(IssueVerificationToken::default())
+}
+
+#[tokio::test]
+async fn calls_load_account_by_email_repository_with_correct_email() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .with(predicate::eq("any_email@mail.com"))
+        .once()
+        .returning(load_account_by_email_repository_load_by_email_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+
+    let _ = sut.send("any_email@mail.com").await;
+}
+
+#[tokio::test]
+async fn does_nothing_if_the_account_is_not_found() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|_| Ok(None));
+
+    let mut issue_verification_token = make_issue_verification_token();
+    issue_verification_token.expect_issue().never();
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+    sut.set_issue_verification_token(issue_verification_token);
+
+    assert!(sut.send("any_email@mail.com").await.is_ok());
+}
+
+#[tokio::test]
+async fn does_nothing_if_the_email_is_already_verified() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|email| {
+            let account = AccountEntity::new(
+                "any_id",
+                "any_name",
+                email,
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            );
+            Ok(Some(account.with_email_verified(true)))
+        });
+
+    let mut issue_verification_token = make_issue_verification_token();
+    issue_verification_token.expect_issue().never();
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+    sut.set_issue_verification_token(issue_verification_token);
+
+    assert!(sut.send("any_email@mail.com").await.is_ok());
+}
+
+#[tokio::test]
+async fn returns_err_if_load_account_by_email_repository_returns_err() {
+    let mut load_account_by_email_repository = make_load_account_by_email_repository();
+    load_account_by_email_repository
+        .expect_load_by_email()
+        .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_email_repository(load_account_by_email_repository);
+
+    let err = sut.send("any_email@mail.com").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn issues_a_token_for_the_account() {
+    let mut issue_verification_token = make_issue_verification_token();
+    issue_verification_token
+        .expect_issue()
+        .withf(|account| account.id() == "any_id")
+        .once()
+        .returning(issue_verification_token_issue_default!());
+
+    let mut sut = make_sut();
+    sut.set_issue_verification_token(issue_verification_token);
+
+    assert!(sut.send("any_email@mail.com").await.is_ok());
+}
+
+#[tokio::test]
+async fn returns_err_if_issue_verification_token_returns_err() {
+    let mut issue_verification_token = make_issue_verification_token();
+    issue_verification_token
+        .expect_issue()
+        .returning(|_| Err(DataError::MailFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_issue_verification_token(issue_verification_token);
+
+    let err = sut.send("any_email@mail.com").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
@@ -0,0 +1,3 @@
+pub mod db_verify_email;
+
+pub use db_verify_email::DbVerifyEmail;
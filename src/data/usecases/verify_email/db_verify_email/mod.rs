@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+
+use crate::data::protocols::{
+    Clock, ConsumeVerificationTokenRepository, Encrypter, MarkEmailVerifiedRepository,
+};
+use crate::domain::entities::TokenPurpose;
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::VerifyEmail;
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct DbVerifyEmail {
+    encrypter: Box<dyn Encrypter>,
+    consume_verification_token_repository: Box<dyn ConsumeVerificationTokenRepository>,
+    mark_email_verified_repository: Box<dyn MarkEmailVerifiedRepository>,
+    clock: Box<dyn Clock>,
+}
+
+impl DbVerifyEmail {
+    /// The `encrypter` must hash the tokens the same way as the one used to
+    /// send them.
+    pub fn new(
+        encrypter: Box<dyn Encrypter>,
+        consume_verification_token_repository: Box<dyn ConsumeVerificationTokenRepository>,
+        mark_email_verified_repository: Box<dyn MarkEmailVerifiedRepository>,
+        clock: Box<dyn Clock>,
+    ) -> Self {
+        Self {
+            encrypter,
+            consume_verification_token_repository,
+            mark_email_verified_repository,
+            clock,
+        }
+    }
+
+    /// Set the db verify email's encrypter.
+    pub fn set_encrypter(&mut self, encrypter: Box<dyn Encrypter>) {
+        self.encrypter = encrypter;
+    }
+
+    /// Set the db verify email's consume verification token repository.
+    pub fn set_consume_verification_token_repository(
+        &mut self,
+        consume_verification_token_repository: Box<dyn ConsumeVerificationTokenRepository>,
+    ) {
+        self.consume_verification_token_repository = consume_verification_token_repository;
+    }
+
+    /// Set the db verify email's mark email verified repository.
+    pub fn set_mark_email_verified_repository(
+        &mut self,
+        mark_email_verified_repository: Box<dyn MarkEmailVerifiedRepository>,
+    ) {
+        self.mark_email_verified_repository = mark_email_verified_repository;
+    }
+
+    /// Set the db verify email's clock.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+}
+
+#[async_trait]
+impl VerifyEmail for DbVerifyEmail {
+    async fn verify(&self, token: &str) -> GenericResult<(), DomainError> {
+        let token_hash = self.encrypter.encrypt(token).await?;
+
+        let token = self
+            .consume_verification_token_repository
+            .consume(TokenPurpose::EmailVerification, &token_hash)
+            .await?
+            .ok_or(DomainError::InvalidToken)?;
+
+        if token.is_expired(self.clock.now()) {
+            return Err(DomainError::InvalidToken);
+        }
+
+        self.mark_email_verified_repository
+            .mark_email_verified(token.account_id())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use chrono::{TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::Clock;
+#[double]
+use crate::data::protocols::ConsumeVerificationTokenRepository;
+#[double]
+use crate::data::protocols::Encrypter;
+#[double]
+use crate::data::protocols::MarkEmailVerifiedRepository;
+
+use crate::data::errors::DataError;
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::VerifyEmail;
+
+use super::DbVerifyEmail;
+
+macro_rules! encrypter_encrypt_default {
+    () => {
+        |_| Ok(String::from("hashed_token"))
+    };
+}
+
+macro_rules! consume_verification_token_repository_consume_default {
+    () => {
+        |purpose, token_hash| {
+            Ok(Some(VerificationTokenEntity::new(
+                "any_id",
+                token_hash,
+                purpose,
+                Utc.ymd(2022, 1, 2).and_hms(0, 0, 0),
+            )))
+        }
+    };
+}
+
+macro_rules! mark_email_verified_repository_mark_email_verified_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+macro_rules! clock_now_default {
+    () => {
+        || Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+    };
+}
+
+fn make_sut() -> DbVerifyEmail {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .returning(encrypter_encrypt_default!());
+
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .returning(consume_verification_token_repository_consume_default!());
+
+    let mut mark_email_verified_repository = make_mark_email_verified_repository();
+    mark_email_verified_repository
+        .expect_mark_email_verified()
+        .returning(mark_email_verified_repository_mark_email_verified_default!());
+
+    let mut clock = make_clock();
+    clock.expect_now().returning(clock_now_default!());
+
+    DbVerifyEmail::new(
+        encrypter,
+        consume_verification_token_repository,
+        mark_email_verified_repository,
+        clock,
+    )
+}
+
+fn make_encrypter() -> Box<Encrypter> {
+    Box::new(Encrypter::default())
+}
+
+fn make_consume_verification_token_repository() -> Box<ConsumeVerificationTokenRepository> {
+    Box::new(ConsumeVerificationTokenRepository::default())
+}
+
+fn make_mark_email_verified_repository() -> Box<MarkEmailVerifiedRepository> {
+    Box::new(MarkEmailVerifiedRepository::default())
+}
+
+fn make_clock() -> Box<Clock> {
+    Box::new(Clock::default())
+}
+
+#[tokio::test]
+async fn consumes_the_email_verification_token_by_its_hash() {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .with(predicate::eq("any_token"))
+        .once()
+        .returning(encrypter_encrypt_default!());
+
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .with(
+            predicate::eq(TokenPurpose::EmailVerification),
+            predicate::eq("hashed_token"),
+        )
+        .once()
+        .returning(consume_verification_token_repository_consume_default!());
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+
+    let _ = sut.verify("any_token").await;
+}
+
+#[tokio::test]
+async fn marks_the_email_of_the_token_account_as_verified() {
+    let mut mark_email_verified_repository = make_mark_email_verified_repository();
+    mark_email_verified_repository
+        .expect_mark_email_verified()
+        .with(predicate::eq("any_id"))
+        .once()
+        .returning(mark_email_verified_repository_mark_email_verified_default!());
+
+    let mut sut = make_sut();
+    sut.set_mark_email_verified_repository(mark_email_verified_repository);
+
+    assert!(sut.verify("any_token").await.is_ok());
+}
+
+#[tokio::test]
+async fn returns_invalid_token_if_the_token_is_not_found() {
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .returning(|_, _| Ok(None));
+
+    let mut mark_email_verified_repository = make_mark_email_verified_repository();
+    mark_email_verified_repository
+        .expect_mark_email_verified()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+    sut.set_mark_email_verified_repository(mark_email_verified_repository);
+
+    let err = sut.verify("any_token").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidToken));
+}
+
+#[tokio::test]
+async fn returns_invalid_token_if_the_token_is_expired() {
+    let mut clock = make_clock();
+    clock
+        .expect_now()
+        .returning(|| Utc.ymd(2022, 1, 2).and_hms(0, 0, 0));
+
+    let mut mark_email_verified_repository = make_mark_email_verified_repository();
+    mark_email_verified_repository
+        .expect_mark_email_verified()
+        .never();
+
+    let mut sut = make_sut();
+    sut.set_clock(clock);
+    sut.set_mark_email_verified_repository(mark_email_verified_repository);
+
+    let err = sut.verify("any_token").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidToken));
+}
+
+#[tokio::test]
+async fn returns_err_if_consume_verification_token_repository_returns_err() {
+    let mut consume_verification_token_repository = make_consume_verification_token_repository();
+    consume_verification_token_repository
+        .expect_consume()
+        .returning(|_, _| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_consume_verification_token_repository(consume_verification_token_repository);
+
+    let err = sut.verify("any_token").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
+
+#[tokio::test]
+async fn returns_err_if_mark_email_verified_repository_returns_err() {
+    let mut mark_email_verified_repository = make_mark_email_verified_repository();
+    mark_email_verified_repository
+        .expect_mark_email_verified()
+        .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_mark_email_verified_repository(mark_email_verified_repository);
+
+    let err = sut.verify("any_token").await.unwrap_err();
+
+    assert!(matches!(err, DomainError::Unexpected(_)));
+}
@@ -1,3 +1,5 @@
 pub mod account;
+pub mod verification_token;
 
 pub use account::AccountEntity;
+pub use verification_token::{TokenPurpose, VerificationTokenEntity};
@@ -1,15 +1,25 @@
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
 
-#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccountEntity {
     id: String,
     name: String,
     email: String,
     password: String,
+    created_at: DateTime<Utc>,
+    email_verified: bool,
+    password_changed_at: Option<DateTime<Utc>>,
+    token_version: i64,
 }
 
 impl AccountEntity {
-    pub fn new(id: &str, name: &str, email: &str, password: &str) -> Self {
+    pub fn new(
+        id: &str,
+        name: &str,
+        email: &str,
+        password: &str,
+        created_at: DateTime<Utc>,
+    ) -> Self {
         let id = String::from(id);
         let name = String::from(name);
         let email = String::from(email);
@@ -20,6 +30,46 @@ impl AccountEntity {
             name,
             email,
             password,
+            created_at,
+            email_verified: false,
+            password_changed_at: None,
+            token_version: 0,
+        }
+    }
+
+    /// Returns the account with the email marked as verified or not. New
+    /// accounts start unverified.
+    pub fn with_email_verified(self, email_verified: bool) -> Self {
+        Self {
+            email_verified,
+            ..self
+        }
+    }
+
+    /// Returns the account with `password`, which is already hashed, in place
+    /// of the current one.
+    pub fn with_password(self, password: &str) -> Self {
+        Self {
+            password: String::from(password),
+            ..self
+        }
+    }
+
+    /// Returns the account with the date its password was last changed, `None`
+    /// if it never changed since signup.
+    pub fn with_password_changed_at(self, password_changed_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            password_changed_at,
+            ..self
+        }
+    }
+
+    /// Returns the account with the version its access tokens must carry. It
+    /// goes up with every password change, revoking the tokens issued before.
+    pub fn with_token_version(self, token_version: i64) -> Self {
+        Self {
+            token_version,
+            ..self
         }
     }
 
@@ -42,4 +92,24 @@ impl AccountEntity {
     pub fn password(&self) -> &str {
         self.password.as_ref()
     }
+
+    /// Get the account entity's creation date.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Whether the account owner proved they own the email.
+    pub fn email_verified(&self) -> bool {
+        self.email_verified
+    }
+
+    /// Get the date the account entity's password was last changed.
+    pub fn password_changed_at(&self) -> Option<DateTime<Utc>> {
+        self.password_changed_at
+    }
+
+    /// Get the account entity's token version.
+    pub fn token_version(&self) -> i64 {
+        self.token_version
+    }
 }
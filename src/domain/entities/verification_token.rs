@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+
+/// What a verification token can be used for, so a token issued for one flow
+/// can't be redeemed in another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenPurpose {
+    EmailVerification,
+    PasswordReset,
+}
+
+impl TokenPurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::EmailVerification => "email_verification",
+            Self::PasswordReset => "password_reset",
+        }
+    }
+}
+
+/// Single-use token sent to the account owner. Only the hash of the token is
+/// stored, the token itself is only known to the recipient.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationTokenEntity {
+    account_id: String,
+    token_hash: String,
+    purpose: TokenPurpose,
+    expires_at: DateTime<Utc>,
+    issued_at: Option<DateTime<Utc>>,
+}
+
+impl VerificationTokenEntity {
+    pub fn new(
+        account_id: &str,
+        token_hash: &str,
+        purpose: TokenPurpose,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            account_id: String::from(account_id),
+            token_hash: String::from(token_hash),
+            purpose,
+            expires_at,
+            issued_at: None,
+        }
+    }
+
+    /// Returns the token with the date it was issued, `None` for tokens
+    /// stored before it was recorded.
+    pub fn with_issued_at(self, issued_at: Option<DateTime<Utc>>) -> Self {
+        Self { issued_at, ..self }
+    }
+
+    /// Get a reference to the verification token entity's account id.
+    pub fn account_id(&self) -> &str {
+        self.account_id.as_ref()
+    }
+
+    /// Get a reference to the verification token entity's token hash.
+    pub fn token_hash(&self) -> &str {
+        self.token_hash.as_ref()
+    }
+
+    /// Get the verification token entity's purpose.
+    pub fn purpose(&self) -> TokenPurpose {
+        self.purpose
+    }
+
+    /// Get the verification token entity's expiration date.
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    /// Get the date the verification token entity was issued.
+    pub fn issued_at(&self) -> Option<DateTime<Utc>> {
+        self.issued_at
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
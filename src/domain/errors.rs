@@ -0,0 +1,3 @@
+pub mod domain_error;
+
+pub use domain_error::DomainError;
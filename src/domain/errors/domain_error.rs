@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+use crate::BoxError;
+
+/// Errors returned by the use cases.
+#[derive(Debug, Error)]
+pub enum DomainError {
+    /// An account is created with an email that is already registered.
+    #[error("email already in use")]
+    EmailInUse,
+    /// The email is not registered or the password does not match.
+    #[error("invalid credentials")]
+    InvalidCredentials,
+    /// The credentials match an account whose email is not verified yet.
+    #[error("email not verified")]
+    EmailNotVerified,
+    /// The current password given to change it does not match.
+    #[error("incorrect password")]
+    IncorrectPassword,
+    /// The new password is the account owner's name or email.
+    #[error("contains personal info")]
+    PasswordContainsPersonalInfo,
+    /// A single-use token is unknown, already used or expired.
+    #[error("invalid or expired token")]
+    InvalidToken,
+    /// A failure the caller cannot act on, such as the database being down.
+    #[error("unexpected error")]
+    Unexpected(#[source] BoxError),
+}
@@ -1,2 +1,4 @@
 pub mod entities;
+pub mod errors;
+pub mod password;
 pub mod usecases;
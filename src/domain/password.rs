@@ -0,0 +1,35 @@
+/// Whether `password` is one of the owner's `personal_values` (e.g. name and
+/// email) or the local part of one of their emails, ignoring case.
+pub fn is_personal_info(password: &str, personal_values: &[&str]) -> bool {
+    let password = password.to_lowercase();
+
+    personal_values.iter().any(|value| {
+        let value = value.to_lowercase();
+        let local_part = value.split('@').next().unwrap_or_default();
+
+        password == value || password == local_part
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_personal_info;
+
+    #[test]
+    fn matches_the_values_and_email_local_parts_ignoring_case() {
+        let personal_values = ["Any Name", "Any_Email@mail.com"];
+
+        for password in ["any name", "ANY_EMAIL@MAIL.COM", "any_email"] {
+            assert!(is_personal_info(password, &personal_values));
+        }
+    }
+
+    #[test]
+    fn accepts_passwords_only_containing_a_value() {
+        let personal_values = ["any_name", "any_email@mail.com"];
+
+        for password in ["any_name1", "mail.com", "any_email@"] {
+            assert!(!is_personal_info(password, &personal_values));
+        }
+    }
+}
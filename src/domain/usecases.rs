@@ -1,3 +1,17 @@
 pub mod add_account;
+pub mod authentication;
+pub mod change_password;
+pub mod load_account_by_token;
+pub mod request_password_reset;
+pub mod reset_password;
+pub mod send_email_verification;
+pub mod verify_email;
 
 pub use add_account::{AddAccount, AddAccountDto, MockAddAccount};
+pub use authentication::{Authentication, AuthenticationDto, MockAuthentication};
+pub use change_password::{ChangePassword, ChangePasswordDto, MockChangePassword};
+pub use load_account_by_token::{LoadAccountByToken, MockLoadAccountByToken};
+pub use request_password_reset::{MockRequestPasswordReset, RequestPasswordReset};
+pub use reset_password::{MockResetPassword, ResetPassword, ResetPasswordDto};
+pub use send_email_verification::{MockSendEmailVerification, SendEmailVerification};
+pub use verify_email::{MockVerifyEmail, VerifyEmail};
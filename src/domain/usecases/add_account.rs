@@ -2,12 +2,13 @@ use async_trait::async_trait;
 use mockall::automock;
 
 use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
 use crate::GenericResult;
 
 #[automock]
 #[async_trait]
 pub trait AddAccount: Send + Sync {
-    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity>;
+    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DomainError>;
 }
 
 #[derive(Debug, PartialEq)]
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::errors::DomainError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait Authentication: Send + Sync {
+    /// Returns an access token, or `InvalidCredentials` if the email is not
+    /// registered or the password does not match.
+    async fn auth(
+        &self,
+        authentication_dto: AuthenticationDto,
+    ) -> GenericResult<String, DomainError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AuthenticationDto {
+    pub email: String,
+    pub password: String,
+}
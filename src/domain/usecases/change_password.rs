@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::errors::DomainError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait ChangePassword: Send + Sync {
+    /// Replaces the account's password and revokes the access tokens issued
+    /// before, returning a fresh one. Returns `IncorrectPassword` if the
+    /// current password does not match.
+    async fn change(
+        &self,
+        change_password_dto: ChangePasswordDto,
+    ) -> GenericResult<String, DomainError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ChangePasswordDto {
+    pub account_id: String,
+    pub current_password: String,
+    pub new_password: String,
+}
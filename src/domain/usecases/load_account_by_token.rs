@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait LoadAccountByToken: Send + Sync {
+    /// Returns the account the access token was issued for, or `None` if the
+    /// token is invalid, expired or was issued before the password changed.
+    async fn load(&self, access_token: &str) -> GenericResult<Option<AccountEntity>, DomainError>;
+}
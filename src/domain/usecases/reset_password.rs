@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::errors::DomainError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait ResetPassword: Send + Sync {
+    /// Replaces the password of the account the token was issued for, or
+    /// returns `InvalidToken` if the token is unknown, used or expired.
+    async fn reset(&self, reset_password_dto: ResetPasswordDto) -> GenericResult<(), DomainError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ResetPasswordDto {
+    pub token: String,
+    pub password: String,
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::errors::DomainError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait SendEmailVerification: Send + Sync {
+    /// Mails a new verification token to the account registered with `email`.
+    /// Does nothing if there is no such account or it is already verified, so
+    /// callers can't tell which emails are registered.
+    async fn send(&self, email: &str) -> GenericResult<(), DomainError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::errors::DomainError;
+use crate::GenericResult;
+
+#[automock]
+#[async_trait]
+pub trait VerifyEmail: Send + Sync {
+    /// Redeems an email verification token, marking the email of its account
+    /// as verified. Returns `InvalidToken` if the token is unknown, already
+    /// used or expired.
+    async fn verify(&self, token: &str) -> GenericResult<(), DomainError>;
+}
@@ -0,0 +1,3 @@
+pub mod system_clock;
+
+pub use system_clock::SystemClock;
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+use crate::data::protocols::Clock;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -1,3 +1,9 @@
+pub mod argon2_adapter;
+pub mod jwt_adapter;
+pub mod random_token_adapter;
 pub mod sha2_adapter;
 
+pub use argon2_adapter::{Argon2Adapter, Argon2Config};
+pub use jwt_adapter::{JwtAdapter, JwtConfig, JwtKeys};
+pub use random_token_adapter::RandomTokenAdapter;
 pub use sha2_adapter::Sha2Adapter;
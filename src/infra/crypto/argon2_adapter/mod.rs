@@ -0,0 +1,148 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{self, PasswordHash, SaltString};
+use argon2::{Algorithm, Argon2, Params, PasswordHasher, PasswordVerifier, Version};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{Encrypter, HashComparer};
+use crate::GenericResult;
+
+#[cfg(test)]
+mod tests;
+
+pub struct Argon2Adapter {
+    encrypter: Box<dyn Encrypter>,
+    hash_comparer: Box<dyn HashComparer>,
+}
+
+impl Argon2Adapter {
+    pub fn new(config: Argon2Config) -> GenericResult<Self> {
+        let Argon2Config {
+            memory_cost,
+            time_cost,
+            parallelism,
+        } = config;
+
+        let params = Params::new(memory_cost, time_cost, parallelism, None)?;
+
+        Ok(Self {
+            encrypter: Box::new(StdEncrypter { params }),
+            hash_comparer: Box::new(StdHashComparer),
+        })
+    }
+
+    /// Set the argon2 adapter's encrypter.
+    pub fn set_encrypter(&mut self, encrypter: Box<dyn Encrypter>) {
+        self.encrypter = encrypter;
+    }
+
+    /// Set the argon2 adapter's hash comparer.
+    pub fn set_hash_comparer(&mut self, hash_comparer: Box<dyn HashComparer>) {
+        self.hash_comparer = hash_comparer;
+    }
+}
+
+impl Default for Argon2Adapter {
+    fn default() -> Self {
+        Self {
+            encrypter: Box::new(StdEncrypter {
+                params: Params::default(),
+            }),
+            hash_comparer: Box::new(StdHashComparer),
+        }
+    }
+}
+
+#[async_trait]
+impl Encrypter for Argon2Adapter {
+    async fn encrypt(&self, value: &str) -> GenericResult<String, DataError> {
+        self.encrypter.encrypt(value).await
+    }
+}
+
+#[async_trait]
+impl HashComparer for Argon2Adapter {
+    async fn compare(&self, value: &str, hash: &str) -> GenericResult<bool, DataError> {
+        self.hash_comparer.compare(value, hash).await
+    }
+}
+
+/// Cost parameters of the Argon2id hashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Argon2Config {
+    /// Memory size in KiB.
+    pub memory_cost: u32,
+    /// Number of iterations.
+    pub time_cost: u32,
+    /// Degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Config {
+    fn default() -> Self {
+        Self {
+            memory_cost: Params::DEFAULT_M_COST,
+            time_cost: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+struct StdEncrypter {
+    params: Params,
+}
+
+impl StdEncrypter {
+    fn argon2(&self) -> Argon2<'static> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
+}
+
+#[async_trait]
+impl Encrypter for StdEncrypter {
+    async fn encrypt(&self, value: &str) -> GenericResult<String, DataError> {
+        let argon2 = self.argon2();
+        let value = String::from(value);
+
+        let hash = tokio::task::spawn_blocking(move || {
+            let salt = SaltString::generate(&mut OsRng);
+
+            argon2
+                .hash_password(value.as_bytes(), &salt)
+                .map(|hash| hash.to_string())
+        })
+        .await
+        .map_err(|err| DataError::HashingFailed(err.into()))?
+        .map_err(|err| DataError::HashingFailed(err.into()))?;
+
+        Ok(hash)
+    }
+}
+
+struct StdHashComparer;
+
+#[async_trait]
+impl HashComparer for StdHashComparer {
+    async fn compare(&self, value: &str, hash: &str) -> GenericResult<bool, DataError> {
+        let value = String::from(value);
+        let hash = String::from(hash);
+
+        let result = tokio::task::spawn_blocking(move || {
+            // The costs and salt are read back from the PHC string itself.
+            let hash = PasswordHash::new(&hash)?;
+
+            match Argon2::default().verify_password(value.as_bytes(), &hash) {
+                Ok(()) => Ok(true),
+                Err(password_hash::Error::Password) => Ok(false),
+                Err(err) => Err(err),
+            }
+        })
+        .await
+        .map_err(|err| DataError::HashingFailed(err.into()))?
+        .map_err(|err| DataError::HashingFailed(err.into()))?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,169 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::data::protocols::Encrypter as MockEncrypter;
+#[double]
+use crate::data::protocols::HashComparer as MockHashComparer;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{Encrypter, HashComparer};
+
+use super::{Argon2Adapter, Argon2Config};
+
+macro_rules! encrypter_encrypt_default {
+    () => {
+        |_| Ok(String::from("hashed_value"))
+    };
+}
+
+macro_rules! hash_comparer_compare_default {
+    () => {
+        |_, _| Ok(true)
+    };
+}
+
+fn make_sut() -> Argon2Adapter {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .returning(encrypter_encrypt_default!());
+
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(hash_comparer_compare_default!());
+
+    let mut sut = Argon2Adapter::default();
+    sut.set_encrypter(encrypter);
+    sut.set_hash_comparer(hash_comparer);
+
+    sut
+}
+
+fn make_encrypter() -> Box<MockEncrypter> {
+    Box::new(MockEncrypter::default())
+}
+
+fn make_hash_comparer() -> Box<MockHashComparer> {
+    Box::new(MockHashComparer::default())
+}
+
+fn make_config() -> Argon2Config {
+    Argon2Config {
+        memory_cost: 64,
+        time_cost: 1,
+        parallelism: 1,
+    }
+}
+
+#[tokio::test]
+async fn calls_encrypter_with_correct_data() {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .once()
+        .with(predicate::eq("any_value"))
+        .returning(encrypter_encrypt_default!());
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+
+    let _ = sut.encrypt("any_value").await;
+}
+
+#[tokio::test]
+async fn returns_err_if_encrypter_returns_err() {
+    let mut encrypter = make_encrypter();
+    encrypter
+        .expect_encrypt()
+        .returning(|_| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_encrypter(encrypter);
+
+    let result = sut.encrypt("any_value").await;
+
+    assert!(matches!(result.unwrap_err(), DataError::HashingFailed(_)));
+}
+
+#[tokio::test]
+async fn returns_a_hash_on_success() {
+    let sut = make_sut();
+    let result = sut.encrypt("any_value").await;
+
+    assert_eq!(result.unwrap(), "hashed_value");
+}
+
+#[test]
+fn returns_err_if_config_is_invalid() {
+    let config = Argon2Config {
+        time_cost: 0,
+        ..make_config()
+    };
+
+    assert!(Argon2Adapter::new(config).is_err());
+}
+
+#[tokio::test]
+async fn returns_a_phc_string_with_the_configured_costs() {
+    let sut = Argon2Adapter::new(make_config()).unwrap();
+    let hash = sut.encrypt("any_value").await.unwrap();
+
+    assert!(hash.starts_with("$argon2id$v=19$m=64,t=1,p=1$"));
+}
+
+#[tokio::test]
+async fn uses_a_random_salt_per_hash() {
+    let sut = Argon2Adapter::new(make_config()).unwrap();
+    let first_hash = sut.encrypt("any_value").await.unwrap();
+    let second_hash = sut.encrypt("any_value").await.unwrap();
+
+    assert_ne!(first_hash, second_hash);
+}
+
+#[tokio::test]
+async fn calls_hash_comparer_with_correct_data() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .once()
+        .with(predicate::eq("any_value"), predicate::eq("any_hash"))
+        .returning(hash_comparer_compare_default!());
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let _ = sut.compare("any_value", "any_hash").await;
+}
+
+#[tokio::test]
+async fn returns_err_if_hash_comparer_returns_err() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(|_, _| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let result = sut.compare("any_value", "any_hash").await;
+
+    assert!(matches!(result.unwrap_err(), DataError::HashingFailed(_)));
+}
+
+#[tokio::test]
+async fn compares_a_value_with_its_own_hash() {
+    let sut = Argon2Adapter::new(make_config()).unwrap();
+    let hash = sut.encrypt("any_value").await.unwrap();
+
+    assert!(sut.compare("any_value", &hash).await.unwrap());
+    assert!(!sut.compare("other_value", &hash).await.unwrap());
+}
+
+#[tokio::test]
+async fn returns_err_if_hash_is_malformed() {
+    let sut = Argon2Adapter::new(make_config()).unwrap();
+
+    assert!(sut.compare("any_value", "malformed_hash").await.is_err());
+}
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{Clock, TokenGenerator, TokenVerifier, VerifiedToken};
+use crate::infra::clock::SystemClock;
+use crate::GenericResult;
+
+#[cfg(test)]
+mod tests;
+
+pub struct JwtAdapter {
+    token_generator: Box<dyn TokenGenerator>,
+    token_verifier: Box<dyn TokenVerifier>,
+}
+
+impl JwtAdapter {
+    pub fn new(config: JwtConfig) -> GenericResult<Self> {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    pub fn with_clock(config: JwtConfig, clock: Arc<dyn Clock>) -> GenericResult<Self> {
+        let JwtConfig {
+            keys,
+            issuer,
+            audience,
+            expires_in,
+        } = config;
+
+        let (algorithm, encoding_key, decoding_key) = match keys {
+            JwtKeys::Hs256 { secret } => (
+                Algorithm::HS256,
+                EncodingKey::from_secret(secret.as_bytes()),
+                DecodingKey::from_secret(secret.as_bytes()),
+            ),
+            JwtKeys::Rs256 {
+                private_key_pem,
+                public_key_pem,
+            } => (
+                Algorithm::RS256,
+                EncodingKey::from_rsa_pem(private_key_pem.as_bytes())?,
+                DecodingKey::from_rsa_pem(public_key_pem.as_bytes())?,
+            ),
+        };
+
+        let jwt = StdJwt {
+            algorithm,
+            encoding_key,
+            decoding_key,
+            issuer,
+            audience,
+            expires_in,
+            clock,
+        };
+
+        Ok(Self {
+            token_generator: Box::new(jwt.clone()),
+            token_verifier: Box::new(jwt),
+        })
+    }
+
+    /// Set the jwt adapter's token generator.
+    pub fn set_token_generator(&mut self, token_generator: Box<dyn TokenGenerator>) {
+        self.token_generator = token_generator;
+    }
+
+    /// Set the jwt adapter's token verifier.
+    pub fn set_token_verifier(&mut self, token_verifier: Box<dyn TokenVerifier>) {
+        self.token_verifier = token_verifier;
+    }
+}
+
+#[async_trait]
+impl TokenGenerator for JwtAdapter {
+    async fn generate(&self, id: &str, token_version: i64) -> GenericResult<String, DataError> {
+        self.token_generator.generate(id, token_version).await
+    }
+}
+
+#[async_trait]
+impl TokenVerifier for JwtAdapter {
+    async fn verify(&self, token: &str) -> GenericResult<Option<VerifiedToken>, DataError> {
+        self.token_verifier.verify(token).await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JwtConfig {
+    pub keys: JwtKeys,
+    /// Value of the `iss` claim, checked on verification when set.
+    pub issuer: Option<String>,
+    /// Value of the `aud` claim, checked on verification when set.
+    pub audience: Option<String>,
+    pub expires_in: Duration,
+}
+
+impl JwtConfig {
+    pub fn hs256(secret: &str) -> Self {
+        Self {
+            keys: JwtKeys::Hs256 {
+                secret: String::from(secret),
+            },
+            issuer: None,
+            audience: None,
+            expires_in: Duration::hours(1),
+        }
+    }
+
+    pub fn rs256(private_key_pem: &str, public_key_pem: &str) -> Self {
+        Self {
+            keys: JwtKeys::Rs256 {
+                private_key_pem: String::from(private_key_pem),
+                public_key_pem: String::from(public_key_pem),
+            },
+            ..Self::hs256("")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JwtKeys {
+    Hs256 {
+        secret: String,
+    },
+    Rs256 {
+        private_key_pem: String,
+        public_key_pem: String,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    sub: String,
+    iat: i64,
+    exp: i64,
+    /// Token version of the account. Tokens issued before the claim existed
+    /// fail to decode, so they are rejected.
+    ver: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aud: Option<String>,
+}
+
+#[derive(Clone)]
+struct StdJwt {
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    issuer: Option<String>,
+    audience: Option<String>,
+    expires_in: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+#[async_trait]
+impl TokenGenerator for StdJwt {
+    async fn generate(&self, id: &str, token_version: i64) -> GenericResult<String, DataError> {
+        let now = self.clock.now();
+
+        let claims = Claims {
+            sub: String::from(id),
+            iat: now.timestamp(),
+            exp: (now + self.expires_in).timestamp(),
+            ver: token_version,
+            iss: self.issuer.clone(),
+            aud: self.audience.clone(),
+        };
+
+        let token = jsonwebtoken::encode(&Header::new(self.algorithm), &claims, &self.encoding_key)
+            .map_err(|err| DataError::TokenFailed(err.into()))?;
+
+        Ok(token)
+    }
+}
+
+#[async_trait]
+impl TokenVerifier for StdJwt {
+    async fn verify(&self, token: &str) -> GenericResult<Option<VerifiedToken>, DataError> {
+        let mut validation = Validation::new(self.algorithm);
+        // Expiry is checked against the injected clock below.
+        validation.validate_exp = false;
+
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+
+        if let Some(audience) = &self.audience {
+            validation.set_audience(&[audience]);
+        }
+
+        let claims = match jsonwebtoken::decode::<Claims>(token, &self.decoding_key, &validation) {
+            Ok(token_data) => token_data.claims,
+            Err(_) => return Ok(None),
+        };
+
+        if claims.exp <= self.clock.now().timestamp() {
+            return Ok(None);
+        }
+
+        Ok(Some(VerifiedToken {
+            subject: claims.sub,
+            token_version: claims.ver,
+        }))
+    }
+}
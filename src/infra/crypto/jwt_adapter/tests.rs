@@ -0,0 +1,269 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use jsonwebtoken::{EncodingKey, Header};
+use mockall::predicate;
+use mockall_double::double;
+use serde_json::json;
+
+#[double]
+use crate::data::protocols::TokenGenerator as MockTokenGenerator;
+#[double]
+use crate::data::protocols::TokenVerifier as MockTokenVerifier;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{MockClock, TokenGenerator, TokenVerifier, VerifiedToken};
+
+use super::{JwtAdapter, JwtConfig};
+
+const PRIVATE_KEY_PEM: &str = include_str!("fixtures/private_key.pem");
+const PUBLIC_KEY_PEM: &str = include_str!("fixtures/public_key.pem");
+
+macro_rules! token_generator_generate_default {
+    () => {
+        |_, _| Ok(String::from("any_token"))
+    };
+}
+
+macro_rules! token_verifier_verify_default {
+    () => {
+        |_| {
+            Ok(Some(VerifiedToken {
+                subject: String::from("any_id"),
+                token_version: 1,
+            }))
+        }
+    };
+}
+
+fn make_sut() -> JwtAdapter {
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .returning(token_generator_generate_default!());
+
+    let mut token_verifier = make_token_verifier();
+    token_verifier
+        .expect_verify()
+        .returning(token_verifier_verify_default!());
+
+    let mut sut = JwtAdapter::new(JwtConfig::hs256("secret")).unwrap();
+    sut.set_token_generator(token_generator);
+    sut.set_token_verifier(token_verifier);
+
+    sut
+}
+
+fn make_token_generator() -> Box<MockTokenGenerator> {
+    Box::new(MockTokenGenerator::default())
+}
+
+fn make_token_verifier() -> Box<MockTokenVerifier> {
+    Box::new(MockTokenVerifier::default())
+}
+
+fn make_time() -> DateTime<Utc> {
+    Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+}
+
+fn make_clock(now: DateTime<Utc>) -> Arc<MockClock> {
+    let mut clock = MockClock::default();
+    clock.expect_now().returning(move || now);
+    Arc::new(clock)
+}
+
+fn make_fixed_sut(config: JwtConfig, now: DateTime<Utc>) -> JwtAdapter {
+    JwtAdapter::with_clock(config, make_clock(now)).unwrap()
+}
+
+#[tokio::test]
+async fn calls_token_generator_with_correct_values() {
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .once()
+        .with(predicate::eq("any_id"), predicate::eq(1))
+        .returning(token_generator_generate_default!());
+
+    let mut sut = make_sut();
+    sut.set_token_generator(token_generator);
+
+    let _ = sut.generate("any_id", 1).await;
+}
+
+#[tokio::test]
+async fn returns_err_if_token_generator_returns_err() {
+    let mut token_generator = make_token_generator();
+    token_generator
+        .expect_generate()
+        .returning(|_, _| Err(DataError::TokenFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_token_generator(token_generator);
+
+    let result = sut.generate("any_id", 1).await;
+
+    assert!(matches!(result.unwrap_err(), DataError::TokenFailed(_)));
+}
+
+#[tokio::test]
+async fn calls_token_verifier_with_correct_token() {
+    let mut token_verifier = make_token_verifier();
+    token_verifier
+        .expect_verify()
+        .once()
+        .with(predicate::eq("any_token"))
+        .returning(token_verifier_verify_default!());
+
+    let mut sut = make_sut();
+    sut.set_token_verifier(token_verifier);
+
+    let _ = sut.verify("any_token").await;
+}
+
+#[tokio::test]
+async fn returns_err_if_token_verifier_returns_err() {
+    let mut token_verifier = make_token_verifier();
+    token_verifier
+        .expect_verify()
+        .returning(|_| Err(DataError::TokenFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_token_verifier(token_verifier);
+
+    let result = sut.verify("any_token").await;
+
+    assert!(matches!(result.unwrap_err(), DataError::TokenFailed(_)));
+}
+
+#[tokio::test]
+async fn verifies_its_own_hs256_tokens() {
+    let sut = make_fixed_sut(JwtConfig::hs256("secret"), make_time());
+    let token = sut.generate("any_id", 1).await.unwrap();
+
+    let verified = sut.verify(&token).await.unwrap();
+
+    assert_eq!(
+        verified,
+        Some(VerifiedToken {
+            subject: String::from("any_id"),
+            token_version: 1,
+        })
+    );
+}
+
+#[tokio::test]
+async fn verifies_its_own_rs256_tokens() {
+    let config = JwtConfig::rs256(PRIVATE_KEY_PEM, PUBLIC_KEY_PEM);
+    let sut = make_fixed_sut(config, make_time());
+    let token = sut.generate("any_id", 1).await.unwrap();
+
+    let verified = sut.verify(&token).await.unwrap();
+
+    assert_eq!(
+        verified,
+        Some(VerifiedToken {
+            subject: String::from("any_id"),
+            token_version: 1,
+        })
+    );
+}
+
+#[tokio::test]
+async fn returns_none_if_token_has_no_version() {
+    let claims = json!({
+        "sub": "any_id",
+        "iat": make_time().timestamp(),
+        "exp": (make_time() + Duration::hours(1)).timestamp(),
+    });
+    let token = jsonwebtoken::encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(b"secret"),
+    )
+    .unwrap();
+
+    let sut = make_fixed_sut(JwtConfig::hs256("secret"), make_time());
+
+    assert_eq!(sut.verify(&token).await.unwrap(), None);
+}
+
+#[test]
+fn returns_err_if_rsa_keys_are_invalid() {
+    let config = JwtConfig::rs256("invalid_key", "invalid_key");
+
+    assert!(JwtAdapter::new(config).is_err());
+}
+
+#[tokio::test]
+async fn returns_none_if_token_is_malformed() {
+    let sut = make_fixed_sut(JwtConfig::hs256("secret"), make_time());
+
+    let verified = sut.verify("malformed_token").await.unwrap();
+
+    assert_eq!(verified, None);
+}
+
+#[tokio::test]
+async fn returns_none_if_token_is_signed_with_another_secret() {
+    let issuer = make_fixed_sut(JwtConfig::hs256("other_secret"), make_time());
+    let token = issuer.generate("any_id", 1).await.unwrap();
+
+    let sut = make_fixed_sut(JwtConfig::hs256("secret"), make_time());
+    let verified = sut.verify(&token).await.unwrap();
+
+    assert_eq!(verified, None);
+}
+
+#[tokio::test]
+async fn returns_none_if_token_is_expired() {
+    let config = JwtConfig {
+        expires_in: Duration::minutes(30),
+        ..JwtConfig::hs256("secret")
+    };
+
+    let issuer = make_fixed_sut(config.clone(), make_time());
+    let token = issuer.generate("any_id", 1).await.unwrap();
+
+    let sut = make_fixed_sut(config.clone(), make_time() + Duration::minutes(29));
+    assert!(sut.verify(&token).await.unwrap().is_some());
+
+    let sut = make_fixed_sut(config, make_time() + Duration::minutes(30));
+    assert_eq!(sut.verify(&token).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn returns_none_if_issuer_does_not_match() {
+    let config = JwtConfig {
+        issuer: Some(String::from("other_issuer")),
+        ..JwtConfig::hs256("secret")
+    };
+    let issuer = make_fixed_sut(config, make_time());
+    let token = issuer.generate("any_id", 1).await.unwrap();
+
+    let config = JwtConfig {
+        issuer: Some(String::from("any_issuer")),
+        ..JwtConfig::hs256("secret")
+    };
+    let sut = make_fixed_sut(config, make_time());
+
+    assert_eq!(sut.verify(&token).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn returns_none_if_audience_does_not_match() {
+    let config = JwtConfig {
+        audience: Some(String::from("other_audience")),
+        ..JwtConfig::hs256("secret")
+    };
+    let issuer = make_fixed_sut(config, make_time());
+    let token = issuer.generate("any_id", 1).await.unwrap();
+
+    let config = JwtConfig {
+        audience: Some(String::from("any_audience")),
+        ..JwtConfig::hs256("secret")
+    };
+    let sut = make_fixed_sut(config, make_time());
+
+    assert_eq!(sut.verify(&token).await.unwrap(), None);
+}
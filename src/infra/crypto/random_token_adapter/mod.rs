@@ -0,0 +1,31 @@
+use base64ct::{Base64UrlUnpadded, Encoding};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use crate::data::protocols::RandomTokenGenerator;
+
+#[cfg(test)]
+mod tests;
+
+/// Number of random bytes in a token, 256 bits.
+const TOKEN_BYTES: usize = 32;
+
+/// Generates tokens from the OS random number generator, encoded as unpadded
+/// base64url so they can be sent in links.
+#[derive(Default)]
+pub struct RandomTokenAdapter;
+
+impl RandomTokenAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl RandomTokenGenerator for RandomTokenAdapter {
+    fn generate(&self) -> String {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+
+        Base64UrlUnpadded::encode_string(&bytes)
+    }
+}
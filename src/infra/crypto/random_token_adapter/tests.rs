@@ -0,0 +1,22 @@
+use crate::data::protocols::RandomTokenGenerator;
+
+use super::RandomTokenAdapter;
+
+#[test]
+fn generates_url_safe_tokens() {
+    let sut = RandomTokenAdapter::new();
+
+    let token = sut.generate();
+
+    assert_eq!(token.len(), 43);
+    assert!(token
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+}
+
+#[test]
+fn generates_a_different_token_each_time() {
+    let sut = RandomTokenAdapter::new();
+
+    assert_ne!(sut.generate(), sut.generate());
+}
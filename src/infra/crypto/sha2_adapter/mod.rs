@@ -1,8 +1,10 @@
 use async_trait::async_trait;
 use base64ct::{Base64, Encoding};
 use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
 
-use crate::data::protocols::Encrypter;
+use crate::data::errors::DataError;
+use crate::data::protocols::{Encrypter, HashComparer};
 use crate::GenericResult;
 
 #[cfg(test)]
@@ -10,12 +12,14 @@ mod tests;
 
 pub struct Sha2Adapter {
     encrypter: Box<dyn Encrypter>,
+    hash_comparer: Box<dyn HashComparer>,
 }
 
 impl Sha2Adapter {
     pub fn new() -> Self {
         Self {
             encrypter: Box::new(StdEncrypter),
+            hash_comparer: Box::new(StdHashComparer),
         }
     }
 
@@ -23,23 +27,53 @@ impl Sha2Adapter {
     pub fn set_encrypter(&mut self, encrypter: Box<dyn Encrypter>) {
         self.encrypter = encrypter;
     }
+
+    /// Set the sha2 adapter's hash comparer.
+    pub fn set_hash_comparer(&mut self, hash_comparer: Box<dyn HashComparer>) {
+        self.hash_comparer = hash_comparer;
+    }
+}
+
+impl Default for Sha2Adapter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[async_trait]
 impl Encrypter for Sha2Adapter {
-    async fn encrypt(&self, value: &str) -> GenericResult<String> {
+    async fn encrypt(&self, value: &str) -> GenericResult<String, DataError> {
         self.encrypter.encrypt(value).await
     }
 }
 
+#[async_trait]
+impl HashComparer for Sha2Adapter {
+    async fn compare(&self, value: &str, hash: &str) -> GenericResult<bool, DataError> {
+        self.hash_comparer.compare(value, hash).await
+    }
+}
+
+fn sha2_hash(value: &str) -> String {
+    let hash = Sha256::digest(value);
+    Base64::encode_string(&hash)
+}
+
 struct StdEncrypter;
 
 #[async_trait]
 impl Encrypter for StdEncrypter {
-    async fn encrypt(&self, value: &str) -> GenericResult<String> {
-        let hash = Sha256::digest(value);
-        let base64_hash = Base64::encode_string(&hash);
+    async fn encrypt(&self, value: &str) -> GenericResult<String, DataError> {
+        Ok(sha2_hash(value))
+    }
+}
+
+struct StdHashComparer;
 
-        Ok(base64_hash)
+#[async_trait]
+impl HashComparer for StdHashComparer {
+    async fn compare(&self, value: &str, hash: &str) -> GenericResult<bool, DataError> {
+        let value_hash = sha2_hash(value);
+        Ok(value_hash.as_bytes().ct_eq(hash.as_bytes()).into())
     }
 }
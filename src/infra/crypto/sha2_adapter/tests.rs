@@ -3,11 +3,13 @@ use mockall_double::double;
 
 #[double]
 use crate::data::protocols::Encrypter as MockEncrypter;
+#[double]
+use crate::data::protocols::HashComparer as MockHashComparer;
 
-use crate::data::protocols::Encrypter;
-use crate::ErrorMsg;
+use crate::data::errors::DataError;
+use crate::data::protocols::{Encrypter, HashComparer};
 
-use super::Sha2Adapter;
+use super::{Sha2Adapter, StdEncrypter, StdHashComparer};
 
 macro_rules! encrypter_encrypt_default {
     () => {
@@ -15,14 +17,26 @@ macro_rules! encrypter_encrypt_default {
     };
 }
 
+macro_rules! hash_comparer_compare_default {
+    () => {
+        |_, _| Ok(true)
+    };
+}
+
 fn make_sut() -> Sha2Adapter {
     let mut encrypter = make_encrypter();
     encrypter
         .expect_encrypt()
         .returning(encrypter_encrypt_default!());
 
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(hash_comparer_compare_default!());
+
     let mut sut = Sha2Adapter::new();
     sut.set_encrypter(encrypter);
+    sut.set_hash_comparer(hash_comparer);
 
     sut
 }
@@ -31,6 +45,10 @@ fn make_encrypter() -> Box<MockEncrypter> {
     Box::new(MockEncrypter::default())
 }
 
+fn make_hash_comparer() -> Box<MockHashComparer> {
+    Box::new(MockHashComparer::default())
+}
+
 #[tokio::test]
 async fn calls_encrypter() {
     let mut encrypter = make_encrypter();
@@ -42,10 +60,7 @@ async fn calls_encrypter() {
     let mut sut = make_sut();
     sut.set_encrypter(encrypter);
 
-    match sut.encrypt("any_value").await {
-        Ok(_) => {}
-        Err(_) => {}
-    }
+    let _ = sut.encrypt("any_value").await;
 }
 
 #[tokio::test]
@@ -59,10 +74,7 @@ async fn calls_encrypter_with_correct_data() {
     let mut sut = make_sut();
     sut.set_encrypter(encrypter);
 
-    match sut.encrypt("any_value").await {
-        Ok(_) => {}
-        Err(_) => {}
-    }
+    let _ = sut.encrypt("any_value").await;
 }
 
 #[tokio::test]
@@ -70,17 +82,14 @@ async fn returns_err_if_encrypter_returns_err() {
     let mut encrypter = make_encrypter();
     encrypter
         .expect_encrypt()
-        .returning(|_| ErrorMsg::default().into());
+        .returning(|_| Err(DataError::HashingFailed("any_error".into())));
 
     let mut sut = make_sut();
     sut.set_encrypter(encrypter);
 
     let result = sut.encrypt("any_value").await;
 
-    assert_eq!(
-        result.unwrap_err().to_string(),
-        ErrorMsg::default().to_string()
-    );
+    assert!(matches!(result.unwrap_err(), DataError::HashingFailed(_)));
 }
 
 #[tokio::test]
@@ -90,3 +99,54 @@ async fn returns_a_hash_on_success() {
 
     assert_eq!(result.unwrap(), "hashed_value");
 }
+
+#[tokio::test]
+async fn calls_hash_comparer_with_correct_data() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .once()
+        .with(predicate::eq("any_value"), predicate::eq("any_hash"))
+        .returning(hash_comparer_compare_default!());
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let _ = sut.compare("any_value", "any_hash").await;
+}
+
+#[tokio::test]
+async fn returns_err_if_hash_comparer_returns_err() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer
+        .expect_compare()
+        .returning(|_, _| Err(DataError::HashingFailed("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let result = sut.compare("any_value", "any_hash").await;
+
+    assert!(matches!(result.unwrap_err(), DataError::HashingFailed(_)));
+}
+
+#[tokio::test]
+async fn returns_false_if_hash_comparer_returns_false() {
+    let mut hash_comparer = make_hash_comparer();
+    hash_comparer.expect_compare().returning(|_, _| Ok(false));
+
+    let mut sut = make_sut();
+    sut.set_hash_comparer(hash_comparer);
+
+    let is_equal = sut.compare("any_value", "any_hash").await.unwrap();
+
+    assert!(!is_equal);
+}
+
+#[tokio::test]
+async fn compares_a_value_with_its_own_hash() {
+    let hash = StdEncrypter.encrypt("any_value").await.unwrap();
+
+    assert!(StdHashComparer.compare("any_value", &hash).await.unwrap());
+    assert!(!StdHashComparer.compare("other_value", &hash).await.unwrap());
+}
@@ -1,6 +1,18 @@
+pub mod account_in_memory_repository;
 pub mod account_mongo_repository;
+pub mod account_sql_repository;
 pub mod mongo_helper;
 pub mod protocols;
+pub mod sql_helper;
+pub mod verification_token_in_memory_repository;
+pub mod verification_token_mongo_repository;
+pub mod verification_token_sql_repository;
 
+pub use account_in_memory_repository::AccountInMemoryRepository;
 pub use account_mongo_repository::AccountMongoRepository;
-pub use mongo_helper::MongoHelper;
+pub use account_sql_repository::AccountSqlRepository;
+pub use mongo_helper::{MongoConfig, MongoHelper};
+pub use sql_helper::{SqlConfig, SqlHelper};
+pub use verification_token_in_memory_repository::VerificationTokenInMemoryRepository;
+pub use verification_token_mongo_repository::VerificationTokenMongoRepository;
+pub use verification_token_sql_repository::VerificationTokenSqlRepository;
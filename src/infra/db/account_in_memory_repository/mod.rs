@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mongodb::bson::oid::ObjectId;
+use tokio::sync::RwLock;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddAccountRepository, Clock, LoadAccountByEmailRepository, LoadAccountByIdRepository,
+    MarkEmailVerifiedRepository, UpdateAccountPasswordRepository,
+};
+use crate::domain::entities::AccountEntity;
+use crate::domain::usecases::AddAccountDto;
+use crate::infra::clock::SystemClock;
+use crate::GenericResult;
+
+use super::protocols::AccountRepository;
+
+#[cfg(test)]
+mod tests;
+
+/// Account repository kept in process memory, for tests and local development
+/// without a database. Clones share the same accounts.
+#[derive(Clone)]
+pub struct AccountInMemoryRepository {
+    /// Accounts keyed by email, which keeps the emails unique.
+    accounts: Arc<RwLock<HashMap<String, AccountEntity>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl AccountInMemoryRepository {
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            accounts: Arc::new(RwLock::new(HashMap::new())),
+            clock,
+        }
+    }
+}
+
+impl Default for AccountInMemoryRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AddAccountRepository for AccountInMemoryRepository {
+    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DataError> {
+        let AddAccountDto {
+            name,
+            email,
+            password,
+        } = &account_dto;
+
+        let mut accounts = self.accounts.write().await;
+
+        if accounts.contains_key(email) {
+            return Err(DataError::DuplicateEmail);
+        }
+
+        let id = ObjectId::new().to_hex();
+        let account = AccountEntity::new(&id, name, email, password, self.clock.now());
+
+        accounts.insert(email.clone(), account.clone());
+
+        Ok(account)
+    }
+}
+
+#[async_trait]
+impl LoadAccountByEmailRepository for AccountInMemoryRepository {
+    async fn load_by_email(&self, email: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        let accounts = self.accounts.read().await;
+
+        Ok(accounts.get(email).cloned())
+    }
+}
+
+#[async_trait]
+impl LoadAccountByIdRepository for AccountInMemoryRepository {
+    async fn load_by_id(&self, id: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        let accounts = self.accounts.read().await;
+
+        Ok(accounts
+            .values()
+            .find(|account| account.id() == id)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl MarkEmailVerifiedRepository for AccountInMemoryRepository {
+    async fn mark_email_verified(&self, account_id: &str) -> GenericResult<(), DataError> {
+        let mut accounts = self.accounts.write().await;
+
+        if let Some(account) = accounts
+            .values_mut()
+            .find(|account| account.id() == account_id)
+        {
+            *account = account.clone().with_email_verified(true);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UpdateAccountPasswordRepository for AccountInMemoryRepository {
+    async fn update_password(
+        &self,
+        account_id: &str,
+        password: &str,
+        changed_at: DateTime<Utc>,
+    ) -> GenericResult<(), DataError> {
+        let mut accounts = self.accounts.write().await;
+
+        if let Some(account) = accounts
+            .values_mut()
+            .find(|account| account.id() == account_id)
+        {
+            let token_version = account.token_version() + 1;
+
+            *account = account
+                .clone()
+                .with_password(password)
+                .with_password_changed_at(Some(changed_at))
+                .with_token_version(token_version);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AccountRepository for AccountInMemoryRepository {
+    async fn create_indexes(&self) -> GenericResult<(), DataError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use mongodb::bson;
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::AccountEntity;
+
+/// Shape of an account in the `accounts` collection. The id is only set on
+/// documents read back, so inserts let the server generate the `_id`.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub(super) struct AccountDocument {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    /// Missing on legacy accounts, which fall back to the `_id` timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<bson::DateTime>,
+    /// Missing on accounts created before email verification existed.
+    #[serde(default)]
+    pub email_verified: bool,
+    /// Missing until the password changes for the first time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_changed_at: Option<bson::DateTime>,
+    /// Missing on accounts created before tokens carried a version.
+    #[serde(default)]
+    pub token_version: i64,
+}
+
+impl AccountDocument {
+    pub fn into_entity(self, id: ObjectId) -> AccountEntity {
+        AccountEntity::new(
+            &id.to_hex(),
+            &self.name,
+            &self.email,
+            &self.password,
+            self.created_at
+                .unwrap_or_else(|| id.timestamp())
+                .to_chrono(),
+        )
+        .with_email_verified(self.email_verified)
+        .with_password_changed_at(self.password_changed_at.map(bson::DateTime::to_chrono))
+        .with_token_version(self.token_version)
+    }
+}
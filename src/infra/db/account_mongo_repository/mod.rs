@@ -1,16 +1,27 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::mock;
-use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{self, doc};
+use mongodb::options::IndexOptions;
 use mongodb::results::InsertOneResult;
+use mongodb::{Collection, IndexModel};
 
-use crate::data::protocols::AddAccountRepository;
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddAccountRepository, LoadAccountByEmailRepository, LoadAccountByIdRepository,
+    MarkEmailVerifiedRepository, UpdateAccountPasswordRepository,
+};
 use crate::domain::entities::AccountEntity;
 use crate::domain::usecases::AddAccountDto;
+use crate::infra::db::mongo_helper::{map_mongo_error, ACCOUNTS_EMAIL_INDEX};
 use crate::infra::db::MongoHelper;
-use crate::{ErrorMsg, GenericResult};
+use crate::GenericResult;
 
+use self::document::AccountDocument;
 use super::protocols::AccountRepository;
 
+mod document;
 #[cfg(test)]
 mod tests;
 
@@ -19,9 +30,9 @@ pub struct AccountMongoRepository {
 }
 
 impl AccountMongoRepository {
-    pub fn new() -> Self {
+    pub fn new(mongo: MongoHelper) -> Self {
         Self {
-            repository: Box::new(StdAccountRepository),
+            repository: Box::new(StdAccountRepository { mongo }),
         }
     }
 
@@ -33,61 +44,266 @@ impl AccountMongoRepository {
 
 #[async_trait]
 impl AddAccountRepository for AccountMongoRepository {
-    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity> {
+    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DataError> {
         self.repository.add(account_dto).await
     }
 }
 
-struct StdAccountRepository;
+#[async_trait]
+impl LoadAccountByEmailRepository for AccountMongoRepository {
+    async fn load_by_email(&self, email: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        self.repository.load_by_email(email).await
+    }
+}
+
+#[async_trait]
+impl LoadAccountByIdRepository for AccountMongoRepository {
+    async fn load_by_id(&self, id: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        self.repository.load_by_id(id).await
+    }
+}
+
+#[async_trait]
+impl MarkEmailVerifiedRepository for AccountMongoRepository {
+    async fn mark_email_verified(&self, account_id: &str) -> GenericResult<(), DataError> {
+        self.repository.mark_email_verified(account_id).await
+    }
+}
+
+#[async_trait]
+impl UpdateAccountPasswordRepository for AccountMongoRepository {
+    async fn update_password(
+        &self,
+        account_id: &str,
+        password: &str,
+        changed_at: DateTime<Utc>,
+    ) -> GenericResult<(), DataError> {
+        self.repository
+            .update_password(account_id, password, changed_at)
+            .await
+    }
+}
+
+#[async_trait]
+impl AccountRepository for AccountMongoRepository {
+    async fn create_indexes(&self) -> GenericResult<(), DataError> {
+        self.repository.create_indexes().await
+    }
+}
+
+struct StdAccountRepository {
+    mongo: MongoHelper,
+}
+
+impl StdAccountRepository {
+    async fn collection(&self) -> GenericResult<Collection<AccountDocument>, DataError> {
+        let db = self.mongo.get_database().await?;
+        Ok(db.collection::<AccountDocument>("accounts"))
+    }
+}
 
 #[async_trait]
 impl AddAccountRepository for StdAccountRepository {
-    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity> {
-        let client = MongoHelper::get_client().await;
-        let db = client.database("clean-rust-api");
-        let account_collection = db.collection::<AccountEntity>("accounts");
+    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DataError> {
+        let account_collection = self.collection().await?;
 
         let AddAccountDto {
             name,
             email,
             password,
-        } = &account_dto;
+        } = account_dto;
 
-        let account = AccountEntity::new("", name, email, password);
+        // Bson datetimes only keep milliseconds, so the returned account
+        // matches the one loaded later.
+        let created_at = bson::DateTime::now();
 
-        let InsertOneResult { inserted_id, .. } =
-            match account_collection.insert_one(account, None).await {
-                Ok(val) => val,
-                Err(err) => return ErrorMsg::parse(err).into(),
-            };
+        let document = AccountDocument {
+            id: None,
+            name,
+            email,
+            password,
+            created_at: Some(created_at),
+            email_verified: false,
+            password_changed_at: None,
+            token_version: 0,
+        };
 
-        let id = inserted_id.to_string();
+        let InsertOneResult { inserted_id, .. } = account_collection
+            .insert_one(&document, None)
+            .await
+            .map_err(map_mongo_error)?;
 
-        let filter = doc! { "_id": inserted_id };
+        let id = match inserted_id.as_object_id() {
+            Some(id) => id,
+            None => {
+                return Err(DataError::DatabaseFailed(
+                    "inserted account id is not an object id".into(),
+                ))
+            }
+        };
 
-        let find_result = match account_collection.find_one(filter, None).await {
-            Ok(val) => val.unwrap(),
-            Err(err) => return ErrorMsg::parse(err).into(),
+        Ok(document.into_entity(id))
+    }
+}
+
+#[async_trait]
+impl LoadAccountByEmailRepository for StdAccountRepository {
+    async fn load_by_email(&self, email: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        let account_collection = self.collection().await?;
+
+        let filter = doc! { "email": email };
+
+        let document = account_collection
+            .find_one(filter, None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        document.map(map_account).transpose()
+    }
+}
+
+#[async_trait]
+impl LoadAccountByIdRepository for StdAccountRepository {
+    async fn load_by_id(&self, id: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        let account_collection = self.collection().await?;
+
+        // Ids not issued by mongo can't match any account.
+        let id = match ObjectId::parse_str(id) {
+            Ok(id) => id,
+            Err(_) => return Ok(None),
         };
 
-        Ok(AccountEntity::new(
-            &id,
-            find_result.name(),
-            find_result.email(),
-            find_result.password(),
-        ))
+        let filter = doc! { "_id": id };
+
+        let document = account_collection
+            .find_one(filter, None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        document.map(map_account).transpose()
     }
 }
 
-impl AccountRepository for StdAccountRepository {}
+#[async_trait]
+impl MarkEmailVerifiedRepository for StdAccountRepository {
+    async fn mark_email_verified(&self, account_id: &str) -> GenericResult<(), DataError> {
+        let account_collection = self.collection().await?;
+
+        // Ids not issued by mongo can't match any account.
+        let id = match ObjectId::parse_str(account_id) {
+            Ok(id) => id,
+            Err(_) => return Ok(()),
+        };
+
+        let filter = doc! { "_id": id };
+        let update = doc! { "$set": { "email_verified": true } };
+
+        account_collection
+            .update_one(filter, update, None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UpdateAccountPasswordRepository for StdAccountRepository {
+    async fn update_password(
+        &self,
+        account_id: &str,
+        password: &str,
+        changed_at: DateTime<Utc>,
+    ) -> GenericResult<(), DataError> {
+        let account_collection = self.collection().await?;
+
+        // Ids not issued by mongo can't match any account.
+        let id = match ObjectId::parse_str(account_id) {
+            Ok(id) => id,
+            Err(_) => return Ok(()),
+        };
+
+        let filter = doc! { "_id": id };
+        let update = doc! {
+            "$set": {
+                "password": password,
+                "password_changed_at": bson::DateTime::from_chrono(changed_at),
+            },
+            "$inc": { "token_version": 1_i64 },
+        };
+
+        account_collection
+            .update_one(filter, update, None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(())
+    }
+}
+
+fn map_account(document: AccountDocument) -> GenericResult<AccountEntity, DataError> {
+    match document.id {
+        Some(id) => Ok(document.into_entity(id)),
+        None => Err(DataError::DatabaseFailed(
+            "account document has no _id".into(),
+        )),
+    }
+}
+
+#[async_trait]
+impl AccountRepository for StdAccountRepository {
+    async fn create_indexes(&self) -> GenericResult<(), DataError> {
+        let account_collection = self.collection().await?;
+
+        let email_index = IndexModel::builder()
+            .keys(doc! { "email": 1 })
+            .options(
+                IndexOptions::builder()
+                    .name(String::from(ACCOUNTS_EMAIL_INDEX))
+                    .unique(true)
+                    .build(),
+            )
+            .build();
+
+        account_collection
+            .create_index(email_index, None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(())
+    }
+}
 
 mock! {
     StdAccountRepository {}
 
     #[async_trait]
     impl AddAccountRepository for StdAccountRepository {
-        async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity>;
+        async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DataError>;
     }
 
-    impl AccountRepository for StdAccountRepository {}
+    #[async_trait]
+    impl LoadAccountByEmailRepository for StdAccountRepository {
+        async fn load_by_email(&self, email: &str) -> GenericResult<Option<AccountEntity>, DataError>;
+    }
+
+    #[async_trait]
+    impl LoadAccountByIdRepository for StdAccountRepository {
+        async fn load_by_id(&self, id: &str) -> GenericResult<Option<AccountEntity>, DataError>;
+    }
+
+    #[async_trait]
+    impl MarkEmailVerifiedRepository for StdAccountRepository {
+        async fn mark_email_verified(&self, account_id: &str) -> GenericResult<(), DataError>;
+    }
+
+    #[async_trait]
+    impl UpdateAccountPasswordRepository for StdAccountRepository {
+        async fn update_password(&self, account_id: &str, password: &str, changed_at: DateTime<Utc>) -> GenericResult<(), DataError>;
+    }
+
+    #[async_trait]
+    impl AccountRepository for StdAccountRepository {
+        async fn create_indexes(&self) -> GenericResult<(), DataError>;
+    }
 }
@@ -1,6 +1,13 @@
+use chrono::{TimeZone, Utc};
+
 use crate::domain::entities::AccountEntity;
 use crate::domain::usecases::AddAccountDto;
 
+use mongodb::options::ClientOptions;
+use mongodb::Client;
+
+use crate::infra::db::MongoHelper;
+
 use super::{AccountMongoRepository, MockStdAccountRepository};
 
 macro_rules! repository_add_default {
@@ -12,7 +19,27 @@ macro_rules! repository_add_default {
                 password,
             } = &account_dto;
 
-            Ok(AccountEntity::new("valid_id", name, email, password))
+            Ok(AccountEntity::new(
+                "valid_id",
+                name,
+                email,
+                password,
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            ))
+        }
+    };
+}
+
+macro_rules! repository_load_by_email_default {
+    () => {
+        |email| {
+            Ok(Some(AccountEntity::new(
+                "valid_id",
+                "valid_name",
+                email,
+                "valid_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
         }
     };
 }
@@ -20,24 +47,33 @@ macro_rules! repository_add_default {
 fn make_sut() -> AccountMongoRepository {
     let mut repository = make_repository();
     repository.expect_add().returning(repository_add_default!());
+    repository
+        .expect_load_by_email()
+        .returning(repository_load_by_email_default!());
 
-    let mut sut = AccountMongoRepository::new();
+    let mut sut = AccountMongoRepository::new(make_mongo());
     sut.set_repository(repository);
 
     sut
 }
 
+fn make_mongo() -> MongoHelper {
+    let client = Client::with_options(ClientOptions::default()).unwrap();
+    MongoHelper::with_client(client, "any_database")
+}
+
 fn make_repository() -> Box<MockStdAccountRepository> {
     Box::new(MockStdAccountRepository::default())
 }
 
 mod add {
+    use chrono::{TimeZone, Utc};
     use mockall::predicate;
 
+    use crate::data::errors::DataError;
     use crate::data::protocols::add_account_repository::AddAccountRepository;
     use crate::domain::entities::account::AccountEntity;
     use crate::domain::usecases::add_account::AddAccountDto;
-    use crate::ErrorMsg;
 
     use super::{make_repository, make_sut};
 
@@ -63,10 +99,7 @@ mod add {
             password: String::from("valid_password"),
         };
 
-        match sut.add(account_dto).await {
-            Ok(_) => {}
-            Err(_) => {}
-        }
+        let _ = sut.add(account_dto).await;
     }
 
     #[tokio::test]
@@ -74,7 +107,7 @@ mod add {
         let mut repository = make_repository();
         repository
             .expect_add()
-            .returning(|_| ErrorMsg::default().into());
+            .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
 
         let mut sut = make_sut();
         sut.set_repository(repository);
@@ -87,10 +120,7 @@ mod add {
 
         let result = sut.add(account_dto).await;
 
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            ErrorMsg::default().to_string()
-        );
+        assert!(matches!(result.unwrap_err(), DataError::DatabaseFailed(_)));
     }
 
     #[tokio::test]
@@ -111,7 +141,400 @@ mod add {
             assert_eq!(account.email(), "valid_email@mail.com");
             assert_eq!(account.password(), "valid_password");
         } else {
-            assert!(false);
+            panic!();
+        }
+    }
+}
+
+mod load_by_email {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate;
+
+    use crate::data::errors::DataError;
+    use crate::data::protocols::LoadAccountByEmailRepository;
+    use crate::domain::entities::AccountEntity;
+
+    use super::{make_repository, make_sut};
+
+    #[tokio::test]
+    async fn calls_repository_implementation_with_correct_email() {
+        let mut repository = make_repository();
+        repository
+            .expect_load_by_email()
+            .once()
+            .with(predicate::eq("valid_email@mail.com"))
+            .returning(repository_load_by_email_default!());
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let _ = sut.load_by_email("valid_email@mail.com").await;
+    }
+
+    #[tokio::test]
+    async fn returns_err_if_repository_implementation_returns_err() {
+        let mut repository = make_repository();
+        repository
+            .expect_load_by_email()
+            .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let result = sut.load_by_email("valid_email@mail.com").await;
+
+        assert!(matches!(result.unwrap_err(), DataError::DatabaseFailed(_)));
+    }
+
+    #[tokio::test]
+    async fn returns_none_if_repository_implementation_returns_none() {
+        let mut repository = make_repository();
+        repository.expect_load_by_email().returning(|_| Ok(None));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let result = sut.load_by_email("valid_email@mail.com").await;
+
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn returns_an_account_on_success() {
+        let sut = make_sut();
+
+        let account = sut.load_by_email("valid_email@mail.com").await.unwrap();
+
+        assert_eq!(
+            account,
+            Some(AccountEntity::new(
+                "valid_id",
+                "valid_name",
+                "valid_email@mail.com",
+                "valid_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+            ))
+        );
+    }
+}
+
+mod load_by_id {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate;
+
+    use crate::data::errors::DataError;
+    use crate::data::protocols::LoadAccountByIdRepository;
+    use crate::domain::entities::AccountEntity;
+
+    use super::{make_repository, make_sut};
+
+    #[tokio::test]
+    async fn calls_repository_implementation_with_correct_id() {
+        let mut repository = make_repository();
+        repository
+            .expect_load_by_id()
+            .once()
+            .with(predicate::eq("valid_id"))
+            .returning(|_| Ok(None));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let _ = sut.load_by_id("valid_id").await;
+    }
+
+    #[tokio::test]
+    async fn returns_err_if_repository_implementation_returns_err() {
+        let mut repository = make_repository();
+        repository
+            .expect_load_by_id()
+            .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let result = sut.load_by_id("valid_id").await;
+
+        assert!(matches!(result.unwrap_err(), DataError::DatabaseFailed(_)));
+    }
+
+    #[tokio::test]
+    async fn returns_the_account_of_the_repository_implementation() {
+        let mut repository = make_repository();
+        repository.expect_load_by_id().returning(|id| {
+            Ok(Some(AccountEntity::new(
+                id,
+                "valid_name",
+                "valid_email@mail.com",
+                "valid_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        });
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let account = sut.load_by_id("valid_id").await.unwrap().unwrap();
+
+        assert_eq!(account.id(), "valid_id");
+    }
+}
+
+mod mark_email_verified {
+    use mockall::predicate;
+
+    use crate::data::errors::DataError;
+    use crate::data::protocols::MarkEmailVerifiedRepository;
+
+    use super::{make_repository, make_sut};
+
+    #[tokio::test]
+    async fn calls_repository_implementation_with_correct_id() {
+        let mut repository = make_repository();
+        repository
+            .expect_mark_email_verified()
+            .once()
+            .with(predicate::eq("valid_id"))
+            .returning(|_| Ok(()));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        sut.mark_email_verified("valid_id").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn returns_err_if_repository_implementation_returns_err() {
+        let mut repository = make_repository();
+        repository
+            .expect_mark_email_verified()
+            .returning(|_| Err(DataError::DatabaseFailed("any_error".into())));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let result = sut.mark_email_verified("valid_id").await;
+
+        assert!(matches!(result.unwrap_err(), DataError::DatabaseFailed(_)));
+    }
+}
+
+mod update_password {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate;
+
+    use crate::data::errors::DataError;
+    use crate::data::protocols::UpdateAccountPasswordRepository;
+
+    use super::{make_repository, make_sut};
+
+    #[tokio::test]
+    async fn calls_repository_implementation_with_correct_values() {
+        let mut repository = make_repository();
+        repository
+            .expect_update_password()
+            .once()
+            .with(
+                predicate::eq("valid_id"),
+                predicate::eq("hashed_password"),
+                predicate::eq(Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        sut.update_password(
+            "valid_id",
+            "hashed_password",
+            Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn returns_err_if_repository_implementation_returns_err() {
+        let mut repository = make_repository();
+        repository
+            .expect_update_password()
+            .returning(|_, _, _| Err(DataError::DatabaseFailed("any_error".into())));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let result = sut
+            .update_password(
+                "valid_id",
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DataError::DatabaseFailed(_)));
+    }
+}
+
+mod create_indexes {
+    use crate::data::errors::DataError;
+    use crate::infra::db::protocols::AccountRepository;
+
+    use super::{make_repository, make_sut};
+
+    #[tokio::test]
+    async fn calls_repository_implementation() {
+        let mut repository = make_repository();
+        repository
+            .expect_create_indexes()
+            .once()
+            .returning(|| Ok(()));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        sut.create_indexes().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn returns_err_if_repository_implementation_returns_err() {
+        let mut repository = make_repository();
+        repository
+            .expect_create_indexes()
+            .returning(|| Err(DataError::DatabaseFailed("any_error".into())));
+
+        let mut sut = make_sut();
+        sut.set_repository(repository);
+
+        let result = sut.create_indexes().await;
+
+        assert!(matches!(result.unwrap_err(), DataError::DatabaseFailed(_)));
+    }
+}
+
+mod document {
+    use chrono::{TimeZone, Utc};
+    use mongodb::bson::{self, oid::ObjectId, Bson};
+
+    use super::super::document::AccountDocument;
+
+    fn make_document(id: Option<ObjectId>) -> AccountDocument {
+        AccountDocument {
+            id,
+            name: String::from("any_name"),
+            email: String::from("any_email@mail.com"),
+            password: String::from("hashed_password"),
+            created_at: Some(bson::DateTime::from_chrono(
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )),
+            email_verified: false,
+            password_changed_at: None,
+            token_version: 0,
         }
     }
+
+    #[test]
+    fn omits_the_id_fields_when_id_is_none() {
+        let document = bson::to_document(&make_document(None)).unwrap();
+
+        assert!(!document.contains_key("_id"));
+        assert!(!document.contains_key("id"));
+    }
+
+    #[test]
+    fn stores_the_id_as_an_object_id_and_created_at_as_a_datetime() {
+        let id = ObjectId::new();
+
+        let document = bson::to_document(&make_document(Some(id))).unwrap();
+
+        assert_eq!(document.get("_id"), Some(&Bson::ObjectId(id)));
+        assert!(matches!(
+            document.get("created_at"),
+            Some(Bson::DateTime(_))
+        ));
+    }
+
+    #[test]
+    fn round_trips_through_bson() {
+        let document = make_document(Some(ObjectId::new()));
+
+        let bson_document = bson::to_document(&document).unwrap();
+        let parsed: AccountDocument = bson::from_document(bson_document).unwrap();
+
+        assert_eq!(parsed, document);
+    }
+
+    #[test]
+    fn defaults_email_verified_to_false_when_missing() {
+        let mut bson_document = bson::to_document(&make_document(Some(ObjectId::new()))).unwrap();
+        bson_document.remove("email_verified");
+
+        let parsed: AccountDocument = bson::from_document(bson_document).unwrap();
+
+        assert!(!parsed.email_verified);
+    }
+
+    #[test]
+    fn defaults_token_version_to_zero_when_missing() {
+        let mut bson_document = bson::to_document(&make_document(Some(ObjectId::new()))).unwrap();
+        bson_document.remove("token_version");
+
+        let parsed: AccountDocument = bson::from_document(bson_document).unwrap();
+
+        assert_eq!(parsed.token_version, 0);
+    }
+
+    #[test]
+    fn falls_back_to_the_id_timestamp_when_created_at_is_missing() {
+        let id = ObjectId::parse_str("61cf99800000000000000000").unwrap();
+        let bson_document = bson::doc! {
+            "_id": id,
+            "name": "any_name",
+            "email": "any_email@mail.com",
+            "password": "hashed_password",
+        };
+
+        let parsed: AccountDocument = bson::from_document(bson_document).unwrap();
+
+        assert_eq!(parsed.created_at, None);
+        assert_eq!(
+            parsed.into_entity(id).created_at(),
+            Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn stores_password_changed_at_as_a_datetime_once_set() {
+        let document = bson::to_document(&make_document(None)).unwrap();
+
+        assert!(!document.contains_key("password_changed_at"));
+
+        let document = AccountDocument {
+            password_changed_at: Some(bson::DateTime::from_chrono(
+                Utc.ymd(2022, 1, 2).and_hms(0, 0, 0),
+            )),
+            ..make_document(None)
+        };
+
+        let bson_document = bson::to_document(&document).unwrap();
+
+        assert!(matches!(
+            bson_document.get("password_changed_at"),
+            Some(Bson::DateTime(_))
+        ));
+        assert_eq!(
+            document.into_entity(ObjectId::new()).password_changed_at(),
+            Some(Utc.ymd(2022, 1, 2).and_hms(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn maps_the_id_to_its_hex_string() {
+        let id = ObjectId::parse_str("62cefa2d1c8b3bbf4a0d0a4e").unwrap();
+
+        let account = make_document(Some(id)).into_entity(id);
+
+        assert_eq!(account.id(), "62cefa2d1c8b3bbf4a0d0a4e");
+        assert_eq!(account.email(), "any_email@mail.com");
+        assert_eq!(account.created_at(), Utc.ymd(2022, 1, 1).and_hms(0, 0, 0));
+    }
 }
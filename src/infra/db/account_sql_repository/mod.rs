@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::any::AnyRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddAccountRepository, Clock, LoadAccountByEmailRepository, LoadAccountByIdRepository,
+    MarkEmailVerifiedRepository, UpdateAccountPasswordRepository,
+};
+use crate::domain::entities::AccountEntity;
+use crate::domain::usecases::AddAccountDto;
+use crate::infra::clock::SystemClock;
+use crate::infra::db::sql_helper::map_sql_error;
+use crate::infra::db::SqlHelper;
+use crate::GenericResult;
+
+use super::protocols::AccountRepository;
+
+#[cfg(test)]
+mod tests;
+
+pub struct AccountSqlRepository {
+    sql: SqlHelper,
+    clock: Arc<dyn Clock>,
+}
+
+impl AccountSqlRepository {
+    pub fn new(sql: SqlHelper) -> Self {
+        Self::with_clock(sql, Arc::new(SystemClock))
+    }
+
+    pub fn with_clock(sql: SqlHelper, clock: Arc<dyn Clock>) -> Self {
+        Self { sql, clock }
+    }
+}
+
+#[async_trait]
+impl AddAccountRepository for AccountSqlRepository {
+    async fn add(&self, account_dto: AddAccountDto) -> GenericResult<AccountEntity, DataError> {
+        let AddAccountDto {
+            name,
+            email,
+            password,
+        } = &account_dto;
+
+        let id = Uuid::new_v4().to_string();
+        let created_at = self.clock.now();
+
+        sqlx::query(
+            "INSERT INTO accounts (id, name, email, password, created_at) \
+             VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(&id)
+        .bind(name)
+        .bind(email)
+        .bind(password)
+        .bind(created_at)
+        .execute(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        Ok(AccountEntity::new(&id, name, email, password, created_at))
+    }
+}
+
+#[async_trait]
+impl LoadAccountByEmailRepository for AccountSqlRepository {
+    async fn load_by_email(&self, email: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        let row = sqlx::query(
+            "SELECT id, name, email, password, created_at, email_verified, password_changed_at, \
+             token_version FROM accounts WHERE email = $1",
+        )
+        .bind(email)
+        .fetch_optional(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        row.as_ref().map(map_account).transpose()
+    }
+}
+
+#[async_trait]
+impl LoadAccountByIdRepository for AccountSqlRepository {
+    async fn load_by_id(&self, id: &str) -> GenericResult<Option<AccountEntity>, DataError> {
+        let row = sqlx::query(
+            "SELECT id, name, email, password, created_at, email_verified, password_changed_at, \
+             token_version FROM accounts WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        row.as_ref().map(map_account).transpose()
+    }
+}
+
+#[async_trait]
+impl MarkEmailVerifiedRepository for AccountSqlRepository {
+    async fn mark_email_verified(&self, account_id: &str) -> GenericResult<(), DataError> {
+        sqlx::query("UPDATE accounts SET email_verified = $1 WHERE id = $2")
+            .bind(true)
+            .bind(account_id)
+            .execute(self.sql.pool())
+            .await
+            .map_err(map_sql_error)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UpdateAccountPasswordRepository for AccountSqlRepository {
+    async fn update_password(
+        &self,
+        account_id: &str,
+        password: &str,
+        changed_at: DateTime<Utc>,
+    ) -> GenericResult<(), DataError> {
+        sqlx::query(
+            "UPDATE accounts SET password = $1, password_changed_at = $2, \
+             token_version = token_version + 1 WHERE id = $3",
+        )
+        .bind(password)
+        .bind(changed_at)
+        .bind(account_id)
+        .execute(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AccountRepository for AccountSqlRepository {
+    /// Runs the migrations, which also create the unique email index.
+    async fn create_indexes(&self) -> GenericResult<(), DataError> {
+        self.sql.migrate().await
+    }
+}
+
+fn map_account(row: &AnyRow) -> GenericResult<AccountEntity, DataError> {
+    let id: String = row.try_get("id").map_err(map_sql_error)?;
+    let name: String = row.try_get("name").map_err(map_sql_error)?;
+    let email: String = row.try_get("email").map_err(map_sql_error)?;
+    let password: String = row.try_get("password").map_err(map_sql_error)?;
+    let created_at: DateTime<Utc> = row.try_get("created_at").map_err(map_sql_error)?;
+    let email_verified: bool = row.try_get("email_verified").map_err(map_sql_error)?;
+    let password_changed_at: Option<DateTime<Utc>> =
+        row.try_get("password_changed_at").map_err(map_sql_error)?;
+    let token_version: i64 = row.try_get("token_version").map_err(map_sql_error)?;
+
+    let account = AccountEntity::new(&id, &name, &email, &password, created_at);
+
+    Ok(account
+        .with_email_verified(email_verified)
+        .with_password_changed_at(password_changed_at)
+        .with_token_version(token_version))
+}
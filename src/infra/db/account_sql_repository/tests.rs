@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddAccountRepository, LoadAccountByEmailRepository, LoadAccountByIdRepository,
+    MarkEmailVerifiedRepository, MockClock, UpdateAccountPasswordRepository,
+};
+use crate::domain::usecases::AddAccountDto;
+use crate::infra::db::{SqlConfig, SqlHelper};
+
+use super::AccountSqlRepository;
+
+fn make_time() -> DateTime<Utc> {
+    Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+}
+
+async fn make_sql() -> SqlHelper {
+    let config = SqlConfig {
+        url: String::from("sqlite::memory:"),
+        max_connections: Some(1),
+        ..SqlConfig::default()
+    };
+
+    let sql = SqlHelper::connect(&config).await.unwrap();
+    sql.migrate().await.unwrap();
+
+    sql
+}
+
+async fn make_sut() -> AccountSqlRepository {
+    let mut clock = MockClock::default();
+    clock.expect_now().returning(make_time);
+
+    AccountSqlRepository::with_clock(make_sql().await, Arc::new(clock))
+}
+
+fn make_account_dto(email: &str) -> AddAccountDto {
+    AddAccountDto {
+        name: String::from("any_name"),
+        email: String::from(email),
+        password: String::from("hashed_password"),
+    }
+}
+
+mod add {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_an_account_on_success() {
+        let sut = make_sut().await;
+
+        let account = sut
+            .add(make_account_dto("any_email@mail.com"))
+            .await
+            .unwrap();
+
+        assert!(!account.id().is_empty());
+        assert_eq!(account.name(), "any_name");
+        assert_eq!(account.email(), "any_email@mail.com");
+        assert_eq!(account.password(), "hashed_password");
+        assert_eq!(account.created_at(), make_time());
+    }
+
+    #[tokio::test]
+    async fn returns_email_in_use_error_if_email_is_already_registered() {
+        let sut = make_sut().await;
+        sut.add(make_account_dto("any_email@mail.com"))
+            .await
+            .unwrap();
+
+        let err = sut
+            .add(make_account_dto("any_email@mail.com"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DataError::DuplicateEmail));
+    }
+
+    #[tokio::test]
+    async fn returns_err_if_the_table_is_missing() {
+        let config = SqlConfig {
+            url: String::from("sqlite::memory:"),
+            max_connections: Some(1),
+            ..SqlConfig::default()
+        };
+        let sut = AccountSqlRepository::new(SqlHelper::connect(&config).await.unwrap());
+
+        let err = sut
+            .add(make_account_dto("any_email@mail.com"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DataError::DatabaseFailed(_)));
+    }
+}
+
+mod load_by_email {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_the_added_account() {
+        let sut = make_sut().await;
+        let account = sut
+            .add(make_account_dto("any_email@mail.com"))
+            .await
+            .unwrap();
+
+        let loaded = sut.load_by_email("any_email@mail.com").await.unwrap();
+
+        assert_eq!(loaded, Some(account));
+    }
+
+    #[tokio::test]
+    async fn returns_none_if_no_account_has_the_email() {
+        let sut = make_sut().await;
+
+        let loaded = sut.load_by_email("any_email@mail.com").await.unwrap();
+
+        assert_eq!(loaded, None);
+    }
+}
+
+mod load_by_id {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_the_added_account() {
+        let sut = make_sut().await;
+        let account = sut.add(make_account_dto("foo@mail.com")).await.unwrap();
+        sut.add(make_account_dto("bar@mail.com")).await.unwrap();
+
+        let loaded = sut.load_by_id(account.id()).await.unwrap();
+
+        assert_eq!(loaded, Some(account));
+    }
+
+    #[tokio::test]
+    async fn returns_none_if_no_account_has_the_id() {
+        let sut = make_sut().await;
+
+        let loaded = sut.load_by_id("any_id").await.unwrap();
+
+        assert_eq!(loaded, None);
+    }
+}
+
+mod mark_email_verified {
+    use super::*;
+
+    #[tokio::test]
+    async fn marks_the_account_email_as_verified() {
+        let sut = make_sut().await;
+        let account = sut.add(make_account_dto("foo@mail.com")).await.unwrap();
+        sut.add(make_account_dto("bar@mail.com")).await.unwrap();
+
+        sut.mark_email_verified(account.id()).await.unwrap();
+
+        let loaded = sut.load_by_email("foo@mail.com").await.unwrap().unwrap();
+        let other = sut.load_by_email("bar@mail.com").await.unwrap().unwrap();
+
+        assert!(loaded.email_verified());
+        assert!(!other.email_verified());
+    }
+}
+
+mod update_password {
+    use super::*;
+
+    #[tokio::test]
+    async fn replaces_the_account_password_and_records_the_change() {
+        let sut = make_sut().await;
+        let account = sut.add(make_account_dto("foo@mail.com")).await.unwrap();
+        sut.add(make_account_dto("bar@mail.com")).await.unwrap();
+
+        let changed_at = Utc.ymd(2022, 1, 2).and_hms(0, 0, 0);
+        sut.update_password(account.id(), "new_hashed_password", changed_at)
+            .await
+            .unwrap();
+
+        let loaded = sut.load_by_email("foo@mail.com").await.unwrap().unwrap();
+        let other = sut.load_by_email("bar@mail.com").await.unwrap().unwrap();
+
+        assert_eq!(loaded.password(), "new_hashed_password");
+        assert_eq!(loaded.password_changed_at(), Some(changed_at));
+        assert_eq!(other.password(), "hashed_password");
+        assert_eq!(other.password_changed_at(), None);
+    }
+
+    #[tokio::test]
+    async fn bumps_the_token_version_on_each_change() {
+        let sut = make_sut().await;
+        let account = sut.add(make_account_dto("foo@mail.com")).await.unwrap();
+        sut.add(make_account_dto("bar@mail.com")).await.unwrap();
+
+        let changed_at = Utc.ymd(2022, 1, 2).and_hms(0, 0, 0);
+        for _ in 0..2 {
+            sut.update_password(account.id(), "new_hashed_password", changed_at)
+                .await
+                .unwrap();
+        }
+
+        let loaded = sut.load_by_email("foo@mail.com").await.unwrap().unwrap();
+        let other = sut.load_by_email("bar@mail.com").await.unwrap().unwrap();
+
+        assert_eq!(account.token_version(), 0);
+        assert_eq!(loaded.token_version(), 2);
+        assert_eq!(other.token_version(), 0);
+    }
+}
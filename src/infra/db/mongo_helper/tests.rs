@@ -0,0 +1,90 @@
+use std::error::Error;
+
+use mongodb::bson::{self, doc};
+use mongodb::error::{ErrorKind, WriteError, WriteFailure};
+
+use crate::data::errors::DataError;
+
+use super::{map_mongo_error, MongoConfig, MongoHelper};
+
+fn make_config() -> MongoConfig {
+    MongoConfig {
+        database: String::from("any_database"),
+        ..MongoConfig::default()
+    }
+}
+
+fn make_duplicate_key_error(message: &str) -> mongodb::error::Error {
+    let write_error: WriteError = bson::from_document(doc! {
+        "code": 11000,
+        "errmsg": message,
+    })
+    .unwrap();
+
+    ErrorKind::Write(WriteFailure::WriteError(write_error)).into()
+}
+
+#[tokio::test]
+async fn uses_the_configured_database() {
+    let sut = MongoHelper::connect(&make_config()).await.unwrap();
+
+    let database = sut.get_database().await.unwrap();
+
+    assert_eq!(database.name(), "any_database");
+}
+
+#[tokio::test]
+async fn returns_err_if_uri_is_invalid() {
+    let config = MongoConfig {
+        uri: String::from("invalid_uri"),
+        ..make_config()
+    };
+
+    let result = MongoHelper::connect(&config).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn returns_err_after_disconnect() {
+    let sut = MongoHelper::connect(&make_config()).await.unwrap();
+    assert!(sut.is_connected().await);
+
+    sut.disconnect().await;
+
+    assert!(!sut.is_connected().await);
+    let err = sut.get_database().await.unwrap_err();
+    assert!(matches!(err, DataError::DatabaseUnavailable(_)));
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "mongo client is disconnected"
+    );
+}
+
+#[tokio::test]
+async fn disconnect_is_shared_by_clones() {
+    let sut = MongoHelper::connect(&make_config()).await.unwrap();
+    let clone = sut.clone();
+
+    sut.disconnect().await;
+
+    assert!(!clone.is_connected().await);
+}
+
+#[test]
+fn maps_duplicate_keys_of_the_accounts_email_index_to_duplicate_email() {
+    let err = make_duplicate_key_error(
+        "E11000 duplicate key error collection: any_database.accounts index: email_1 dup key: { email: \"any_email@mail.com\" }",
+    );
+
+    assert!(matches!(map_mongo_error(err), DataError::DuplicateEmail));
+}
+
+#[test]
+fn maps_other_duplicate_keys_to_database_failed() {
+    let err = make_duplicate_key_error(
+        "E11000 duplicate key error collection: any_database.verification_tokens index: token_hash_1 dup key: { token_hash: \"any_hash\" }",
+    );
+
+    assert!(matches!(map_mongo_error(err), DataError::DatabaseFailed(_)));
+}
@@ -1,3 +1,5 @@
 pub mod account_repository;
+pub mod verification_token_repository;
 
 pub use account_repository::AccountRepository;
+pub use verification_token_repository::VerificationTokenRepository;
@@ -1,3 +1,21 @@
-use crate::data::protocols::AddAccountRepository;
+use async_trait::async_trait;
 
-pub trait AccountRepository: AddAccountRepository {}
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddAccountRepository, LoadAccountByEmailRepository, LoadAccountByIdRepository,
+    MarkEmailVerifiedRepository, UpdateAccountPasswordRepository,
+};
+use crate::GenericResult;
+
+#[async_trait]
+pub trait AccountRepository:
+    AddAccountRepository
+    + LoadAccountByEmailRepository
+    + LoadAccountByIdRepository
+    + MarkEmailVerifiedRepository
+    + UpdateAccountPasswordRepository
+{
+    /// Creates the indexes the repository relies on, such as the unique
+    /// index on the account email.
+    async fn create_indexes(&self) -> GenericResult<(), DataError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddVerificationTokenRepository, ConsumeVerificationTokenRepository,
+    LoadLatestVerificationTokenRepository, LoadVerificationTokenByHashRepository,
+};
+use crate::GenericResult;
+
+#[async_trait]
+pub trait VerificationTokenRepository:
+    AddVerificationTokenRepository
+    + ConsumeVerificationTokenRepository
+    + LoadVerificationTokenByHashRepository
+    + LoadLatestVerificationTokenRepository
+{
+    /// Creates the indexes the repository relies on, such as the unique
+    /// index on the token hash.
+    async fn create_indexes(&self) -> GenericResult<(), DataError>;
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use sqlx::any::{AnyKind, AnyPool, AnyPoolOptions};
+use sqlx::error::DatabaseError;
+use sqlx::migrate::{MigrateError, Migrator};
+
+use crate::data::errors::DataError;
+use crate::GenericResult;
+
+#[cfg(test)]
+mod tests;
+
+static SQLITE_MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+static POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+
+/// Handle to a SQLite or Postgres connection pool, picked by the scheme of
+/// the configured url. Clones share the same pool.
+#[derive(Debug, Clone)]
+pub struct SqlHelper {
+    pool: AnyPool,
+}
+
+impl SqlHelper {
+    pub async fn connect(config: &SqlConfig) -> GenericResult<Self> {
+        let mut options = AnyPoolOptions::new();
+
+        if let Some(max_connections) = config.max_connections {
+            options = options.max_connections(max_connections);
+        }
+
+        if let Some(connect_timeout_ms) = config.connect_timeout_ms {
+            options = options.acquire_timeout(Duration::from_millis(connect_timeout_ms));
+        }
+
+        let pool = options.connect(&config.url).await?;
+
+        Ok(Self { pool })
+    }
+
+    pub fn pool(&self) -> &AnyPool {
+        &self.pool
+    }
+
+    pub fn kind(&self) -> AnyKind {
+        self.pool.any_kind()
+    }
+
+    /// Applies the embedded migrations of the pool's database that have not
+    /// run yet.
+    pub async fn migrate(&self) -> GenericResult<(), DataError> {
+        let migrator = match self.kind() {
+            AnyKind::Sqlite => &SQLITE_MIGRATOR,
+            AnyKind::Postgres => &POSTGRES_MIGRATOR,
+        };
+
+        migrator.run(&self.pool).await.map_err(|err| match err {
+            MigrateError::Execute(err) => map_sql_error(err),
+            err => DataError::DatabaseFailed(Box::new(err)),
+        })
+    }
+
+    /// Closes the pool, waiting for the connections in use to be returned.
+    pub async fn disconnect(&self) {
+        self.pool.close().await;
+    }
+
+    pub fn is_connected(&self) -> bool {
+        !self.pool.is_closed()
+    }
+}
+
+/// Maps a sqlx error to the data error the repositories return. Only the
+/// unique violations of the accounts email column are a `DuplicateEmail`.
+pub fn map_sql_error(err: sqlx::Error) -> DataError {
+    match &err {
+        sqlx::Error::Database(db_err) if is_duplicate_account_email(db_err.as_ref()) => {
+            DataError::DuplicateEmail
+        }
+        sqlx::Error::Io(_)
+        | sqlx::Error::Tls(_)
+        | sqlx::Error::PoolTimedOut
+        | sqlx::Error::PoolClosed => DataError::DatabaseUnavailable(Box::new(err)),
+        _ => DataError::DatabaseFailed(Box::new(err)),
+    }
+}
+
+fn is_duplicate_account_email(db_err: &dyn DatabaseError) -> bool {
+    const POSTGRES_UNIQUE_VIOLATION: &str = "23505";
+    const POSTGRES_ACCOUNTS_EMAIL_CONSTRAINT: &str = "accounts_email_key";
+    const SQLITE_CONSTRAINT_UNIQUE: &str = "2067";
+    const SQLITE_ACCOUNTS_EMAIL_MESSAGE: &str = "UNIQUE constraint failed: accounts.email";
+
+    match db_err.code().as_deref() {
+        Some(POSTGRES_UNIQUE_VIOLATION) => {
+            db_err.constraint() == Some(POSTGRES_ACCOUNTS_EMAIL_CONSTRAINT)
+        }
+        Some(SQLITE_CONSTRAINT_UNIQUE) => db_err.message() == SQLITE_ACCOUNTS_EMAIL_MESSAGE,
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct SqlConfig {
+    /// A `sqlite:` or `postgres://` connection url.
+    pub url: String,
+    pub max_connections: Option<u32>,
+    pub connect_timeout_ms: Option<u64>,
+}
+
+impl Default for SqlConfig {
+    fn default() -> Self {
+        Self {
+            url: String::from("sqlite:clean-rust-api.db?mode=rwc"),
+            max_connections: None,
+            connect_timeout_ms: None,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use sqlx::any::AnyKind;
+
+use crate::data::errors::DataError;
+
+use super::{map_sql_error, SqlConfig, SqlHelper};
+
+fn make_config() -> SqlConfig {
+    SqlConfig {
+        url: String::from("sqlite::memory:"),
+        max_connections: Some(1),
+        ..SqlConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn picks_the_database_from_the_url() {
+    let sut = SqlHelper::connect(&make_config()).await.unwrap();
+
+    assert_eq!(sut.kind(), AnyKind::Sqlite);
+}
+
+#[tokio::test]
+async fn returns_err_if_url_is_invalid() {
+    let config = SqlConfig {
+        url: String::from("invalid_url"),
+        ..make_config()
+    };
+
+    let result = SqlHelper::connect(&config).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn creates_the_accounts_table() {
+    let sut = SqlHelper::connect(&make_config()).await.unwrap();
+
+    sut.migrate().await.unwrap();
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM accounts")
+        .fetch_one(sut.pool())
+        .await
+        .unwrap();
+
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn migrate_can_run_twice() {
+    let sut = SqlHelper::connect(&make_config()).await.unwrap();
+
+    sut.migrate().await.unwrap();
+
+    assert!(sut.migrate().await.is_ok());
+}
+
+#[tokio::test]
+async fn returns_err_after_disconnect() {
+    let sut = SqlHelper::connect(&make_config()).await.unwrap();
+    assert!(sut.is_connected());
+
+    sut.disconnect().await;
+
+    assert!(!sut.is_connected());
+    assert!(matches!(
+        sut.migrate().await.unwrap_err(),
+        DataError::DatabaseUnavailable(_)
+    ));
+}
+
+#[tokio::test]
+async fn maps_duplicate_account_emails_to_duplicate_email() {
+    let sut = SqlHelper::connect(&make_config()).await.unwrap();
+    sut.migrate().await.unwrap();
+
+    let insert = |id: &'static str| {
+        sqlx::query(
+            "INSERT INTO accounts (id, name, email, password, created_at) \
+             VALUES ($1, 'any_name', 'any_email@mail.com', 'any_password', '2022-01-01')",
+        )
+        .bind(id)
+    };
+
+    insert("first_id").execute(sut.pool()).await.unwrap();
+    let err = insert("second_id").execute(sut.pool()).await.unwrap_err();
+
+    assert!(matches!(map_sql_error(err), DataError::DuplicateEmail));
+}
+
+#[tokio::test]
+async fn maps_other_unique_violations_to_database_failed() {
+    let sut = SqlHelper::connect(&make_config()).await.unwrap();
+
+    sqlx::query("CREATE TABLE others (code TEXT UNIQUE)")
+        .execute(sut.pool())
+        .await
+        .unwrap();
+
+    let insert = || sqlx::query("INSERT INTO others (code) VALUES ('any_code')");
+
+    insert().execute(sut.pool()).await.unwrap();
+    let err = insert().execute(sut.pool()).await.unwrap_err();
+
+    assert!(matches!(map_sql_error(err), DataError::DatabaseFailed(_)));
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddVerificationTokenRepository, ConsumeVerificationTokenRepository,
+    LoadLatestVerificationTokenRepository, LoadVerificationTokenByHashRepository,
+};
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::GenericResult;
+
+use super::protocols::VerificationTokenRepository;
+
+#[cfg(test)]
+mod tests;
+
+/// Verification token repository kept in process memory, for tests and local
+/// development without a database. Clones share the same tokens.
+#[derive(Clone, Default)]
+pub struct VerificationTokenInMemoryRepository {
+    tokens: Arc<RwLock<HashMap<(TokenPurpose, String), VerificationTokenEntity>>>,
+}
+
+impl VerificationTokenInMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AddVerificationTokenRepository for VerificationTokenInMemoryRepository {
+    async fn add(&self, token: VerificationTokenEntity) -> GenericResult<(), DataError> {
+        let key = (token.purpose(), token.token_hash().to_string());
+
+        self.tokens.write().await.insert(key, token);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConsumeVerificationTokenRepository for VerificationTokenInMemoryRepository {
+    async fn consume(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let key = (purpose, token_hash.to_string());
+
+        Ok(self.tokens.write().await.remove(&key))
+    }
+}
+
+#[async_trait]
+impl LoadVerificationTokenByHashRepository for VerificationTokenInMemoryRepository {
+    async fn load_by_hash(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let key = (purpose, token_hash.to_string());
+
+        Ok(self.tokens.read().await.get(&key).cloned())
+    }
+}
+
+#[async_trait]
+impl LoadLatestVerificationTokenRepository for VerificationTokenInMemoryRepository {
+    async fn load_latest(
+        &self,
+        account_id: &str,
+        purpose: TokenPurpose,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let tokens = self.tokens.read().await;
+
+        Ok(tokens
+            .values()
+            .filter(|token| token.account_id() == account_id && token.purpose() == purpose)
+            .filter(|token| token.issued_at().is_some())
+            .max_by_key(|token| token.issued_at())
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl VerificationTokenRepository for VerificationTokenInMemoryRepository {
+    async fn create_indexes(&self) -> GenericResult<(), DataError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::{Duration, TimeZone, Utc};
+
+use crate::data::protocols::{
+    AddVerificationTokenRepository, ConsumeVerificationTokenRepository,
+    LoadLatestVerificationTokenRepository, LoadVerificationTokenByHashRepository,
+};
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+
+use super::VerificationTokenInMemoryRepository;
+
+fn make_token() -> VerificationTokenEntity {
+    VerificationTokenEntity::new(
+        "any_id",
+        "hashed_token",
+        TokenPurpose::EmailVerification,
+        Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+    )
+}
+
+#[tokio::test]
+async fn consumes_an_added_token_once() {
+    let sut = VerificationTokenInMemoryRepository::new();
+    sut.add(make_token()).await.unwrap();
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, Some(make_token()));
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, None);
+}
+
+#[tokio::test]
+async fn loads_an_added_token_without_consuming_it() {
+    let sut = VerificationTokenInMemoryRepository::new();
+    sut.add(make_token()).await.unwrap();
+
+    let loaded = sut
+        .load_by_hash(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(loaded, Some(make_token()));
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, Some(make_token()));
+}
+
+#[tokio::test]
+async fn returns_none_if_the_token_is_unknown() {
+    let sut = VerificationTokenInMemoryRepository::new();
+    sut.add(make_token()).await.unwrap();
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "other_hash")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, None);
+}
+
+#[tokio::test]
+async fn loads_the_latest_token_issued_for_the_account_and_purpose() {
+    let sut = VerificationTokenInMemoryRepository::new();
+    let issued_at = Utc.ymd(2022, 1, 1).and_hms(0, 0, 0);
+
+    for (token_hash, account_id, purpose, minutes) in [
+        ("first_hash", "any_id", TokenPurpose::EmailVerification, 0),
+        ("latest_hash", "any_id", TokenPurpose::EmailVerification, 2),
+        (
+            "other_purpose_hash",
+            "any_id",
+            TokenPurpose::PasswordReset,
+            3,
+        ),
+        (
+            "other_account_hash",
+            "other_id",
+            TokenPurpose::EmailVerification,
+            3,
+        ),
+    ] {
+        let token = VerificationTokenEntity::new(account_id, token_hash, purpose, issued_at)
+            .with_issued_at(Some(issued_at + Duration::minutes(minutes)));
+        sut.add(token).await.unwrap();
+    }
+    sut.add(make_token()).await.unwrap();
+
+    let latest = sut
+        .load_latest("any_id", TokenPurpose::EmailVerification)
+        .await
+        .unwrap();
+
+    assert_eq!(latest.unwrap().token_hash(), "latest_hash");
+}
+
+#[tokio::test]
+async fn shares_the_tokens_between_clones() {
+    let sut = VerificationTokenInMemoryRepository::new();
+    sut.clone().add(make_token()).await.unwrap();
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert!(consumed.is_some());
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use mongodb::bson;
+use mongodb::bson::serde_helpers::chrono_datetime_as_bson_datetime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+
+/// Shape of a token in the `verification_tokens` collection. The purpose is
+/// stored as its string form and `expires_at` as a bson datetime, which the
+/// TTL index needs.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub(super) struct VerificationTokenDocument {
+    pub token_hash: String,
+    pub purpose: String,
+    pub account_id: String,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+    /// Missing on tokens stored before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued_at: Option<bson::DateTime>,
+}
+
+impl VerificationTokenDocument {
+    pub fn from_entity(token: &VerificationTokenEntity) -> Self {
+        Self {
+            token_hash: token.token_hash().to_string(),
+            purpose: token.purpose().as_str().to_string(),
+            account_id: token.account_id().to_string(),
+            expires_at: token.expires_at(),
+            issued_at: token.issued_at().map(bson::DateTime::from_chrono),
+        }
+    }
+
+    pub fn into_entity(self, purpose: TokenPurpose) -> VerificationTokenEntity {
+        VerificationTokenEntity::new(&self.account_id, &self.token_hash, purpose, self.expires_at)
+            .with_issued_at(self.issued_at.map(bson::DateTime::to_chrono))
+    }
+}
@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use mongodb::options::{FindOneOptions, IndexOptions};
+use mongodb::{Collection, IndexModel};
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddVerificationTokenRepository, ConsumeVerificationTokenRepository,
+    LoadLatestVerificationTokenRepository, LoadVerificationTokenByHashRepository,
+};
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::infra::db::mongo_helper::map_mongo_error;
+use crate::infra::db::MongoHelper;
+use crate::GenericResult;
+
+use self::document::VerificationTokenDocument;
+use super::protocols::VerificationTokenRepository;
+
+mod document;
+#[cfg(test)]
+mod tests;
+
+pub struct VerificationTokenMongoRepository {
+    mongo: MongoHelper,
+}
+
+impl VerificationTokenMongoRepository {
+    pub fn new(mongo: MongoHelper) -> Self {
+        Self { mongo }
+    }
+
+    async fn collection(&self) -> GenericResult<Collection<VerificationTokenDocument>, DataError> {
+        let db = self.mongo.get_database().await?;
+        Ok(db.collection::<VerificationTokenDocument>("verification_tokens"))
+    }
+}
+
+#[async_trait]
+impl AddVerificationTokenRepository for VerificationTokenMongoRepository {
+    async fn add(&self, token: VerificationTokenEntity) -> GenericResult<(), DataError> {
+        let token_collection = self.collection().await?;
+
+        token_collection
+            .insert_one(VerificationTokenDocument::from_entity(&token), None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConsumeVerificationTokenRepository for VerificationTokenMongoRepository {
+    /// Deletes and returns the token in a single operation, so concurrent
+    /// requests can't redeem the same token twice.
+    async fn consume(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let token_collection = self.collection().await?;
+
+        let filter = doc! { "token_hash": token_hash, "purpose": purpose.as_str() };
+
+        let document = token_collection
+            .find_one_and_delete(filter, None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(document.map(|document| document.into_entity(purpose)))
+    }
+}
+
+#[async_trait]
+impl LoadVerificationTokenByHashRepository for VerificationTokenMongoRepository {
+    async fn load_by_hash(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let token_collection = self.collection().await?;
+
+        let filter = doc! { "token_hash": token_hash, "purpose": purpose.as_str() };
+
+        let document = token_collection
+            .find_one(filter, None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(document.map(|document| document.into_entity(purpose)))
+    }
+}
+
+#[async_trait]
+impl LoadLatestVerificationTokenRepository for VerificationTokenMongoRepository {
+    async fn load_latest(
+        &self,
+        account_id: &str,
+        purpose: TokenPurpose,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let token_collection = self.collection().await?;
+
+        let filter = doc! {
+            "account_id": account_id,
+            "purpose": purpose.as_str(),
+            "issued_at": { "$exists": true },
+        };
+        let options = FindOneOptions::builder()
+            .sort(doc! { "issued_at": -1 })
+            .build();
+
+        let document = token_collection
+            .find_one(filter, options)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(document.map(|document| document.into_entity(purpose)))
+    }
+}
+
+#[async_trait]
+impl VerificationTokenRepository for VerificationTokenMongoRepository {
+    /// Creates the unique token hash index, the index finding the latest
+    /// token of an account and a TTL index that lets the server drop expired
+    /// tokens.
+    async fn create_indexes(&self) -> GenericResult<(), DataError> {
+        let token_collection = self.collection().await?;
+
+        let token_hash_index = IndexModel::builder()
+            .keys(doc! { "token_hash": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        let account_id_index = IndexModel::builder()
+            .keys(doc! { "account_id": 1, "purpose": 1, "issued_at": -1 })
+            .build();
+
+        let expires_at_index = IndexModel::builder()
+            .keys(doc! { "expires_at": 1 })
+            .options(
+                IndexOptions::builder()
+                    .expire_after(Duration::from_secs(0))
+                    .build(),
+            )
+            .build();
+
+        token_collection
+            .create_indexes([token_hash_index, account_id_index, expires_at_index], None)
+            .await
+            .map_err(map_mongo_error)?;
+
+        Ok(())
+    }
+}
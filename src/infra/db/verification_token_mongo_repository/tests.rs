@@ -0,0 +1,65 @@
+use chrono::{TimeZone, Utc};
+use mongodb::bson::{self, Bson};
+
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+
+use super::document::VerificationTokenDocument;
+
+fn make_token() -> VerificationTokenEntity {
+    VerificationTokenEntity::new(
+        "any_id",
+        "hashed_token",
+        TokenPurpose::EmailVerification,
+        Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+    )
+}
+
+#[test]
+fn stores_the_purpose_as_a_string_and_expires_at_as_a_datetime() {
+    let document = VerificationTokenDocument::from_entity(&make_token());
+
+    let bson_document = bson::to_document(&document).unwrap();
+
+    assert_eq!(
+        bson_document.get("purpose"),
+        Some(&Bson::String(String::from("email_verification")))
+    );
+    assert!(matches!(
+        bson_document.get("expires_at"),
+        Some(Bson::DateTime(_))
+    ));
+}
+
+#[test]
+fn stores_issued_at_as_a_datetime_once_set() {
+    let bson_document =
+        bson::to_document(&VerificationTokenDocument::from_entity(&make_token())).unwrap();
+
+    assert!(!bson_document.contains_key("issued_at"));
+
+    let token = make_token().with_issued_at(Some(Utc.ymd(2021, 12, 31).and_hms(0, 0, 0)));
+
+    let bson_document = bson::to_document(&VerificationTokenDocument::from_entity(&token)).unwrap();
+
+    assert!(matches!(
+        bson_document.get("issued_at"),
+        Some(Bson::DateTime(_))
+    ));
+
+    let parsed: VerificationTokenDocument = bson::from_document(bson_document).unwrap();
+
+    assert_eq!(parsed.into_entity(TokenPurpose::EmailVerification), token);
+}
+
+#[test]
+fn round_trips_through_bson() {
+    let document = VerificationTokenDocument::from_entity(&make_token());
+
+    let bson_document = bson::to_document(&document).unwrap();
+    let parsed: VerificationTokenDocument = bson::from_document(bson_document).unwrap();
+
+    assert_eq!(
+        parsed.into_entity(TokenPurpose::EmailVerification),
+        make_token()
+    );
+}
@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::any::AnyRow;
+use sqlx::Row;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{
+    AddVerificationTokenRepository, ConsumeVerificationTokenRepository,
+    LoadLatestVerificationTokenRepository, LoadVerificationTokenByHashRepository,
+};
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::infra::db::sql_helper::map_sql_error;
+use crate::infra::db::SqlHelper;
+use crate::GenericResult;
+
+use super::protocols::VerificationTokenRepository;
+
+#[cfg(test)]
+mod tests;
+
+pub struct VerificationTokenSqlRepository {
+    sql: SqlHelper,
+}
+
+impl VerificationTokenSqlRepository {
+    pub fn new(sql: SqlHelper) -> Self {
+        Self { sql }
+    }
+}
+
+#[async_trait]
+impl AddVerificationTokenRepository for VerificationTokenSqlRepository {
+    async fn add(&self, token: VerificationTokenEntity) -> GenericResult<(), DataError> {
+        sqlx::query(
+            "INSERT INTO verification_tokens \
+             (token_hash, purpose, account_id, expires_at, issued_at) \
+             VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(token.token_hash())
+        .bind(token.purpose().as_str())
+        .bind(token.account_id())
+        .bind(token.expires_at())
+        .bind(token.issued_at())
+        .execute(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConsumeVerificationTokenRepository for VerificationTokenSqlRepository {
+    /// Deletes and returns the token in a single statement, so concurrent
+    /// requests can't redeem the same token twice.
+    async fn consume(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let row = sqlx::query(
+            "DELETE FROM verification_tokens WHERE token_hash = $1 AND purpose = $2 \
+             RETURNING token_hash, account_id, expires_at, issued_at",
+        )
+        .bind(token_hash)
+        .bind(purpose.as_str())
+        .fetch_optional(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        row.map(|row| map_token(&row, purpose)).transpose()
+    }
+}
+
+#[async_trait]
+impl LoadVerificationTokenByHashRepository for VerificationTokenSqlRepository {
+    async fn load_by_hash(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let row = sqlx::query(
+            "SELECT token_hash, account_id, expires_at, issued_at FROM verification_tokens \
+             WHERE token_hash = $1 AND purpose = $2",
+        )
+        .bind(token_hash)
+        .bind(purpose.as_str())
+        .fetch_optional(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        row.map(|row| map_token(&row, purpose)).transpose()
+    }
+}
+
+#[async_trait]
+impl LoadLatestVerificationTokenRepository for VerificationTokenSqlRepository {
+    async fn load_latest(
+        &self,
+        account_id: &str,
+        purpose: TokenPurpose,
+    ) -> GenericResult<Option<VerificationTokenEntity>, DataError> {
+        let row = sqlx::query(
+            "SELECT token_hash, account_id, expires_at, issued_at FROM verification_tokens \
+             WHERE account_id = $1 AND purpose = $2 AND issued_at IS NOT NULL \
+             ORDER BY issued_at DESC LIMIT 1",
+        )
+        .bind(account_id)
+        .bind(purpose.as_str())
+        .fetch_optional(self.sql.pool())
+        .await
+        .map_err(map_sql_error)?;
+
+        row.map(|row| map_token(&row, purpose)).transpose()
+    }
+}
+
+#[async_trait]
+impl VerificationTokenRepository for VerificationTokenSqlRepository {
+    /// Runs the migrations, which also create the token table.
+    async fn create_indexes(&self) -> GenericResult<(), DataError> {
+        self.sql.migrate().await
+    }
+}
+
+fn map_token(
+    row: &AnyRow,
+    purpose: TokenPurpose,
+) -> GenericResult<VerificationTokenEntity, DataError> {
+    let token_hash: String = row.try_get("token_hash").map_err(map_sql_error)?;
+    let account_id: String = row.try_get("account_id").map_err(map_sql_error)?;
+    let expires_at: DateTime<Utc> = row.try_get("expires_at").map_err(map_sql_error)?;
+    let issued_at: Option<DateTime<Utc>> = row.try_get("issued_at").map_err(map_sql_error)?;
+
+    Ok(
+        VerificationTokenEntity::new(&account_id, &token_hash, purpose, expires_at)
+            .with_issued_at(issued_at),
+    )
+}
@@ -0,0 +1,143 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::data::protocols::{
+    AddAccountRepository, AddVerificationTokenRepository, ConsumeVerificationTokenRepository,
+    LoadLatestVerificationTokenRepository, LoadVerificationTokenByHashRepository,
+};
+use crate::domain::entities::{TokenPurpose, VerificationTokenEntity};
+use crate::domain::usecases::AddAccountDto;
+use crate::infra::db::{AccountSqlRepository, SqlConfig, SqlHelper};
+
+use super::VerificationTokenSqlRepository;
+
+fn make_time() -> DateTime<Utc> {
+    Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)
+}
+
+async fn make_sql() -> SqlHelper {
+    let config = SqlConfig {
+        url: String::from("sqlite::memory:"),
+        max_connections: Some(1),
+        ..SqlConfig::default()
+    };
+
+    let sql = SqlHelper::connect(&config).await.unwrap();
+    sql.migrate().await.unwrap();
+
+    sql
+}
+
+/// Creates the sut along with the id of an account the tokens can refer to.
+async fn make_sut() -> (VerificationTokenSqlRepository, String) {
+    let sql = make_sql().await;
+
+    let account = AccountSqlRepository::new(sql.clone())
+        .add(AddAccountDto {
+            name: String::from("any_name"),
+            email: String::from("any_email@mail.com"),
+            password: String::from("hashed_password"),
+        })
+        .await
+        .unwrap();
+
+    (
+        VerificationTokenSqlRepository::new(sql),
+        account.id().to_string(),
+    )
+}
+
+fn make_token(account_id: &str) -> VerificationTokenEntity {
+    VerificationTokenEntity::new(
+        account_id,
+        "hashed_token",
+        TokenPurpose::EmailVerification,
+        make_time(),
+    )
+}
+
+#[tokio::test]
+async fn consumes_an_added_token_once() {
+    let (sut, account_id) = make_sut().await;
+    sut.add(make_token(&account_id)).await.unwrap();
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, Some(make_token(&account_id)));
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, None);
+}
+
+#[tokio::test]
+async fn loads_an_added_token_without_consuming_it() {
+    let (sut, account_id) = make_sut().await;
+    sut.add(make_token(&account_id)).await.unwrap();
+
+    let loaded = sut
+        .load_by_hash(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(loaded, Some(make_token(&account_id)));
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "hashed_token")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, Some(make_token(&account_id)));
+}
+
+#[tokio::test]
+async fn returns_none_if_the_token_is_unknown() {
+    let (sut, account_id) = make_sut().await;
+    sut.add(make_token(&account_id)).await.unwrap();
+
+    let consumed = sut
+        .consume(TokenPurpose::EmailVerification, "other_hash")
+        .await
+        .unwrap();
+
+    assert_eq!(consumed, None);
+}
+
+#[tokio::test]
+async fn loads_the_latest_token_issued_for_the_account_and_purpose() {
+    let (sut, account_id) = make_sut().await;
+
+    for (token_hash, purpose, minutes) in [
+        ("first_hash", TokenPurpose::EmailVerification, 0),
+        ("latest_hash", TokenPurpose::EmailVerification, 2),
+        ("other_purpose_hash", TokenPurpose::PasswordReset, 3),
+    ] {
+        let token = VerificationTokenEntity::new(&account_id, token_hash, purpose, make_time())
+            .with_issued_at(Some(make_time() + Duration::minutes(minutes)));
+        sut.add(token).await.unwrap();
+    }
+    sut.add(make_token(&account_id)).await.unwrap();
+
+    let latest = sut
+        .load_latest(&account_id, TokenPurpose::EmailVerification)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        latest,
+        Some(
+            VerificationTokenEntity::new(
+                &account_id,
+                "latest_hash",
+                TokenPurpose::EmailVerification,
+                make_time(),
+            )
+            .with_issued_at(Some(make_time() + Duration::minutes(2)))
+        )
+    );
+}
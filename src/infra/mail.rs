@@ -0,0 +1,5 @@
+pub mod in_memory_mailer;
+pub mod smtp_mailer;
+
+pub use in_memory_mailer::InMemoryMailer;
+pub use smtp_mailer::{SmtpConfig, SmtpMailer};
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{Mail, Mailer};
+use crate::GenericResult;
+
+#[cfg(test)]
+mod tests;
+
+/// Mailer keeping the mails in process memory instead of sending them, for
+/// tests and local development. Clones share the same mails.
+#[derive(Clone, Default)]
+pub struct InMemoryMailer {
+    mails: Arc<RwLock<Vec<Mail>>>,
+}
+
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the mails sent so far, oldest first.
+    pub async fn sent(&self) -> Vec<Mail> {
+        self.mails.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send(&self, mail: Mail) -> GenericResult<(), DataError> {
+        self.mails.write().await.push(mail);
+        Ok(())
+    }
+}
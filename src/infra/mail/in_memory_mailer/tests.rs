@@ -0,0 +1,36 @@
+use crate::data::protocols::{Mail, Mailer};
+
+use super::InMemoryMailer;
+
+fn make_mail(to: &str) -> Mail {
+    Mail {
+        to: String::from(to),
+        subject: String::from("any_subject"),
+        body: String::from("any_body"),
+    }
+}
+
+#[tokio::test]
+async fn keeps_the_sent_mails_in_order() {
+    let sut = InMemoryMailer::new();
+
+    sut.send(make_mail("first@mail.com")).await.unwrap();
+    sut.send(make_mail("second@mail.com")).await.unwrap();
+
+    assert_eq!(
+        sut.sent().await,
+        vec![make_mail("first@mail.com"), make_mail("second@mail.com")]
+    );
+}
+
+#[tokio::test]
+async fn clones_share_the_mails() {
+    let sut = InMemoryMailer::new();
+
+    sut.clone()
+        .send(make_mail("any_email@mail.com"))
+        .await
+        .unwrap();
+
+    assert_eq!(sut.sent().await.len(), 1);
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::Deserialize;
+
+use crate::data::errors::DataError;
+use crate::data::protocols::{Mail, Mailer};
+use crate::GenericResult;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct SmtpConfig {
+    pub host: String,
+    /// Defaults to 587 with STARTTLS and 25 without.
+    pub port: Option<u16>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Sender of the mails, e.g. `"Clean Rust API <no-reply@example.com>"`.
+    pub from: String,
+    /// Upgrades the connection with STARTTLS. Only disable it for local
+    /// servers, credentials are sent in plain text otherwise.
+    pub starttls: bool,
+}
+
+impl Default for SmtpConfig {
+    fn default() -> Self {
+        Self {
+            host: String::from("localhost"),
+            port: None,
+            username: None,
+            password: None,
+            from: String::from("Clean Rust API <no-reply@localhost>"),
+            starttls: true,
+        }
+    }
+}
+
+/// Sends mails through an SMTP relay. The connection is opened lazily, on the
+/// first mail sent.
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &SmtpConfig) -> GenericResult<Self> {
+        let mut builder = if config.starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+        };
+
+        if let Some(port) = config.port {
+            builder = builder.port(port);
+        }
+
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from: config.from.parse()?,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: Mail) -> GenericResult<(), DataError> {
+        let to: Mailbox = mail
+            .to
+            .parse()
+            .map_err(|err| DataError::MailFailed(Box::new(err)))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(mail.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(mail.body)
+            .map_err(|err| DataError::MailFailed(Box::new(err)))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|err| DataError::MailFailed(Box::new(err)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::data::errors::DataError;
+use crate::data::protocols::{Mail, Mailer};
+
+use super::{SmtpConfig, SmtpMailer};
+
+fn make_config() -> SmtpConfig {
+    SmtpConfig {
+        host: String::from("127.0.0.1"),
+        // Nothing listens on port 1, so connecting fails right away.
+        port: Some(1),
+        starttls: false,
+        ..SmtpConfig::default()
+    }
+}
+
+fn make_mail(to: &str) -> Mail {
+    Mail {
+        to: String::from(to),
+        subject: String::from("any_subject"),
+        body: String::from("any_body"),
+    }
+}
+
+#[test]
+fn returns_err_if_the_sender_is_invalid() {
+    let config = SmtpConfig {
+        from: String::from("invalid_sender"),
+        ..make_config()
+    };
+
+    assert!(SmtpMailer::new(&config).is_err());
+}
+
+#[tokio::test]
+async fn returns_mail_failed_if_the_recipient_is_invalid() {
+    let sut = SmtpMailer::new(&make_config()).unwrap();
+
+    let err = sut.send(make_mail("invalid_email")).await.unwrap_err();
+
+    assert!(matches!(err, DataError::MailFailed(_)));
+}
+
+#[tokio::test]
+async fn returns_mail_failed_if_the_server_is_unreachable() {
+    let sut = SmtpMailer::new(&make_config()).unwrap();
+
+    let err = sut.send(make_mail("any_email@mail.com")).await.unwrap_err();
+
+    assert!(matches!(err, DataError::MailFailed(_)));
+}
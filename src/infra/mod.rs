@@ -1,2 +1,4 @@
+pub mod clock;
 pub mod crypto;
 pub mod db;
+pub mod mail;
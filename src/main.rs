@@ -1,10 +1,105 @@
-use actix_web::{App, HttpServer};
+use std::io;
+use std::time::Duration;
+
+use actix_web::{web, App, HttpServer};
+use clean_rust_api::app::config::Config;
+use clean_rust_api::app::database::Database;
+use clean_rust_api::app::factories::{
+    make_auth_middleware, make_change_password_controller, make_forgot_password_controller,
+    make_login_controller, make_resend_email_verification_controller,
+    make_reset_password_controller, make_signup_controller, make_verify_email_controller,
+};
+use clean_rust_api::app::logger::init_logger;
+use clean_rust_api::app::server::load_rustls_config;
 use clean_rust_api::setup_app;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    HttpServer::new(|| App::new().configure(setup_app))
-        .bind(("127.0.0.1", 8000))?
-        .run()
+    let config = Config::load().map_err(|err| io::Error::other(err.to_string()))?;
+
+    init_logger(config.log.level).map_err(|err| io::Error::other(err.to_string()))?;
+
+    let database = Database::connect(&config)
         .await
+        .map_err(|err| io::Error::other(err.to_string()))?;
+
+    let signup_controller = web::Data::new(
+        make_signup_controller(&database, &config)
+            .map_err(|err| io::Error::other(err.to_string()))?,
+    );
+    let login_controller = web::Data::new(
+        make_login_controller(&database, &config)
+            .map_err(|err| io::Error::other(err.to_string()))?,
+    );
+    let verify_email_controller = web::Data::new(make_verify_email_controller(&database));
+    let resend_email_verification_controller = web::Data::new(
+        make_resend_email_verification_controller(&database, &config)
+            .map_err(|err| io::Error::other(err.to_string()))?,
+    );
+
+    let forgot_password_controller = web::Data::new(
+        make_forgot_password_controller(&database, &config)
+            .map_err(|err| io::Error::other(err.to_string()))?,
+    );
+    let reset_password_controller = web::Data::new(
+        make_reset_password_controller(&database, &config)
+            .map_err(|err| io::Error::other(err.to_string()))?,
+    );
+
+    let auth_middleware = web::Data::new(
+        make_auth_middleware(&database, &config)
+            .map_err(|err| io::Error::other(err.to_string()))?,
+    );
+    let change_password_controller = web::Data::new(
+        make_change_password_controller(&database, &config)
+            .map_err(|err| io::Error::other(err.to_string()))?,
+    );
+
+    let server_config = config.server;
+
+    let mut server = HttpServer::new(move || {
+        App::new()
+            .app_data(signup_controller.clone())
+            .app_data(login_controller.clone())
+            .app_data(verify_email_controller.clone())
+            .app_data(resend_email_verification_controller.clone())
+            .app_data(forgot_password_controller.clone())
+            .app_data(reset_password_controller.clone())
+            .app_data(auth_middleware.clone())
+            .app_data(change_password_controller.clone())
+            .configure(setup_app)
+    });
+
+    if let Some(workers) = server_config.workers {
+        server = server.workers(workers);
+    }
+
+    if let Some(keep_alive_secs) = server_config.keep_alive_secs {
+        server = server.keep_alive(Duration::from_secs(keep_alive_secs));
+    }
+
+    if let Some(timeout_ms) = server_config.client_request_timeout_ms {
+        server = server.client_request_timeout(Duration::from_millis(timeout_ms));
+    }
+
+    if let Some(timeout_ms) = server_config.client_disconnect_timeout_ms {
+        server = server.client_disconnect_timeout(Duration::from_millis(timeout_ms));
+    }
+
+    let addr = (server_config.host.as_str(), server_config.port);
+
+    server = match &server_config.tls {
+        Some(tls) => {
+            let rustls_config =
+                load_rustls_config(tls).map_err(|err| io::Error::other(err.to_string()))?;
+            server.bind_rustls(addr, rustls_config)?
+        }
+        None => server.bind(addr)?,
+    };
+
+    let result = server.run().await;
+
+    database.disconnect().await;
+
+    result
 }
@@ -1,3 +1,24 @@
+pub mod change_password;
+pub mod forgot_password;
+pub mod login;
+pub mod resend_email_verification;
+pub mod reset_password;
 pub mod signup;
+pub mod verify_email;
 
-pub use signup::{SignUpController, SignUpReqBodyBuilder};
+pub use change_password::{
+    ChangePasswordController, ChangePasswordReqBody, ChangePasswordReqBodyBuilder,
+    ChangePasswordResBody,
+};
+pub use forgot_password::{ForgotPasswordController, ForgotPasswordReqBody, ForgotPasswordResBody};
+pub use login::{LoginController, LoginReqBody, LoginReqBodyBuilder, LoginResBody};
+pub use resend_email_verification::{
+    ResendEmailVerificationController, ResendEmailVerificationReqBody,
+    ResendEmailVerificationResBody,
+};
+pub use reset_password::{
+    ResetPasswordController, ResetPasswordReqBody, ResetPasswordReqBodyBuilder,
+    ResetPasswordResBody,
+};
+pub use signup::{SignUpController, SignUpReqBody, SignUpReqBodyBuilder, SignUpResBody};
+pub use verify_email::{VerifyEmailController, VerifyEmailReqBody, VerifyEmailResBody};
@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::usecases::{ChangePassword, ChangePasswordDto};
+use crate::presentation::helpers::{
+    bad_request, domain_error, http_error, server_error, validation_errors,
+};
+use crate::presentation::http::{HttpRequest, HttpResponse, ProblemDetails};
+use crate::presentation::protocols::{ControllerProtocol, Validation, ValidationInput};
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct ChangePasswordController {
+    validation: Box<dyn Validation>,
+    change_password: Box<dyn ChangePassword>,
+}
+
+impl ChangePasswordController {
+    pub fn new(validation: Box<dyn Validation>, change_password: Box<dyn ChangePassword>) -> Self {
+        Self {
+            validation,
+            change_password,
+        }
+    }
+
+    /// Set the change password controller's validation.
+    pub fn set_validation(&mut self, validation: Box<dyn Validation>) {
+        self.validation = validation;
+    }
+
+    /// Set the change password controller's change password.
+    pub fn set_change_password(&mut self, change_password: Box<dyn ChangePassword>) {
+        self.change_password = change_password;
+    }
+}
+
+#[async_trait]
+impl ControllerProtocol<ChangePasswordReqBody, ChangePasswordResBody> for ChangePasswordController {
+    async fn handle(
+        &self,
+        req: HttpRequest<ChangePasswordReqBody>,
+    ) -> HttpResponse<ChangePasswordResBody> {
+        let account_id = match req.account_id() {
+            Some(account_id) => account_id,
+            None => return http_error(401, "unauthorized"),
+        };
+
+        let body = req.body();
+
+        if body.is_none() {
+            return bad_request("missing body");
+        }
+
+        let body = body.unwrap();
+
+        let errors = match self.validation.validate(body) {
+            Ok(errors) => errors,
+            Err(_) => return server_error(),
+        };
+
+        if !errors.is_empty() {
+            return validation_errors(errors);
+        }
+
+        let result = self
+            .change_password
+            .change(ChangePasswordDto {
+                account_id: account_id.to_string(),
+                current_password: body.current_password().to_string(),
+                new_password: body.password().to_string(),
+            })
+            .await;
+
+        match result {
+            Ok(access_token) => {
+                HttpResponse::new(200, ChangePasswordResBody::AccessToken { access_token })
+            }
+            Err(err) => domain_error(&err),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ChangePasswordReqBody {
+    current_password: String,
+    password: String,
+    password_confirmation: String,
+}
+
+impl ChangePasswordReqBody {
+    /// Get a reference to the change password req body's current password.
+    pub fn current_password(&self) -> &str {
+        self.current_password.as_ref()
+    }
+
+    /// Get a reference to the change password req body's password.
+    pub fn password(&self) -> &str {
+        self.password.as_ref()
+    }
+
+    /// Get a reference to the change password req body's password confirmation.
+    pub fn password_confirmation(&self) -> &str {
+        self.password_confirmation.as_ref()
+    }
+}
+
+impl ValidationInput for ChangePasswordReqBody {
+    fn field(&self, name: &str) -> Option<&str> {
+        match name {
+            "current_password" => Some(self.current_password()),
+            "password" => Some(self.password()),
+            "password_confirmation" => Some(self.password_confirmation()),
+            _ => None,
+        }
+    }
+}
+
+pub struct ChangePasswordReqBodyBuilder {
+    current_password: String,
+    password: String,
+    password_confirmation: String,
+}
+
+impl ChangePasswordReqBodyBuilder {
+    pub fn new() -> Self {
+        Self {
+            current_password: String::new(),
+            password: String::new(),
+            password_confirmation: String::new(),
+        }
+    }
+
+    pub fn build(self) -> ChangePasswordReqBody {
+        let Self {
+            current_password,
+            password,
+            password_confirmation,
+        } = self;
+
+        ChangePasswordReqBody {
+            current_password,
+            password,
+            password_confirmation,
+        }
+    }
+
+    /// Set the change password req body builder's current password.
+    pub fn set_current_password(self, current_password: &str) -> Self {
+        let mut this = self;
+        this.current_password = String::from(current_password);
+        this
+    }
+
+    /// Set the change password req body builder's password.
+    pub fn set_password(self, password: &str) -> Self {
+        let mut this = self;
+        this.password = String::from(password);
+        this
+    }
+
+    /// Set the change password req body builder's password confirmation.
+    pub fn set_password_confirmation(self, password_confirmation: &str) -> Self {
+        let mut this = self;
+        this.password_confirmation = String::from(password_confirmation);
+        this
+    }
+}
+
+impl Default for ChangePasswordReqBodyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ChangePasswordResBody {
+    AccessToken { access_token: String },
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for ChangePasswordResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
+}
@@ -0,0 +1,243 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::domain::usecases::ChangePassword;
+#[double]
+use crate::presentation::protocols::Validation;
+
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::ChangePasswordDto;
+use crate::presentation::http::{FieldError, HttpRequest, ProblemDetails};
+use crate::presentation::protocols::ControllerProtocol;
+
+use super::{
+    ChangePasswordController, ChangePasswordReqBody, ChangePasswordReqBodyBuilder,
+    ChangePasswordResBody,
+};
+
+macro_rules! validation_validate_default {
+    () => {
+        |_| Ok(vec![])
+    };
+}
+
+macro_rules! change_password_change_default {
+    () => {
+        |_| Ok(String::from("any_token"))
+    };
+}
+
+fn make_sut() -> ChangePasswordController {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .returning(validation_validate_default!());
+
+    let mut change_password = make_change_password();
+    change_password
+        .expect_change()
+        .returning(change_password_change_default!());
+
+    ChangePasswordController::new(validation, change_password)
+}
+
+fn make_validation() -> Box<Validation> {
+    Box::new(Validation::default())
+}
+
+fn make_change_password() -> Box<ChangePassword> {
+    Box::new(ChangePassword::default())
+}
+
+fn make_req() -> HttpRequest<ChangePasswordReqBody> {
+    let body = ChangePasswordReqBodyBuilder::new()
+        .set_current_password("current_password")
+        .set_password("new_password")
+        .set_password_confirmation("new_password")
+        .build();
+
+    HttpRequest::new(Some(body)).with_account_id("any_id")
+}
+
+#[tokio::test]
+async fn returns_401_if_the_request_is_not_authenticated() {
+    let mut change_password = make_change_password();
+    change_password.expect_change().never();
+
+    let mut sut = make_sut();
+    sut.set_change_password(change_password);
+
+    let body = ChangePasswordReqBodyBuilder::new()
+        .set_current_password("current_password")
+        .set_password("new_password")
+        .set_password_confirmation("new_password")
+        .build();
+
+    let res = sut.handle(HttpRequest::new(Some(body))).await;
+
+    assert_eq!(res.status_code(), 401);
+    assert_eq!(
+        res.body(),
+        &ChangePasswordResBody::Err(ProblemDetails::new(401, "unauthorized"))
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_req_body_is_none() {
+    let sut = make_sut();
+    let req = HttpRequest::new(None).with_account_id("any_id");
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ChangePasswordResBody::Err(ProblemDetails::new(400, "missing body"))
+    );
+}
+
+#[tokio::test]
+async fn calls_validation_with_correct_body() {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .withf(|input| {
+            input.field("current_password") == Some("current_password")
+                && input.field("password") == Some("new_password")
+                && input.field("password_confirmation") == Some("new_password")
+        })
+        .once()
+        .returning(validation_validate_default!());
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
+
+    let _ = sut.handle(make_req()).await;
+}
+
+#[tokio::test]
+async fn returns_400_with_every_validation_error() {
+    let mut validation = make_validation();
+    validation.expect_validate().returning(|_| {
+        Ok(vec![
+            FieldError::new("current_password", "missing param"),
+            FieldError::new("password", "too short"),
+        ])
+    });
+
+    let mut change_password = make_change_password();
+    change_password.expect_change().never();
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
+    sut.set_change_password(change_password);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ChangePasswordResBody::Err(
+            ProblemDetails::new(400, "current_password: missing param; password: too short")
+                .with_errors(vec![
+                    FieldError::new("current_password", "missing param"),
+                    FieldError::new("password", "too short"),
+                ])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_validation_returns_err() {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+    assert_eq!(
+        res.body(),
+        &ChangePasswordResBody::Err(ProblemDetails::new(500, "internal server error"))
+    );
+}
+
+#[tokio::test]
+async fn calls_change_password_with_correct_values() {
+    let mut change_password = make_change_password();
+    change_password
+        .expect_change()
+        .with(predicate::eq(ChangePasswordDto {
+            account_id: String::from("any_id"),
+            current_password: String::from("current_password"),
+            new_password: String::from("new_password"),
+        }))
+        .once()
+        .returning(change_password_change_default!());
+
+    let mut sut = make_sut();
+    sut.set_change_password(change_password);
+
+    let _ = sut.handle(make_req()).await;
+}
+
+#[tokio::test]
+async fn returns_400_if_the_current_password_is_incorrect() {
+    let mut change_password = make_change_password();
+    change_password
+        .expect_change()
+        .returning(|_| Err(DomainError::IncorrectPassword));
+
+    let mut sut = make_sut();
+    sut.set_change_password(change_password);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ChangePasswordResBody::Err(
+            ProblemDetails::new(400, "current_password: incorrect password").with_errors(vec![
+                FieldError::new("current_password", "incorrect password")
+            ])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_change_password_returns_err() {
+    let mut change_password = make_change_password();
+    change_password
+        .expect_change()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_change_password(change_password);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+    assert_eq!(
+        res.body(),
+        &ChangePasswordResBody::Err(ProblemDetails::new(500, "internal server error"))
+    );
+}
+
+#[tokio::test]
+async fn returns_200_with_a_new_access_token_on_success() {
+    let sut = make_sut();
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 200);
+    assert_eq!(
+        res.body(),
+        &ChangePasswordResBody::AccessToken {
+            access_token: String::from("any_token")
+        }
+    );
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::usecases::RequestPasswordReset;
+use crate::presentation::helpers::{bad_request, invalid_param, missing_param, server_error};
+use crate::presentation::http::{HttpRequest, HttpResponse, ProblemDetails};
+use crate::presentation::protocols::{ControllerProtocol, EmailNormalizer, EmailValidator};
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct ForgotPasswordController {
+    email_normalizer: Box<dyn EmailNormalizer>,
+    email_validator: Box<dyn EmailValidator>,
+    request_password_reset: Arc<dyn RequestPasswordReset>,
+}
+
+impl ForgotPasswordController {
+    pub fn new(
+        email_normalizer: Box<dyn EmailNormalizer>,
+        email_validator: Box<dyn EmailValidator>,
+        request_password_reset: Box<dyn RequestPasswordReset>,
+    ) -> Self {
+        Self {
+            email_normalizer,
+            email_validator,
+            request_password_reset: Arc::from(request_password_reset),
+        }
+    }
+
+    /// Set the forgot password controller's email normalizer.
+    pub fn set_email_normalizer(&mut self, email_normalizer: Box<dyn EmailNormalizer>) {
+        self.email_normalizer = email_normalizer;
+    }
+
+    /// Set the forgot password controller's email validator.
+    pub fn set_email_validator(&mut self, email_validator: Box<dyn EmailValidator>) {
+        self.email_validator = email_validator;
+    }
+
+    /// Set the forgot password controller's request password reset.
+    pub fn set_request_password_reset(
+        &mut self,
+        request_password_reset: Box<dyn RequestPasswordReset>,
+    ) {
+        self.request_password_reset = Arc::from(request_password_reset);
+    }
+}
+
+#[async_trait]
+impl ControllerProtocol<ForgotPasswordReqBody, ForgotPasswordResBody> for ForgotPasswordController {
+    /// Answers the same whether or not the email is registered, so the
+    /// endpoint can't be used to find registered emails. The reset is
+    /// requested in the background, its failures are logged, as neither its
+    /// outcome nor its duration may tell registered emails apart.
+    async fn handle(
+        &self,
+        req: HttpRequest<ForgotPasswordReqBody>,
+    ) -> HttpResponse<ForgotPasswordResBody> {
+        let body = req.body();
+
+        if body.is_none() {
+            return bad_request("missing body");
+        }
+
+        let email = &self.email_normalizer.normalize(body.unwrap().email());
+
+        if email.is_empty() {
+            return missing_param("email");
+        }
+
+        match self.email_validator.is_valid(email) {
+            Ok(is_valid) => {
+                if !is_valid {
+                    return invalid_param("email");
+                }
+            }
+            Err(_) => return server_error(),
+        }
+
+        let request_password_reset = Arc::clone(&self.request_password_reset);
+        let email = email.to_string();
+
+        tokio::spawn(async move {
+            if let Err(err) = request_password_reset.request(&email).await {
+                log::error!("failed to request a password reset: {:?}", err);
+            }
+        });
+
+        HttpResponse::new(
+            202,
+            ForgotPasswordResBody::Message {
+                message: String::from("password reset email sent if the account exists"),
+            },
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ForgotPasswordReqBody {
+    email: String,
+}
+
+impl ForgotPasswordReqBody {
+    pub fn new(email: &str) -> Self {
+        Self {
+            email: String::from(email),
+        }
+    }
+
+    /// Get a reference to the forgot password req body's email.
+    pub fn email(&self) -> &str {
+        self.email.as_ref()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ForgotPasswordResBody {
+    Message { message: String },
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for ForgotPasswordResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
+}
@@ -0,0 +1,194 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::domain::usecases::RequestPasswordReset;
+#[double]
+use crate::presentation::protocols::EmailNormalizer;
+#[double]
+use crate::presentation::protocols::EmailValidator;
+
+use crate::domain::errors::DomainError;
+use crate::presentation::http::{FieldError, HttpRequest, ProblemDetails};
+use crate::presentation::protocols::ControllerProtocol;
+
+use super::{ForgotPasswordController, ForgotPasswordReqBody, ForgotPasswordResBody};
+
+macro_rules! email_normalizer_normalize_default {
+    () => {
+        |email| email.to_string()
+    };
+}
+
+macro_rules! email_validator_is_valid_default {
+    () => {
+        |_| Ok(true)
+    };
+}
+
+macro_rules! request_password_reset_request_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+fn make_sut() -> ForgotPasswordController {
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .returning(email_normalizer_normalize_default!());
+
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .returning(email_validator_is_valid_default!());
+
+    let mut request_password_reset = make_request_password_reset();
+    request_password_reset
+        .expect_request()
+        .returning(request_password_reset_request_default!());
+
+    ForgotPasswordController::new(email_normalizer, email_validator, request_password_reset)
+}
+
+fn make_email_normalizer() -> Box<EmailNormalizer> {
+    Box::new(EmailNormalizer::default())
+}
+
+fn make_email_validator() -> Box<EmailValidator> {
+    Box::new(EmailValidator::default())
+}
+
+fn make_request_password_reset() -> Box<RequestPasswordReset> {
+    Box::new(RequestPasswordReset::default())
+}
+
+fn make_req() -> HttpRequest<ForgotPasswordReqBody> {
+    HttpRequest::new(Some(ForgotPasswordReqBody::new("any_email@mail.com")))
+}
+
+#[tokio::test]
+async fn returns_400_if_req_body_is_none() {
+    let sut = make_sut();
+    let req = HttpRequest::new(None);
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ForgotPasswordResBody::Err(ProblemDetails::new(400, "missing body"))
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_no_email_is_provided() {
+    let sut = make_sut();
+
+    let req = HttpRequest::new(Some(ForgotPasswordReqBody::new("")));
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ForgotPasswordResBody::Err(
+            ProblemDetails::new(400, "email: missing param")
+                .with_errors(vec![FieldError::new("email", "missing param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_invalid_email_is_provided() {
+    let mut email_validator = make_email_validator();
+    email_validator.expect_is_valid().returning(|_| Ok(false));
+
+    let mut request_password_reset = make_request_password_reset();
+    request_password_reset.expect_request().never();
+
+    let mut sut = make_sut();
+    sut.set_email_validator(email_validator);
+    sut.set_request_password_reset(request_password_reset);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ForgotPasswordResBody::Err(
+            ProblemDetails::new(400, "email: invalid param")
+                .with_errors(vec![FieldError::new("email", "invalid param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_email_validator_returns_err() {
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut();
+    sut.set_email_validator(email_validator);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+}
+
+#[tokio::test]
+async fn requests_the_reset_for_the_normalized_email() {
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .with(predicate::eq("any_email@mail.com"))
+        .returning(|_| String::from("normalized_email@mail.com"));
+
+    let mut request_password_reset = make_request_password_reset();
+    request_password_reset
+        .expect_request()
+        .once()
+        .with(predicate::eq("normalized_email@mail.com"))
+        .returning(request_password_reset_request_default!());
+
+    let mut sut = make_sut();
+    sut.set_email_normalizer(email_normalizer);
+    sut.set_request_password_reset(request_password_reset);
+
+    let _ = sut.handle(make_req()).await;
+
+    // Lets the spawned request run before the mock checks its expectations.
+    tokio::task::yield_now().await;
+}
+
+#[tokio::test]
+async fn returns_202_even_if_request_password_reset_returns_err() {
+    let mut request_password_reset = make_request_password_reset();
+    request_password_reset
+        .expect_request()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_request_password_reset(request_password_reset);
+
+    let res = sut.handle(make_req()).await;
+
+    tokio::task::yield_now().await;
+
+    assert_eq!(res.status_code(), 202);
+}
+
+#[tokio::test]
+async fn returns_202_on_success() {
+    let sut = make_sut();
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 202);
+    assert_eq!(
+        res.body(),
+        &ForgotPasswordResBody::Message {
+            message: String::from("password reset email sent if the account exists")
+        }
+    );
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::usecases::{Authentication, AuthenticationDto};
+use crate::presentation::helpers::{
+    bad_request, domain_error, invalid_param, missing_param, server_error,
+};
+use crate::presentation::http::{HttpRequest, HttpResponse, ProblemDetails};
+use crate::presentation::protocols::{ControllerProtocol, EmailNormalizer, EmailValidator};
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct LoginController {
+    email_normalizer: Box<dyn EmailNormalizer>,
+    email_validator: Box<dyn EmailValidator>,
+    authentication: Box<dyn Authentication>,
+}
+
+impl LoginController {
+    pub fn new(
+        email_normalizer: Box<dyn EmailNormalizer>,
+        email_validator: Box<dyn EmailValidator>,
+        authentication: Box<dyn Authentication>,
+    ) -> Self {
+        Self {
+            email_normalizer,
+            email_validator,
+            authentication,
+        }
+    }
+
+    /// Set the login controller's email normalizer.
+    pub fn set_email_normalizer(&mut self, email_normalizer: Box<dyn EmailNormalizer>) {
+        self.email_normalizer = email_normalizer;
+    }
+
+    /// Set the login controller's email validator.
+    pub fn set_email_validator(&mut self, email_validator: Box<dyn EmailValidator>) {
+        self.email_validator = email_validator;
+    }
+
+    /// Set the login controller's authentication.
+    pub fn set_authentication(&mut self, authentication: Box<dyn Authentication>) {
+        self.authentication = authentication;
+    }
+}
+
+#[async_trait]
+impl ControllerProtocol<LoginReqBody, LoginResBody> for LoginController {
+    async fn handle(&self, req: HttpRequest<LoginReqBody>) -> HttpResponse<LoginResBody> {
+        let body = req.body();
+
+        if body.is_none() {
+            return bad_request("missing body");
+        }
+
+        let body = body.unwrap();
+        let email = &self.email_normalizer.normalize(body.email());
+        let password = body.password();
+
+        if email.is_empty() {
+            return missing_param("email");
+        }
+
+        if password.is_empty() {
+            return missing_param("password");
+        }
+
+        match self.email_validator.is_valid(email) {
+            Ok(is_valid) => {
+                if !is_valid {
+                    return invalid_param("email");
+                }
+            }
+            Err(_) => return server_error(),
+        }
+
+        let result = self
+            .authentication
+            .auth(AuthenticationDto {
+                email: email.to_string(),
+                password: password.to_string(),
+            })
+            .await;
+
+        let access_token = match result {
+            Ok(access_token) => access_token,
+            Err(err) => return domain_error(&err),
+        };
+
+        HttpResponse::new(200, LoginResBody::AccessToken { access_token })
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct LoginReqBody {
+    email: String,
+    password: String,
+}
+
+impl LoginReqBody {
+    /// Get a reference to the login req body's email.
+    pub fn email(&self) -> &str {
+        self.email.as_ref()
+    }
+
+    /// Get a reference to the login req body's password.
+    pub fn password(&self) -> &str {
+        self.password.as_ref()
+    }
+}
+
+pub struct LoginReqBodyBuilder {
+    email: String,
+    password: String,
+}
+
+impl LoginReqBodyBuilder {
+    pub fn new() -> Self {
+        Self {
+            email: String::new(),
+            password: String::new(),
+        }
+    }
+
+    pub fn build(self) -> LoginReqBody {
+        let Self { email, password } = self;
+
+        LoginReqBody { email, password }
+    }
+
+    /// Set the login req body builder's email.
+    pub fn set_email(self, email: &str) -> Self {
+        let mut this = self;
+        this.email = String::from(email);
+        this
+    }
+
+    /// Set the login req body builder's password.
+    pub fn set_password(self, password: &str) -> Self {
+        let mut this = self;
+        this.password = String::from(password);
+        this
+    }
+}
+
+impl Default for LoginReqBodyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum LoginResBody {
+    AccessToken { access_token: String },
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for LoginResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
+}
@@ -0,0 +1,306 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::domain::usecases::Authentication;
+#[double]
+use crate::presentation::protocols::EmailNormalizer;
+#[double]
+use crate::presentation::protocols::EmailValidator;
+
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::AuthenticationDto;
+use crate::presentation::http::{FieldError, HttpRequest, ProblemDetails};
+use crate::presentation::protocols::ControllerProtocol;
+
+use super::{LoginController, LoginReqBody, LoginReqBodyBuilder, LoginResBody};
+
+macro_rules! email_validator_is_valid_default {
+    () => {
+        |_| Ok(true)
+    };
+}
+
+macro_rules! authentication_auth_default {
+    () => {
+        |_| Ok(String::from("any_token"))
+    };
+}
+
+macro_rules! email_normalizer_normalize_default {
+    () => {
+        |email| email.to_string()
+    };
+}
+
+fn make_sut() -> LoginController {
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .returning(email_normalizer_normalize_default!());
+
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .returning(email_validator_is_valid_default!());
+
+    let mut authentication = make_authentication();
+    authentication
+        .expect_auth()
+        .returning(authentication_auth_default!());
+
+    LoginController::new(email_normalizer, email_validator, authentication)
+}
+
+fn make_email_normalizer() -> Box<EmailNormalizer> {
+    Box::new(EmailNormalizer::default())
+}
+
+fn make_email_validator() -> Box<EmailValidator> {
+    Box::new(EmailValidator::default())
+}
+
+fn make_authentication() -> Box<Authentication> {
+    Box::new(Authentication::default())
+}
+
+fn make_req() -> HttpRequest<LoginReqBody> {
+    let body = LoginReqBodyBuilder::new()
+        .set_email("any_email@mail.com")
+        .set_password("any_password")
+        .build();
+
+    HttpRequest::new(Some(body))
+}
+
+#[tokio::test]
+async fn returns_400_if_req_body_is_none() {
+    let sut = make_sut();
+    let req = HttpRequest::new(None);
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(ProblemDetails::new(400, "missing body"))
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_no_email_is_provided() {
+    let sut = make_sut();
+
+    let body = LoginReqBodyBuilder::new()
+        .set_password("any_password")
+        .build();
+
+    let req = HttpRequest::new(Some(body));
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(
+            ProblemDetails::new(400, "email: missing param")
+                .with_errors(vec![FieldError::new("email", "missing param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_no_password_is_provided() {
+    let sut = make_sut();
+
+    let body = LoginReqBodyBuilder::new()
+        .set_email("any_email@mail.com")
+        .build();
+
+    let req = HttpRequest::new(Some(body));
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(
+            ProblemDetails::new(400, "password: missing param")
+                .with_errors(vec![FieldError::new("password", "missing param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn calls_email_validator_with_correct_email() {
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .once()
+        .with(predicate::eq("any_email@mail.com"))
+        .returning(email_validator_is_valid_default!());
+
+    let mut sut = make_sut();
+    sut.set_email_validator(email_validator);
+
+    sut.handle(make_req()).await;
+}
+
+#[tokio::test]
+async fn returns_400_if_invalid_email_is_provided() {
+    let mut email_validator = make_email_validator();
+    email_validator.expect_is_valid().returning(|_| Ok(false));
+
+    let mut sut = make_sut();
+    sut.set_email_validator(email_validator);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(
+            ProblemDetails::new(400, "email: invalid param")
+                .with_errors(vec![FieldError::new("email", "invalid param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_email_validator_returns_err() {
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut();
+    sut.set_email_validator(email_validator);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(ProblemDetails::new(500, "internal server error"))
+    );
+}
+
+#[tokio::test]
+async fn uses_the_normalized_email() {
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .with(predicate::eq("any_email@mail.com"))
+        .returning(|_| String::from("normalized_email@mail.com"));
+
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .with(predicate::eq("normalized_email@mail.com"))
+        .returning(email_validator_is_valid_default!());
+
+    let mut authentication = make_authentication();
+    authentication
+        .expect_auth()
+        .with(predicate::eq(AuthenticationDto {
+            email: String::from("normalized_email@mail.com"),
+            password: String::from("any_password"),
+        }))
+        .returning(authentication_auth_default!());
+
+    let mut sut = make_sut();
+    sut.set_email_normalizer(email_normalizer);
+    sut.set_email_validator(email_validator);
+    sut.set_authentication(authentication);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 200);
+}
+
+#[tokio::test]
+async fn calls_authentication_with_correct_values() {
+    let mut authentication = make_authentication();
+    authentication
+        .expect_auth()
+        .once()
+        .with(predicate::eq(AuthenticationDto {
+            email: String::from("any_email@mail.com"),
+            password: String::from("any_password"),
+        }))
+        .returning(authentication_auth_default!());
+
+    let mut sut = make_sut();
+    sut.set_authentication(authentication);
+
+    sut.handle(make_req()).await;
+}
+
+#[tokio::test]
+async fn returns_401_if_invalid_credentials_are_provided() {
+    let mut authentication = make_authentication();
+    authentication
+        .expect_auth()
+        .returning(|_| Err(DomainError::InvalidCredentials));
+
+    let mut sut = make_sut();
+    sut.set_authentication(authentication);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 401);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(ProblemDetails::new(401, "unauthorized"))
+    );
+}
+
+#[tokio::test]
+async fn returns_403_if_authentication_returns_email_not_verified() {
+    let mut authentication = make_authentication();
+    authentication
+        .expect_auth()
+        .returning(|_| Err(DomainError::EmailNotVerified));
+
+    let mut sut = make_sut();
+    sut.set_authentication(authentication);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 403);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(ProblemDetails::new(403, "email not verified"))
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_authentication_returns_err() {
+    let mut authentication = make_authentication();
+    authentication
+        .expect_auth()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_authentication(authentication);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::Err(ProblemDetails::new(500, "internal server error"))
+    );
+}
+
+#[tokio::test]
+async fn returns_200_if_valid_credentials_are_provided() {
+    let sut = make_sut();
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 200);
+    assert_eq!(
+        res.body(),
+        &LoginResBody::AccessToken {
+            access_token: String::from("any_token")
+        }
+    );
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::usecases::SendEmailVerification;
+use crate::presentation::helpers::{bad_request, invalid_param, missing_param, server_error};
+use crate::presentation::http::{HttpRequest, HttpResponse, ProblemDetails};
+use crate::presentation::protocols::{ControllerProtocol, EmailNormalizer, EmailValidator};
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct ResendEmailVerificationController {
+    email_normalizer: Box<dyn EmailNormalizer>,
+    email_validator: Box<dyn EmailValidator>,
+    send_email_verification: Arc<dyn SendEmailVerification>,
+}
+
+impl ResendEmailVerificationController {
+    pub fn new(
+        email_normalizer: Box<dyn EmailNormalizer>,
+        email_validator: Box<dyn EmailValidator>,
+        send_email_verification: Box<dyn SendEmailVerification>,
+    ) -> Self {
+        Self {
+            email_normalizer,
+            email_validator,
+            send_email_verification: Arc::from(send_email_verification),
+        }
+    }
+
+    /// Set the resend email verification controller's email normalizer.
+    pub fn set_email_normalizer(&mut self, email_normalizer: Box<dyn EmailNormalizer>) {
+        self.email_normalizer = email_normalizer;
+    }
+
+    /// Set the resend email verification controller's email validator.
+    pub fn set_email_validator(&mut self, email_validator: Box<dyn EmailValidator>) {
+        self.email_validator = email_validator;
+    }
+
+    /// Set the resend email verification controller's send email verification.
+    pub fn set_send_email_verification(
+        &mut self,
+        send_email_verification: Box<dyn SendEmailVerification>,
+    ) {
+        self.send_email_verification = Arc::from(send_email_verification);
+    }
+}
+
+#[async_trait]
+impl ControllerProtocol<ResendEmailVerificationReqBody, ResendEmailVerificationResBody>
+    for ResendEmailVerificationController
+{
+    /// Answers the same whether or not the email belongs to an unverified
+    /// account, so the endpoint can't be used to find registered emails. The
+    /// mail is sent in the background, its failures are logged, as neither
+    /// its outcome nor its duration may tell registered emails apart.
+    async fn handle(
+        &self,
+        req: HttpRequest<ResendEmailVerificationReqBody>,
+    ) -> HttpResponse<ResendEmailVerificationResBody> {
+        let body = req.body();
+
+        if body.is_none() {
+            return bad_request("missing body");
+        }
+
+        let email = &self.email_normalizer.normalize(body.unwrap().email());
+
+        if email.is_empty() {
+            return missing_param("email");
+        }
+
+        match self.email_validator.is_valid(email) {
+            Ok(is_valid) => {
+                if !is_valid {
+                    return invalid_param("email");
+                }
+            }
+            Err(_) => return server_error(),
+        }
+
+        let send_email_verification = Arc::clone(&self.send_email_verification);
+        let email = email.to_string();
+
+        tokio::spawn(async move {
+            if let Err(err) = send_email_verification.send(&email).await {
+                log::error!("failed to resend an email verification: {:?}", err);
+            }
+        });
+
+        HttpResponse::new(
+            202,
+            ResendEmailVerificationResBody::Message {
+                message: String::from("verification email sent if the account is unverified"),
+            },
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ResendEmailVerificationReqBody {
+    email: String,
+}
+
+impl ResendEmailVerificationReqBody {
+    pub fn new(email: &str) -> Self {
+        Self {
+            email: String::from(email),
+        }
+    }
+
+    /// Get a reference to the resend email verification req body's email.
+    pub fn email(&self) -> &str {
+        self.email.as_ref()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ResendEmailVerificationResBody {
+    Message { message: String },
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for ResendEmailVerificationResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
+}
@@ -0,0 +1,203 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::domain::usecases::SendEmailVerification;
+#[double]
+use crate::presentation::protocols::EmailNormalizer;
+#[double]
+use crate::presentation::protocols::EmailValidator;
+
+use crate::domain::errors::DomainError;
+use crate::presentation::http::{FieldError, HttpRequest, ProblemDetails};
+use crate::presentation::protocols::ControllerProtocol;
+
+use super::{
+    ResendEmailVerificationController, ResendEmailVerificationReqBody,
+    ResendEmailVerificationResBody,
+};
+
+macro_rules! email_normalizer_normalize_default {
+    () => {
+        |email| email.to_string()
+    };
+}
+
+macro_rules! email_validator_is_valid_default {
+    () => {
+        |_| Ok(true)
+    };
+}
+
+macro_rules! send_email_verification_send_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+fn make_sut() -> ResendEmailVerificationController {
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .returning(email_normalizer_normalize_default!());
+
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .returning(email_validator_is_valid_default!());
+
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification
+        .expect_send()
+        .returning(send_email_verification_send_default!());
+
+    ResendEmailVerificationController::new(
+        email_normalizer,
+        email_validator,
+        send_email_verification,
+    )
+}
+
+fn make_email_normalizer() -> Box<EmailNormalizer> {
+    Box::new(EmailNormalizer::default())
+}
+
+fn make_email_validator() -> Box<EmailValidator> {
+    Box::new(EmailValidator::default())
+}
+
+fn make_send_email_verification() -> Box<SendEmailVerification> {
+    Box::new(SendEmailVerification::default())
+}
+
+fn make_req() -> HttpRequest<ResendEmailVerificationReqBody> {
+    HttpRequest::new(Some(ResendEmailVerificationReqBody::new(
+        "any_email@mail.com",
+    )))
+}
+
+#[tokio::test]
+async fn returns_400_if_req_body_is_none() {
+    let sut = make_sut();
+    let req = HttpRequest::new(None);
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ResendEmailVerificationResBody::Err(ProblemDetails::new(400, "missing body"))
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_no_email_is_provided() {
+    let sut = make_sut();
+
+    let req = HttpRequest::new(Some(ResendEmailVerificationReqBody::new("")));
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ResendEmailVerificationResBody::Err(
+            ProblemDetails::new(400, "email: missing param")
+                .with_errors(vec![FieldError::new("email", "missing param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_invalid_email_is_provided() {
+    let mut email_validator = make_email_validator();
+    email_validator.expect_is_valid().returning(|_| Ok(false));
+
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification.expect_send().never();
+
+    let mut sut = make_sut();
+    sut.set_email_validator(email_validator);
+    sut.set_send_email_verification(send_email_verification);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ResendEmailVerificationResBody::Err(
+            ProblemDetails::new(400, "email: invalid param")
+                .with_errors(vec![FieldError::new("email", "invalid param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_email_validator_returns_err() {
+    let mut email_validator = make_email_validator();
+    email_validator
+        .expect_is_valid()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut();
+    sut.set_email_validator(email_validator);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+}
+
+#[tokio::test]
+async fn sends_the_email_verification_to_the_normalized_email() {
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .with(predicate::eq("any_email@mail.com"))
+        .returning(|_| String::from("normalized_email@mail.com"));
+
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification
+        .expect_send()
+        .once()
+        .with(predicate::eq("normalized_email@mail.com"))
+        .returning(send_email_verification_send_default!());
+
+    let mut sut = make_sut();
+    sut.set_email_normalizer(email_normalizer);
+    sut.set_send_email_verification(send_email_verification);
+
+    let _ = sut.handle(make_req()).await;
+
+    // Lets the spawned send run before the mock checks its expectations.
+    tokio::task::yield_now().await;
+}
+
+#[tokio::test]
+async fn returns_202_even_if_send_email_verification_returns_err() {
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification
+        .expect_send()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_send_email_verification(send_email_verification);
+
+    let res = sut.handle(make_req()).await;
+
+    tokio::task::yield_now().await;
+
+    assert_eq!(res.status_code(), 202);
+}
+
+#[tokio::test]
+async fn returns_202_on_success() {
+    let sut = make_sut();
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 202);
+    assert_eq!(
+        res.body(),
+        &ResendEmailVerificationResBody::Message {
+            message: String::from("verification email sent if the account is unverified")
+        }
+    );
+}
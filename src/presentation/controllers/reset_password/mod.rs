@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::usecases::{ResetPassword, ResetPasswordDto};
+use crate::presentation::helpers::{bad_request, domain_error, server_error, validation_errors};
+use crate::presentation::http::{HttpRequest, HttpResponse, ProblemDetails};
+use crate::presentation::protocols::{ControllerProtocol, Validation, ValidationInput};
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct ResetPasswordController {
+    validation: Box<dyn Validation>,
+    reset_password: Box<dyn ResetPassword>,
+}
+
+impl ResetPasswordController {
+    pub fn new(validation: Box<dyn Validation>, reset_password: Box<dyn ResetPassword>) -> Self {
+        Self {
+            validation,
+            reset_password,
+        }
+    }
+
+    /// Set the reset password controller's validation.
+    pub fn set_validation(&mut self, validation: Box<dyn Validation>) {
+        self.validation = validation;
+    }
+
+    /// Set the reset password controller's reset password.
+    pub fn set_reset_password(&mut self, reset_password: Box<dyn ResetPassword>) {
+        self.reset_password = reset_password;
+    }
+}
+
+#[async_trait]
+impl ControllerProtocol<ResetPasswordReqBody, ResetPasswordResBody> for ResetPasswordController {
+    async fn handle(
+        &self,
+        req: HttpRequest<ResetPasswordReqBody>,
+    ) -> HttpResponse<ResetPasswordResBody> {
+        let body = req.body();
+
+        if body.is_none() {
+            return bad_request("missing body");
+        }
+
+        let body = body.unwrap();
+
+        let errors = match self.validation.validate(body) {
+            Ok(errors) => errors,
+            Err(_) => return server_error(),
+        };
+
+        if !errors.is_empty() {
+            return validation_errors(errors);
+        }
+
+        let result = self
+            .reset_password
+            .reset(ResetPasswordDto {
+                token: body.token().to_string(),
+                password: body.password().to_string(),
+            })
+            .await;
+
+        if let Err(err) = result {
+            return domain_error(&err);
+        }
+
+        HttpResponse::new(
+            200,
+            ResetPasswordResBody::Message {
+                message: String::from("password reset"),
+            },
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ResetPasswordReqBody {
+    token: String,
+    password: String,
+    password_confirmation: String,
+}
+
+impl ResetPasswordReqBody {
+    /// Get a reference to the reset password req body's token.
+    pub fn token(&self) -> &str {
+        self.token.as_ref()
+    }
+
+    /// Get a reference to the reset password req body's password.
+    pub fn password(&self) -> &str {
+        self.password.as_ref()
+    }
+
+    /// Get a reference to the reset password req body's password confirmation.
+    pub fn password_confirmation(&self) -> &str {
+        self.password_confirmation.as_ref()
+    }
+}
+
+impl ValidationInput for ResetPasswordReqBody {
+    fn field(&self, name: &str) -> Option<&str> {
+        match name {
+            "token" => Some(self.token()),
+            "password" => Some(self.password()),
+            "password_confirmation" => Some(self.password_confirmation()),
+            _ => None,
+        }
+    }
+}
+
+pub struct ResetPasswordReqBodyBuilder {
+    token: String,
+    password: String,
+    password_confirmation: String,
+}
+
+impl ResetPasswordReqBodyBuilder {
+    pub fn new() -> Self {
+        Self {
+            token: String::new(),
+            password: String::new(),
+            password_confirmation: String::new(),
+        }
+    }
+
+    pub fn build(self) -> ResetPasswordReqBody {
+        let Self {
+            token,
+            password,
+            password_confirmation,
+        } = self;
+
+        ResetPasswordReqBody {
+            token,
+            password,
+            password_confirmation,
+        }
+    }
+
+    /// Set the reset password req body builder's token.
+    pub fn set_token(self, token: &str) -> Self {
+        let mut this = self;
+        this.token = String::from(token);
+        this
+    }
+
+    /// Set the reset password req body builder's password.
+    pub fn set_password(self, password: &str) -> Self {
+        let mut this = self;
+        this.password = String::from(password);
+        this
+    }
+
+    /// Set the reset password req body builder's password confirmation.
+    pub fn set_password_confirmation(self, password_confirmation: &str) -> Self {
+        let mut this = self;
+        this.password_confirmation = String::from(password_confirmation);
+        this
+    }
+}
+
+impl Default for ResetPasswordReqBodyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ResetPasswordResBody {
+    Message { message: String },
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for ResetPasswordResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
+}
@@ -0,0 +1,212 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::domain::usecases::ResetPassword;
+#[double]
+use crate::presentation::protocols::Validation;
+
+use crate::domain::errors::DomainError;
+use crate::domain::usecases::ResetPasswordDto;
+use crate::presentation::http::{FieldError, HttpRequest, ProblemDetails};
+use crate::presentation::protocols::ControllerProtocol;
+
+use super::{
+    ResetPasswordController, ResetPasswordReqBody, ResetPasswordReqBodyBuilder,
+    ResetPasswordResBody,
+};
+
+macro_rules! validation_validate_default {
+    () => {
+        |_| Ok(vec![])
+    };
+}
+
+macro_rules! reset_password_reset_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+fn make_sut() -> ResetPasswordController {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .returning(validation_validate_default!());
+
+    let mut reset_password = make_reset_password();
+    reset_password
+        .expect_reset()
+        .returning(reset_password_reset_default!());
+
+    ResetPasswordController::new(validation, reset_password)
+}
+
+fn make_validation() -> Box<Validation> {
+    Box::new(Validation::default())
+}
+
+fn make_reset_password() -> Box<ResetPassword> {
+    Box::new(ResetPassword::default())
+}
+
+fn make_req() -> HttpRequest<ResetPasswordReqBody> {
+    let body = ResetPasswordReqBodyBuilder::new()
+        .set_token("any_token")
+        .set_password("new_password")
+        .set_password_confirmation("new_password")
+        .build();
+
+    HttpRequest::new(Some(body))
+}
+
+#[tokio::test]
+async fn returns_400_if_req_body_is_none() {
+    let sut = make_sut();
+    let req = HttpRequest::new(None);
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ResetPasswordResBody::Err(ProblemDetails::new(400, "missing body"))
+    );
+}
+
+#[tokio::test]
+async fn calls_validation_with_correct_body() {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .withf(|input| {
+            input.field("token") == Some("any_token")
+                && input.field("password") == Some("new_password")
+                && input.field("password_confirmation") == Some("new_password")
+        })
+        .once()
+        .returning(validation_validate_default!());
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
+
+    let _ = sut.handle(make_req()).await;
+}
+
+#[tokio::test]
+async fn returns_400_with_every_validation_error() {
+    let mut validation = make_validation();
+    validation.expect_validate().returning(|_| {
+        Ok(vec![
+            FieldError::new("token", "missing param"),
+            FieldError::new("password", "too short"),
+        ])
+    });
+
+    let mut reset_password = make_reset_password();
+    reset_password.expect_reset().never();
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
+    sut.set_reset_password(reset_password);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ResetPasswordResBody::Err(
+            ProblemDetails::new(400, "token: missing param; password: too short").with_errors(
+                vec![
+                    FieldError::new("token", "missing param"),
+                    FieldError::new("password", "too short"),
+                ]
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_validation_returns_err() {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+}
+
+#[tokio::test]
+async fn calls_reset_password_with_correct_values() {
+    let mut reset_password = make_reset_password();
+    reset_password
+        .expect_reset()
+        .with(predicate::eq(ResetPasswordDto {
+            token: String::from("any_token"),
+            password: String::from("new_password"),
+        }))
+        .once()
+        .returning(reset_password_reset_default!());
+
+    let mut sut = make_sut();
+    sut.set_reset_password(reset_password);
+
+    let _ = sut.handle(make_req()).await;
+}
+
+#[tokio::test]
+async fn returns_400_if_reset_password_returns_invalid_token_error() {
+    let mut reset_password = make_reset_password();
+    reset_password
+        .expect_reset()
+        .returning(|_| Err(DomainError::InvalidToken));
+
+    let mut sut = make_sut();
+    sut.set_reset_password(reset_password);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &ResetPasswordResBody::Err(ProblemDetails::new(400, "invalid or expired token"))
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_reset_password_returns_err() {
+    let mut reset_password = make_reset_password();
+    reset_password
+        .expect_reset()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_reset_password(reset_password);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+    assert_eq!(
+        res.body(),
+        &ResetPasswordResBody::Err(ProblemDetails::new(500, "internal server error"))
+    );
+}
+
+#[tokio::test]
+async fn returns_200_on_success() {
+    let sut = make_sut();
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 200);
+    assert_eq!(
+        res.body(),
+        &ResetPasswordResBody::Message {
+            message: String::from("password reset")
+        }
+    );
+}
@@ -1,37 +1,63 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::AccountEntity;
-use crate::domain::usecases::{AddAccount, AddAccountDto};
-use crate::presentation::http::{HttpRequest, HttpResponse};
-use crate::presentation::protocols::{ControllerProtocol, EmailValidator};
-use crate::ErrorMsg;
+use crate::domain::usecases::{AddAccount, AddAccountDto, SendEmailVerification};
+use crate::presentation::helpers::{bad_request, domain_error, server_error, validation_errors};
+use crate::presentation::http::{HttpRequest, HttpResponse, ProblemDetails};
+use crate::presentation::protocols::{
+    ControllerProtocol, EmailNormalizer, Validation, ValidationInput,
+};
+use crate::presentation::views::AccountView;
 
 #[cfg(test)]
 pub mod tests;
 
 pub struct SignUpController {
-    email_validator: Box<dyn EmailValidator>,
+    email_normalizer: Box<dyn EmailNormalizer>,
+    validation: Box<dyn Validation>,
     add_account: Box<dyn AddAccount>,
+    send_email_verification: Arc<dyn SendEmailVerification>,
 }
 
 impl SignUpController {
-    pub fn new(email_validator: Box<dyn EmailValidator>, add_account: Box<dyn AddAccount>) -> Self {
+    pub fn new(
+        email_normalizer: Box<dyn EmailNormalizer>,
+        validation: Box<dyn Validation>,
+        add_account: Box<dyn AddAccount>,
+        send_email_verification: Box<dyn SendEmailVerification>,
+    ) -> Self {
         Self {
-            email_validator,
+            email_normalizer,
+            validation,
             add_account,
+            send_email_verification: Arc::from(send_email_verification),
         }
     }
 
-    /// Set the sign up controller's email validator.
-    pub fn set_email_validator(&mut self, email_validator: Box<dyn EmailValidator>) {
-        self.email_validator = email_validator;
+    /// Set the sign up controller's email normalizer.
+    pub fn set_email_normalizer(&mut self, email_normalizer: Box<dyn EmailNormalizer>) {
+        self.email_normalizer = email_normalizer;
+    }
+
+    /// Set the sign up controller's validation.
+    pub fn set_validation(&mut self, validation: Box<dyn Validation>) {
+        self.validation = validation;
     }
 
     /// Set the sign up controller's add account.
     pub fn set_add_account(&mut self, add_account: Box<dyn AddAccount>) {
         self.add_account = add_account;
     }
+
+    /// Set the sign up controller's send email verification.
+    pub fn set_send_email_verification(
+        &mut self,
+        send_email_verification: Box<dyn SendEmailVerification>,
+    ) {
+        self.send_email_verification = Arc::from(send_email_verification);
+    }
 }
 
 #[async_trait]
@@ -44,39 +70,23 @@ impl ControllerProtocol<SignUpReqBody, SignUpResBody> for SignUpController {
         }
 
         let body = body.unwrap();
-        let name = body.name();
-        let email = body.email();
-        let password = body.password();
-        let password_confirmation = body.password_confirmation();
-
-        if name.is_empty() {
-            return bad_request("missing param 'name'");
-        }
-
-        if email.is_empty() {
-            return bad_request("missing param 'email'");
-        }
-
-        if password.is_empty() {
-            return bad_request("missing param 'password'");
-        }
+        let body = SignUpReqBody {
+            email: self.email_normalizer.normalize(body.email()),
+            ..body.clone()
+        };
 
-        if password_confirmation.is_empty() {
-            return bad_request("missing param 'password_confirmation'");
-        }
+        let errors = match self.validation.validate(&body) {
+            Ok(errors) => errors,
+            Err(_) => return server_error(),
+        };
 
-        if password != password_confirmation {
-            return bad_request("invalid param 'password_confirmation'");
+        if !errors.is_empty() {
+            return validation_errors(errors);
         }
 
-        match self.email_validator.is_valid(email) {
-            Ok(is_valid) => {
-                if !is_valid {
-                    return bad_request("invalid param 'email'");
-                }
-            }
-            Err(_) => return server_error(),
-        }
+        let name = body.name();
+        let email = body.email();
+        let password = body.password();
 
         let result = self
             .add_account
@@ -89,26 +99,26 @@ impl ControllerProtocol<SignUpReqBody, SignUpResBody> for SignUpController {
 
         let account = match result {
             Ok(account) => account,
-            Err(_) => return server_error(),
+            Err(err) => return domain_error(&err),
         };
 
-        HttpResponse::new(200, SignUpResBody::Account(account))
-    }
-}
+        // The account exists at this point, the mail is sent in the background
+        // and one that fails to go out can be requested again through the
+        // resend endpoint.
+        let send_email_verification = Arc::clone(&self.send_email_verification);
+        let email = account.email().to_string();
 
-fn http_error(status_code: u32, msg: &str) -> HttpResponse<SignUpResBody> {
-    HttpResponse::new(status_code, SignUpResBody::Err(ErrorMsg::new(msg)))
-}
-
-fn bad_request(msg: &str) -> HttpResponse<SignUpResBody> {
-    http_error(400, msg)
-}
+        tokio::spawn(async move {
+            if let Err(err) = send_email_verification.send(&email).await {
+                log::error!("failed to send an email verification: {:?}", err);
+            }
+        });
 
-fn server_error() -> HttpResponse<SignUpResBody> {
-    http_error(500, "internal server error")
+        HttpResponse::new(200, SignUpResBody::Account(account.into()))
+    }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct SignUpReqBody {
     name: String,
     email: String,
@@ -138,6 +148,18 @@ impl SignUpReqBody {
     }
 }
 
+impl ValidationInput for SignUpReqBody {
+    fn field(&self, name: &str) -> Option<&str> {
+        match name {
+            "name" => Some(self.name()),
+            "email" => Some(self.email()),
+            "password" => Some(self.password()),
+            "password_confirmation" => Some(self.password_confirmation()),
+            _ => None,
+        }
+    }
+}
+
 pub struct SignUpReqBodyBuilder {
     name: String,
     email: String,
@@ -200,8 +222,21 @@ impl SignUpReqBodyBuilder {
     }
 }
 
-#[derive(Debug, PartialEq)]
+impl Default for SignUpReqBodyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum SignUpResBody {
-    Account(AccountEntity),
-    Err(ErrorMsg),
+    Account(AccountView),
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for SignUpResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
 }
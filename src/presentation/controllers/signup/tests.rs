@@ -1,22 +1,27 @@
+use chrono::{TimeZone, Utc};
 use mockall::predicate;
 use mockall_double::double;
 
 #[double]
 use crate::domain::usecases::AddAccount;
 #[double]
-use crate::presentation::protocols::EmailValidator;
+use crate::domain::usecases::SendEmailVerification;
+#[double]
+use crate::presentation::protocols::EmailNormalizer;
+#[double]
+use crate::presentation::protocols::Validation;
 
 use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
 use crate::domain::usecases::AddAccountDto;
-use crate::presentation::http::HttpRequest;
+use crate::presentation::http::{FieldError, HttpRequest, ProblemDetails};
 use crate::presentation::protocols::ControllerProtocol;
-use crate::ErrorMsg;
 
 use super::{SignUpController, SignUpReqBodyBuilder, SignUpResBody};
 
-macro_rules! email_validator_is_valid_default {
+macro_rules! validation_validate_default {
     () => {
-        |_| Ok(true)
+        |_| Ok(vec![])
     };
 }
 
@@ -29,33 +34,74 @@ macro_rules! add_account_add_default {
                 password,
             } = &account_dto;
 
-            Ok(AccountEntity::new("valid_id", name, email, password))
+            Ok(AccountEntity::new(
+                "valid_id",
+                name,
+                email,
+                password,
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            ))
         }
     };
 }
 
+macro_rules! send_email_verification_send_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+macro_rules! email_normalizer_normalize_default {
+    () => {
+        |email| email.to_string()
+    };
+}
+
 fn make_sut() -> SignUpController {
-    let mut email_validator = make_email_validator();
-    email_validator
-        .expect_is_valid()
-        .returning(email_validator_is_valid_default!());
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .returning(email_normalizer_normalize_default!());
+
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .returning(validation_validate_default!());
 
     let mut add_account = make_add_account();
     add_account
         .expect_add()
         .returning(add_account_add_default!());
 
-    SignUpController::new(email_validator, add_account)
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification
+        .expect_send()
+        .returning(send_email_verification_send_default!());
+
+    SignUpController::new(
+        email_normalizer,
+        validation,
+        add_account,
+        send_email_verification,
+    )
 }
 
-fn make_email_validator() -> Box<EmailValidator> {
-    Box::new(EmailValidator::default())
+fn make_email_normalizer() -> Box<EmailNormalizer> {
+    Box::new(EmailNormalizer::default())
+}
+
+fn make_validation() -> Box<Validation> {
+    Box::new(Validation::default())
 }
 
 fn make_add_account() -> Box<AddAccount> {
     Box::new(AddAccount::default())
 }
 
+fn make_send_email_verification() -> Box<SendEmailVerification> {
+    Box::new(SendEmailVerification::default())
+}
+
 #[tokio::test]
 async fn returns_400_if_req_body_is_none() {
     let sut = make_sut();
@@ -65,36 +111,57 @@ async fn returns_400_if_req_body_is_none() {
     assert_eq!(res.status_code(), 400);
     assert_eq!(
         res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("missing body"))
+        &SignUpResBody::Err(ProblemDetails::new(400, "missing body"))
     );
 }
 
 #[tokio::test]
-async fn returns_400_if_no_name_is_provided() {
-    let sut = make_sut();
+async fn calls_validation_with_correct_body() {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .withf(|input| {
+            input.field("name") == Some("any_name")
+                && input.field("email") == Some("any_email@mail.com")
+                && input.field("password") == Some("any_password")
+                && input.field("password_confirmation") == Some("any_password")
+        })
+        .once()
+        .returning(validation_validate_default!());
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
 
     let body = SignUpReqBodyBuilder::new()
+        .set_name("any_name")
         .set_email("any_email@mail.com")
         .set_password("any_password")
         .set_password_confirmation("any_password")
         .build();
 
     let req = HttpRequest::new(Some(body));
-    let res = sut.handle(req).await;
-
-    assert_eq!(res.status_code(), 400);
-    assert_eq!(
-        res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("missing param 'name'"))
-    );
+    let _ = sut.handle(req).await;
 }
 
 #[tokio::test]
-async fn returns_400_if_no_email_is_provided() {
-    let sut = make_sut();
+async fn returns_400_with_every_validation_error() {
+    let mut validation = make_validation();
+    validation.expect_validate().returning(|_| {
+        Ok(vec![
+            FieldError::new("name", "missing param"),
+            FieldError::new("email", "invalid param"),
+        ])
+    });
+
+    let mut add_account = make_add_account();
+    add_account.expect_add().never();
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
+    sut.set_add_account(add_account);
 
     let body = SignUpReqBodyBuilder::new()
-        .set_name("any_name")
+        .set_email("invalid_email")
         .set_password("any_password")
         .set_password_confirmation("any_password")
         .build();
@@ -105,81 +172,132 @@ async fn returns_400_if_no_email_is_provided() {
     assert_eq!(res.status_code(), 400);
     assert_eq!(
         res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("missing param 'email'"))
+        &SignUpResBody::Err(
+            ProblemDetails::new(400, "name: missing param; email: invalid param").with_errors(
+                vec![
+                    FieldError::new("name", "missing param"),
+                    FieldError::new("email", "invalid param"),
+                ]
+            )
+        )
     );
 }
 
 #[tokio::test]
-async fn returns_400_if_no_password_is_provided() {
-    let sut = make_sut();
+async fn returns_500_if_validation_returns_err() {
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut();
+    sut.set_validation(validation);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
         .set_email("any_email@mail.com")
+        .set_password("any_password")
         .set_password_confirmation("any_password")
         .build();
 
     let req = HttpRequest::new(Some(body));
     let res = sut.handle(req).await;
 
-    assert_eq!(res.status_code(), 400);
+    assert_eq!(res.status_code(), 500);
     assert_eq!(
         res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("missing param 'password'"))
+        &SignUpResBody::Err(ProblemDetails::new(500, "internal server error"))
     );
 }
+
 #[tokio::test]
-async fn returns_400_if_no_password_confirmation_is_provided() {
-    let sut = make_sut();
+async fn validates_and_adds_the_normalized_email() {
+    let mut email_normalizer = make_email_normalizer();
+    email_normalizer
+        .expect_normalize()
+        .with(predicate::eq("any_email@mail.com"))
+        .returning(|_| String::from("normalized_email@mail.com"));
+
+    let mut validation = make_validation();
+    validation
+        .expect_validate()
+        .withf(|input| input.field("email") == Some("normalized_email@mail.com"))
+        .returning(validation_validate_default!());
+
+    let mut add_account = make_add_account();
+    add_account
+        .expect_add()
+        .with(predicate::eq(AddAccountDto {
+            name: String::from("any_name"),
+            email: String::from("normalized_email@mail.com"),
+            password: String::from("any_password"),
+        }))
+        .returning(add_account_add_default!());
+
+    let mut sut = make_sut();
+    sut.set_email_normalizer(email_normalizer);
+    sut.set_validation(validation);
+    sut.set_add_account(add_account);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
         .set_email("any_email@mail.com")
         .set_password("any_password")
+        .set_password_confirmation("any_password")
         .build();
 
     let req = HttpRequest::new(Some(body));
     let res = sut.handle(req).await;
 
-    assert_eq!(res.status_code(), 400);
-    assert_eq!(
-        res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("missing param 'password_confirmation'"))
-    );
+    tokio::task::yield_now().await;
+
+    assert_eq!(res.status_code(), 200);
 }
 
 #[tokio::test]
-async fn returns_400_if_password_confirmation_fails() {
-    let sut = make_sut();
+async fn calls_add_account_with_correct_values() {
+    let mut add_account = make_add_account();
+    add_account
+        .expect_add()
+        .with(predicate::eq(AddAccountDto {
+            name: String::from("any_name"),
+            email: String::from("any_email@mail.com"),
+            password: String::from("any_password"),
+        }))
+        .returning(add_account_add_default!());
+
+    let mut sut = make_sut();
+    sut.set_add_account(add_account);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
         .set_email("any_email@mail.com")
         .set_password("any_password")
-        .set_password_confirmation("invalid_password")
+        .set_password_confirmation("any_password")
         .build();
 
     let req = HttpRequest::new(Some(body));
-    let res = sut.handle(req).await;
-
-    assert_eq!(res.status_code(), 400);
-    assert_eq!(
-        res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("invalid param 'password_confirmation'"))
-    );
+    sut.handle(req).await;
 }
 
 #[tokio::test]
-async fn returns_400_if_invalid_email_is_provided() {
-    let mut email_validator = make_email_validator();
-    email_validator.expect_is_valid().returning(|_| Ok(false));
+async fn returns_500_if_add_account_returns_err() {
+    let mut add_account = make_add_account();
+    add_account
+        .expect_add()
+        .with(predicate::eq(AddAccountDto {
+            name: String::from("any_name"),
+            email: String::from("any_email@mail.com"),
+            password: String::from("any_password"),
+        }))
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
 
     let mut sut = make_sut();
-    sut.set_email_validator(email_validator);
+    sut.set_add_account(add_account);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
-        .set_email("invalid_email@mail.com")
+        .set_email("any_email@mail.com")
         .set_password("any_password")
         .set_password_confirmation("any_password")
         .build();
@@ -187,23 +305,22 @@ async fn returns_400_if_invalid_email_is_provided() {
     let req = HttpRequest::new(Some(body));
     let res = sut.handle(req).await;
 
-    assert_eq!(res.status_code(), 400);
+    assert_eq!(res.status_code(), 500);
     assert_eq!(
         res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("invalid param 'email'"))
+        &SignUpResBody::Err(ProblemDetails::new(500, "internal server error"))
     );
 }
 
 #[tokio::test]
-async fn calls_email_validator_with_correct_email() {
-    let mut email_validator = make_email_validator();
-    email_validator
-        .expect_is_valid()
-        .with(predicate::eq("any_email@mail.com"))
-        .returning(email_validator_is_valid_default!());
+async fn returns_409_if_add_account_returns_email_in_use_error() {
+    let mut add_account = make_add_account();
+    add_account
+        .expect_add()
+        .returning(|_| Err(DomainError::EmailInUse));
 
     let mut sut = make_sut();
-    sut.set_email_validator(email_validator);
+    sut.set_add_account(add_account);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
@@ -213,18 +330,26 @@ async fn calls_email_validator_with_correct_email() {
         .build();
 
     let req = HttpRequest::new(Some(body));
-    sut.handle(req).await;
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 409);
+    assert_eq!(
+        res.body(),
+        &SignUpResBody::Err(ProblemDetails::new(409, "email already in use"))
+    );
 }
 
 #[tokio::test]
-async fn returns_500_if_email_validator_returns_err() {
-    let mut email_validator = make_email_validator();
-    email_validator
-        .expect_is_valid()
-        .returning(|_| ErrorMsg::default().into());
+async fn sends_the_email_verification_to_the_added_account() {
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification
+        .expect_send()
+        .once()
+        .with(predicate::eq("any_email@mail.com"))
+        .returning(send_email_verification_send_default!());
 
     let mut sut = make_sut();
-    sut.set_email_validator(email_validator);
+    sut.set_send_email_verification(send_email_verification);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
@@ -234,29 +359,25 @@ async fn returns_500_if_email_validator_returns_err() {
         .build();
 
     let req = HttpRequest::new(Some(body));
-    let res = sut.handle(req).await;
+    let _ = sut.handle(req).await;
 
-    assert_eq!(res.status_code(), 500);
-    assert_eq!(
-        res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("internal server error"))
-    );
+    // Lets the spawned send run before the mock checks its expectations.
+    tokio::task::yield_now().await;
 }
 
 #[tokio::test]
-async fn calls_add_account_with_correct_values() {
+async fn does_not_send_the_email_verification_if_add_account_returns_err() {
     let mut add_account = make_add_account();
     add_account
         .expect_add()
-        .with(predicate::eq(AddAccountDto {
-            name: String::from("any_name"),
-            email: String::from("any_email@mail.com"),
-            password: String::from("any_password"),
-        }))
-        .returning(add_account_add_default!());
+        .returning(|_| Err(DomainError::EmailInUse));
+
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification.expect_send().never();
 
     let mut sut = make_sut();
     sut.set_add_account(add_account);
+    sut.set_send_email_verification(send_email_verification);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
@@ -266,23 +387,20 @@ async fn calls_add_account_with_correct_values() {
         .build();
 
     let req = HttpRequest::new(Some(body));
-    sut.handle(req).await;
+    let _ = sut.handle(req).await;
+
+    tokio::task::yield_now().await;
 }
 
 #[tokio::test]
-async fn returns_500_if_add_account_returns_err() {
-    let mut add_account = make_add_account();
-    add_account
-        .expect_add()
-        .with(predicate::eq(AddAccountDto {
-            name: String::from("any_name"),
-            email: String::from("any_email@mail.com"),
-            password: String::from("any_password"),
-        }))
-        .returning(|_| ErrorMsg::default().into());
+async fn returns_200_even_if_send_email_verification_returns_err() {
+    let mut send_email_verification = make_send_email_verification();
+    send_email_verification
+        .expect_send()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
 
     let mut sut = make_sut();
-    sut.set_add_account(add_account);
+    sut.set_send_email_verification(send_email_verification);
 
     let body = SignUpReqBodyBuilder::new()
         .set_name("any_name")
@@ -294,11 +412,7 @@ async fn returns_500_if_add_account_returns_err() {
     let req = HttpRequest::new(Some(body));
     let res = sut.handle(req).await;
 
-    assert_eq!(res.status_code(), 500);
-    assert_eq!(
-        res.body(),
-        &SignUpResBody::Err(ErrorMsg::new("internal server error"))
-    );
+    assert_eq!(res.status_code(), 200);
 }
 
 #[tokio::test]
@@ -321,8 +435,9 @@ async fn returns_200_if_valid_data_is_provided() {
         assert_eq!(account.id(), "valid_id");
         assert_eq!(account.name(), "valid_name");
         assert_eq!(account.email(), "valid_email@mail.com");
-        assert_eq!(account.password(), "valid_password");
+        assert!(!account.email_verified());
+        assert_eq!(account.created_at(), Utc.ymd(2022, 1, 1).and_hms(0, 0, 0));
     } else {
-        assert!(false)
+        panic!();
     }
 }
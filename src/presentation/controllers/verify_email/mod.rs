@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::usecases::VerifyEmail;
+use crate::presentation::helpers::{bad_request, domain_error, missing_param};
+use crate::presentation::http::{HttpRequest, HttpResponse, ProblemDetails};
+use crate::presentation::protocols::ControllerProtocol;
+
+#[cfg(test)]
+pub mod tests;
+
+pub struct VerifyEmailController {
+    verify_email: Box<dyn VerifyEmail>,
+}
+
+impl VerifyEmailController {
+    pub fn new(verify_email: Box<dyn VerifyEmail>) -> Self {
+        Self { verify_email }
+    }
+
+    /// Set the verify email controller's verify email.
+    pub fn set_verify_email(&mut self, verify_email: Box<dyn VerifyEmail>) {
+        self.verify_email = verify_email;
+    }
+}
+
+#[async_trait]
+impl ControllerProtocol<VerifyEmailReqBody, VerifyEmailResBody> for VerifyEmailController {
+    async fn handle(
+        &self,
+        req: HttpRequest<VerifyEmailReqBody>,
+    ) -> HttpResponse<VerifyEmailResBody> {
+        let body = req.body();
+
+        if body.is_none() {
+            return bad_request("missing body");
+        }
+
+        let token = body.unwrap().token();
+
+        if token.is_empty() {
+            return missing_param("token");
+        }
+
+        if let Err(err) = self.verify_email.verify(token).await {
+            return domain_error(&err);
+        }
+
+        HttpResponse::new(
+            200,
+            VerifyEmailResBody::Message {
+                message: String::from("email verified"),
+            },
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct VerifyEmailReqBody {
+    token: String,
+}
+
+impl VerifyEmailReqBody {
+    pub fn new(token: &str) -> Self {
+        Self {
+            token: String::from(token),
+        }
+    }
+
+    /// Get a reference to the verify email req body's token.
+    pub fn token(&self) -> &str {
+        self.token.as_ref()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum VerifyEmailResBody {
+    Message { message: String },
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for VerifyEmailResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
+}
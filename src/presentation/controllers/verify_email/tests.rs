@@ -0,0 +1,136 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::domain::usecases::VerifyEmail;
+
+use crate::domain::errors::DomainError;
+use crate::presentation::http::{FieldError, HttpRequest, ProblemDetails};
+use crate::presentation::protocols::ControllerProtocol;
+
+use super::{VerifyEmailController, VerifyEmailReqBody, VerifyEmailResBody};
+
+macro_rules! verify_email_verify_default {
+    () => {
+        |_| Ok(())
+    };
+}
+
+fn make_sut() -> VerifyEmailController {
+    let mut verify_email = make_verify_email();
+    verify_email
+        .expect_verify()
+        .returning(verify_email_verify_default!());
+
+    VerifyEmailController::new(verify_email)
+}
+
+fn make_verify_email() -> Box<VerifyEmail> {
+    Box::new(VerifyEmail::default())
+}
+
+fn make_req() -> HttpRequest<VerifyEmailReqBody> {
+    HttpRequest::new(Some(VerifyEmailReqBody::new("any_token")))
+}
+
+#[tokio::test]
+async fn returns_400_if_req_body_is_none() {
+    let sut = make_sut();
+    let req = HttpRequest::new(None);
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &VerifyEmailResBody::Err(ProblemDetails::new(400, "missing body"))
+    );
+}
+
+#[tokio::test]
+async fn returns_400_if_no_token_is_provided() {
+    let mut verify_email = make_verify_email();
+    verify_email.expect_verify().never();
+
+    let mut sut = make_sut();
+    sut.set_verify_email(verify_email);
+
+    let req = HttpRequest::new(Some(VerifyEmailReqBody::new("")));
+    let res = sut.handle(req).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &VerifyEmailResBody::Err(
+            ProblemDetails::new(400, "token: missing param")
+                .with_errors(vec![FieldError::new("token", "missing param")])
+        )
+    );
+}
+
+#[tokio::test]
+async fn calls_verify_email_with_correct_token() {
+    let mut verify_email = make_verify_email();
+    verify_email
+        .expect_verify()
+        .once()
+        .with(predicate::eq("any_token"))
+        .returning(verify_email_verify_default!());
+
+    let mut sut = make_sut();
+    sut.set_verify_email(verify_email);
+
+    let _ = sut.handle(make_req()).await;
+}
+
+#[tokio::test]
+async fn returns_400_if_verify_email_returns_invalid_token_error() {
+    let mut verify_email = make_verify_email();
+    verify_email
+        .expect_verify()
+        .returning(|_| Err(DomainError::InvalidToken));
+
+    let mut sut = make_sut();
+    sut.set_verify_email(verify_email);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 400);
+    assert_eq!(
+        res.body(),
+        &VerifyEmailResBody::Err(ProblemDetails::new(400, "invalid or expired token"))
+    );
+}
+
+#[tokio::test]
+async fn returns_500_if_verify_email_returns_err() {
+    let mut verify_email = make_verify_email();
+    verify_email
+        .expect_verify()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_verify_email(verify_email);
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 500);
+    assert_eq!(
+        res.body(),
+        &VerifyEmailResBody::Err(ProblemDetails::new(500, "internal server error"))
+    );
+}
+
+#[tokio::test]
+async fn returns_200_if_the_token_is_valid() {
+    let sut = make_sut();
+
+    let res = sut.handle(make_req()).await;
+
+    assert_eq!(res.status_code(), 200);
+    assert_eq!(
+        res.body(),
+        &VerifyEmailResBody::Message {
+            message: String::from("email verified")
+        }
+    );
+}
@@ -0,0 +1,179 @@
+use crate::domain::errors::DomainError;
+use crate::presentation::http::{
+    FieldError, HttpResponse, ProblemDetails, PROBLEM_JSON_CONTENT_TYPE,
+};
+
+/// Answers with `problem` as an `application/problem+json` body.
+pub fn problem<T>(problem: ProblemDetails) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    HttpResponse::new(problem.status(), T::from(problem))
+        .with_content_type(PROBLEM_JSON_CONTENT_TYPE)
+}
+
+pub fn http_error<T>(status_code: u32, detail: &str) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    problem(ProblemDetails::new(status_code, detail))
+}
+
+pub fn bad_request<T>(detail: &str) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    http_error(400, detail)
+}
+
+pub fn missing_param<T>(field: &str) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    field_error(field, "missing param")
+}
+
+pub fn invalid_param<T>(field: &str) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    field_error(field, "invalid param")
+}
+
+pub fn server_error<T>() -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    http_error(500, "internal server error")
+}
+
+/// Answers with the status code and detail `err` maps to. Unexpected errors
+/// hide their cause from the client.
+pub fn domain_error<T>(err: &DomainError) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    match err {
+        DomainError::EmailInUse => http_error(409, &err.to_string()),
+        DomainError::InvalidCredentials => http_error(401, "unauthorized"),
+        DomainError::EmailNotVerified => http_error(403, &err.to_string()),
+        DomainError::IncorrectPassword => field_error("current_password", &err.to_string()),
+        DomainError::PasswordContainsPersonalInfo => field_error("password", &err.to_string()),
+        DomainError::InvalidToken => bad_request(&err.to_string()),
+        DomainError::Unexpected(_) => server_error(),
+    }
+}
+
+/// Answers with a single 400 listing every field error, e.g. `name: missing
+/// param; email: invalid param`.
+pub fn validation_errors<T>(errors: Vec<FieldError>) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    let detail = errors
+        .iter()
+        .map(|error| format!("{}: {}", error.field(), error.detail()))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    problem(ProblemDetails::new(400, &detail).with_errors(errors))
+}
+
+fn field_error<T>(field: &str, detail: &str) -> HttpResponse<T>
+where
+    T: From<ProblemDetails> + Send,
+{
+    validation_errors(vec![FieldError::new(field, detail)])
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::domain::errors::DomainError;
+    use crate::presentation::http::{HttpResponse, ProblemDetails, PROBLEM_JSON_CONTENT_TYPE};
+
+    use crate::presentation::http::FieldError;
+
+    use super::{domain_error, missing_param, server_error, validation_errors};
+
+    #[test]
+    fn serves_problems_as_problem_json() {
+        let res: HttpResponse<ProblemDetails> = server_error();
+
+        assert_eq!(res.status_code(), 500);
+        assert_eq!(res.content_type(), PROBLEM_JSON_CONTENT_TYPE);
+        assert_eq!(
+            serde_json::to_value(res.body()).unwrap(),
+            json!({
+                "type": "about:blank",
+                "title": "Internal Server Error",
+                "status": 500,
+                "detail": "internal server error"
+            })
+        );
+    }
+
+    #[test]
+    fn lists_the_field_of_param_errors() {
+        let res: HttpResponse<ProblemDetails> = missing_param("email");
+
+        assert_eq!(
+            serde_json::to_value(res.body()).unwrap(),
+            json!({
+                "type": "about:blank",
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "email: missing param",
+                "errors": [{ "field": "email", "detail": "missing param" }]
+            })
+        );
+    }
+
+    #[test]
+    fn joins_every_field_error_in_the_detail() {
+        let res: HttpResponse<ProblemDetails> = validation_errors(vec![
+            FieldError::new("name", "missing param"),
+            FieldError::new("email", "invalid param"),
+        ]);
+
+        assert_eq!(res.status_code(), 400);
+        assert_eq!(
+            res.body().detail(),
+            "name: missing param; email: invalid param"
+        );
+        assert_eq!(res.body().errors().len(), 2);
+    }
+
+    #[test]
+    fn maps_each_domain_error_to_its_status_code() {
+        let cases = [
+            (DomainError::EmailInUse, 409, "email already in use"),
+            (DomainError::InvalidCredentials, 401, "unauthorized"),
+            (DomainError::EmailNotVerified, 403, "email not verified"),
+            (
+                DomainError::IncorrectPassword,
+                400,
+                "current_password: incorrect password",
+            ),
+            (
+                DomainError::PasswordContainsPersonalInfo,
+                400,
+                "password: contains personal info",
+            ),
+            (DomainError::InvalidToken, 400, "invalid or expired token"),
+            (
+                DomainError::Unexpected("any_error".into()),
+                500,
+                "internal server error",
+            ),
+        ];
+
+        for (err, status_code, detail) in cases {
+            let res: HttpResponse<ProblemDetails> = domain_error(&err);
+
+            assert_eq!(res.status_code(), status_code);
+            assert_eq!(res.body().detail(), detail);
+        }
+    }
+}
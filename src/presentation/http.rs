@@ -1,8 +1,14 @@
+use serde::Serialize;
+
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
 pub struct HttpRequest<T>
 where
     Self: Send,
 {
     body: Option<T>,
+    account_id: Option<String>,
 }
 
 impl<T> HttpRequest<T>
@@ -10,13 +16,29 @@ where
     Self: Send,
 {
     pub fn new(body: Option<T>) -> Self {
-        Self { body }
+        Self {
+            body,
+            account_id: None,
+        }
+    }
+
+    /// Set the id of the account the request was authenticated as.
+    pub fn with_account_id(self, account_id: &str) -> Self {
+        let mut this = self;
+        this.account_id = Some(String::from(account_id));
+        this
     }
 
     /// Get a reference to the http request's body.
     pub fn body(&self) -> Option<&T> {
         self.body.as_ref()
     }
+
+    /// Get the id of the account the request was authenticated as, `None` on
+    /// unprotected routes.
+    pub fn account_id(&self) -> Option<&str> {
+        self.account_id.as_deref()
+    }
 }
 
 pub struct HttpResponse<T>
@@ -24,6 +46,7 @@ where
     Self: Send,
 {
     status_code: u32,
+    content_type: &'static str,
     body: T,
 }
 
@@ -32,7 +55,18 @@ where
     Self: Send,
 {
     pub fn new(status_code: u32, body: T) -> Self {
-        Self { status_code, body }
+        Self {
+            status_code,
+            content_type: JSON_CONTENT_TYPE,
+            body,
+        }
+    }
+
+    /// Set the http response's content type, `application/json` by default.
+    pub fn with_content_type(self, content_type: &'static str) -> Self {
+        let mut this = self;
+        this.content_type = content_type;
+        this
     }
 
     /// Get the http response's status code.
@@ -40,8 +74,107 @@ where
         self.status_code
     }
 
+    /// Get the http response's content type.
+    pub fn content_type(&self) -> &'static str {
+        self.content_type
+    }
+
     /// Get a reference to the http response's body.
     pub fn body(&self) -> &T {
         &self.body
     }
 }
+
+/// Error response body following RFC 7807, served as
+/// `application/problem+json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    problem_type: String,
+    title: String,
+    status: u32,
+    detail: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<FieldError>,
+}
+
+impl ProblemDetails {
+    /// Builds a problem with the `about:blank` type, titled after the status.
+    pub fn new(status: u32, detail: &str) -> Self {
+        Self {
+            problem_type: String::from("about:blank"),
+            title: String::from(status_title(status)),
+            status,
+            detail: String::from(detail),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Set the problem details' per-field errors.
+    pub fn with_errors(self, errors: Vec<FieldError>) -> Self {
+        let mut this = self;
+        this.errors = errors;
+        this
+    }
+
+    /// Get the problem details' status.
+    pub fn status(&self) -> u32 {
+        self.status
+    }
+
+    /// Get a reference to the problem details' title.
+    pub fn title(&self) -> &str {
+        self.title.as_ref()
+    }
+
+    /// Get a reference to the problem details' detail.
+    pub fn detail(&self) -> &str {
+        self.detail.as_ref()
+    }
+
+    /// Get a reference to the problem details' per-field errors.
+    pub fn errors(&self) -> &[FieldError] {
+        self.errors.as_ref()
+    }
+}
+
+/// Validation error of a single request body field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    field: String,
+    detail: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, detail: &str) -> Self {
+        Self {
+            field: String::from(field),
+            detail: String::from(detail),
+        }
+    }
+
+    /// Get a reference to the field error's field.
+    pub fn field(&self) -> &str {
+        self.field.as_ref()
+    }
+
+    /// Get a reference to the field error's detail.
+    pub fn detail(&self) -> &str {
+        self.detail.as_ref()
+    }
+}
+
+fn status_title(status: u32) -> &'static str {
+    match status {
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Error",
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Serialize;
+
+use crate::domain::usecases::LoadAccountByToken;
+use crate::presentation::helpers::{http_error, server_error};
+use crate::presentation::http::{HttpResponse, ProblemDetails};
+
+#[cfg(test)]
+pub mod tests;
+
+/// Authenticates the requests of protected routes by their bearer access
+/// token.
+pub struct AuthMiddleware {
+    load_account_by_token: Box<dyn LoadAccountByToken>,
+}
+
+impl AuthMiddleware {
+    pub fn new(load_account_by_token: Box<dyn LoadAccountByToken>) -> Self {
+        Self {
+            load_account_by_token,
+        }
+    }
+
+    /// Set the auth middleware's load account by token.
+    pub fn set_load_account_by_token(
+        &mut self,
+        load_account_by_token: Box<dyn LoadAccountByToken>,
+    ) {
+        self.load_account_by_token = load_account_by_token;
+    }
+
+    /// Answers 200 with the id of the account the `Authorization` header
+    /// value authenticates, or 401 if it is missing or not a valid
+    /// `Bearer` access token.
+    pub async fn handle(&self, authorization: Option<&str>) -> HttpResponse<AuthResBody> {
+        let access_token = match authorization.and_then(bearer_token) {
+            Some(access_token) => access_token,
+            None => return http_error(401, "unauthorized"),
+        };
+
+        let account = match self.load_account_by_token.load(access_token).await {
+            Ok(Some(account)) => account,
+            Ok(None) => return http_error(401, "unauthorized"),
+            Err(_) => return server_error(),
+        };
+
+        HttpResponse::new(
+            200,
+            AuthResBody::AccountId {
+                account_id: account.id().to_string(),
+            },
+        )
+    }
+}
+
+/// Token of a `Bearer <token>` header value, the scheme being case
+/// insensitive.
+fn bearer_token(authorization: &str) -> Option<&str> {
+    let (scheme, token) = authorization.trim().split_once(' ')?;
+    let token = token.trim();
+
+    if !scheme.eq_ignore_ascii_case("bearer") || token.is_empty() {
+        return None;
+    }
+
+    Some(token)
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum AuthResBody {
+    AccountId { account_id: String },
+    Err(ProblemDetails),
+}
+
+impl From<ProblemDetails> for AuthResBody {
+    fn from(problem_details: ProblemDetails) -> Self {
+        Self::Err(problem_details)
+    }
+}
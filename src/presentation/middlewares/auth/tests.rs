@@ -0,0 +1,134 @@
+use chrono::{TimeZone, Utc};
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::domain::usecases::LoadAccountByToken;
+
+use crate::domain::entities::AccountEntity;
+use crate::domain::errors::DomainError;
+use crate::presentation::http::{ProblemDetails, PROBLEM_JSON_CONTENT_TYPE};
+
+use super::{AuthMiddleware, AuthResBody};
+
+macro_rules! load_account_by_token_load_default {
+    () => {
+        |_| {
+            Ok(Some(AccountEntity::new(
+                "any_id",
+                "any_name",
+                "any_email@mail.com",
+                "hashed_password",
+                Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+            )))
+        }
+    };
+}
+
+fn make_sut() -> AuthMiddleware {
+    let mut load_account_by_token = make_load_account_by_token();
+    load_account_by_token
+        .expect_load()
+        .returning(load_account_by_token_load_default!());
+
+    AuthMiddleware::new(load_account_by_token)
+}
+
+fn make_load_account_by_token() -> Box<LoadAccountByToken> {
+    Box::new(LoadAccountByToken::default())
+}
+
+fn unauthorized() -> AuthResBody {
+    AuthResBody::Err(ProblemDetails::new(401, "unauthorized"))
+}
+
+#[tokio::test]
+async fn returns_401_if_no_authorization_is_provided() {
+    let sut = make_sut();
+
+    let res = sut.handle(None).await;
+
+    assert_eq!(res.status_code(), 401);
+    assert_eq!(res.content_type(), PROBLEM_JSON_CONTENT_TYPE);
+    assert_eq!(res.body(), &unauthorized());
+}
+
+#[tokio::test]
+async fn returns_401_if_authorization_is_not_a_bearer_token() {
+    let mut load_account_by_token = make_load_account_by_token();
+    load_account_by_token.expect_load().never();
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_token(load_account_by_token);
+
+    for authorization in ["any_token", "Basic any_token", "Bearer", "Bearer  "] {
+        let res = sut.handle(Some(authorization)).await;
+
+        assert_eq!(res.status_code(), 401);
+        assert_eq!(res.body(), &unauthorized());
+    }
+}
+
+#[tokio::test]
+async fn calls_load_account_by_token_with_the_bearer_token() {
+    let mut load_account_by_token = make_load_account_by_token();
+    load_account_by_token
+        .expect_load()
+        .with(predicate::eq("any_token"))
+        .times(2)
+        .returning(load_account_by_token_load_default!());
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_token(load_account_by_token);
+
+    let _ = sut.handle(Some("Bearer any_token")).await;
+    let _ = sut.handle(Some("bearer any_token")).await;
+}
+
+#[tokio::test]
+async fn returns_401_if_load_account_by_token_returns_none() {
+    let mut load_account_by_token = make_load_account_by_token();
+    load_account_by_token.expect_load().returning(|_| Ok(None));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_token(load_account_by_token);
+
+    let res = sut.handle(Some("Bearer any_token")).await;
+
+    assert_eq!(res.status_code(), 401);
+    assert_eq!(res.body(), &unauthorized());
+}
+
+#[tokio::test]
+async fn returns_500_if_load_account_by_token_returns_err() {
+    let mut load_account_by_token = make_load_account_by_token();
+    load_account_by_token
+        .expect_load()
+        .returning(|_| Err(DomainError::Unexpected("any_error".into())));
+
+    let mut sut = make_sut();
+    sut.set_load_account_by_token(load_account_by_token);
+
+    let res = sut.handle(Some("Bearer any_token")).await;
+
+    assert_eq!(res.status_code(), 500);
+    assert_eq!(
+        res.body(),
+        &AuthResBody::Err(ProblemDetails::new(500, "internal server error"))
+    );
+}
+
+#[tokio::test]
+async fn returns_200_with_the_account_id_on_success() {
+    let sut = make_sut();
+
+    let res = sut.handle(Some("Bearer any_token")).await;
+
+    assert_eq!(res.status_code(), 200);
+    assert_eq!(
+        res.body(),
+        &AuthResBody::AccountId {
+            account_id: String::from("any_id")
+        }
+    );
+}
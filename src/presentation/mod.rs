@@ -1,3 +1,6 @@
 pub mod controllers;
+pub mod helpers;
 pub mod http;
+pub mod middlewares;
 pub mod protocols;
+pub mod views;
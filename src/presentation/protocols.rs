@@ -1,5 +1,9 @@
 pub mod controller;
+pub mod email_normalizer;
 pub mod email_validator;
+pub mod validation;
 
 pub use controller::ControllerProtocol;
-pub use email_validator::{EmailValidator, MockEmailValidator};
+pub use email_normalizer::{EmailNormalizer, MockEmailNormalizer};
+pub use email_validator::{EmailRejection, EmailValidator, MockEmailValidator};
+pub use validation::{MockValidation, Validation, ValidationInput};
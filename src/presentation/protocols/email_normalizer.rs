@@ -0,0 +1,9 @@
+use mockall::automock;
+
+#[automock]
+pub trait EmailNormalizer: Send + Sync {
+    /// Returns the canonical form of `email`, used to validate, store and look
+    /// up accounts. Malformed emails are returned trimmed, for the email
+    /// validator to reject.
+    fn normalize(&self, email: &str) -> String;
+}
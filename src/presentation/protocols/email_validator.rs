@@ -2,7 +2,41 @@ use mockall::automock;
 
 use crate::GenericResult;
 
+/// Why an email was rejected by an `EmailValidator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailRejection {
+    InvalidSyntax,
+    /// The domain is missing from a non empty allow list.
+    DomainNotAllowed,
+    DomainDenied,
+    DisposableDomain,
+    /// The domain has no MX record, so it can't receive mail.
+    NoMailServer,
+}
+
+impl EmailRejection {
+    /// Field error detail sent to the client.
+    pub fn detail(&self) -> &'static str {
+        match self {
+            Self::InvalidSyntax => "invalid param",
+            Self::DomainNotAllowed => "domain not allowed",
+            Self::DomainDenied => "domain denied",
+            Self::DisposableDomain => "disposable domain",
+            Self::NoMailServer => "domain without mail server",
+        }
+    }
+}
+
 #[automock]
 pub trait EmailValidator: Send + Sync {
     fn is_valid(&self, email: &str) -> GenericResult<bool>;
+
+    /// Returns why `email` is rejected, or `None` when it is valid.
+    fn check(&self, email: &str) -> GenericResult<Option<EmailRejection>> {
+        if self.is_valid(email)? {
+            Ok(None)
+        } else {
+            Ok(Some(EmailRejection::InvalidSyntax))
+        }
+    }
 }
@@ -0,0 +1,17 @@
+use mockall::automock;
+
+use crate::presentation::http::FieldError;
+use crate::GenericResult;
+
+/// Exposes the fields of a request body by name, so validations can be
+/// written once and reused across controllers.
+pub trait ValidationInput {
+    fn field(&self, name: &str) -> Option<&str>;
+}
+
+#[automock]
+pub trait Validation: Send + Sync {
+    /// Returns the field errors found in `input`. `Err` is kept for failures
+    /// of the validation itself, not for invalid input.
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>>;
+}
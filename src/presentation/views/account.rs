@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::entities::AccountEntity;
+
+/// Public representation of an account, safe to send to clients.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AccountView {
+    id: String,
+    name: String,
+    email: String,
+    email_verified: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl AccountView {
+    /// Get a reference to the account view's id.
+    pub fn id(&self) -> &str {
+        self.id.as_ref()
+    }
+
+    /// Get a reference to the account view's name.
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Get a reference to the account view's email.
+    pub fn email(&self) -> &str {
+        self.email.as_ref()
+    }
+
+    /// Get whether the account view's email is verified.
+    pub fn email_verified(&self) -> bool {
+        self.email_verified
+    }
+
+    /// Get the account view's creation date.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+impl From<&AccountEntity> for AccountView {
+    fn from(account: &AccountEntity) -> Self {
+        Self {
+            id: String::from(account.id()),
+            name: String::from(account.name()),
+            email: String::from(account.email()),
+            email_verified: account.email_verified(),
+            created_at: account.created_at(),
+        }
+    }
+}
+
+impl From<AccountEntity> for AccountView {
+    fn from(account: AccountEntity) -> Self {
+        Self::from(&account)
+    }
+}
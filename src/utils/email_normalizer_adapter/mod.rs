@@ -0,0 +1,101 @@
+use serde::Deserialize;
+
+use crate::presentation::protocols::EmailNormalizer;
+
+#[cfg(test)]
+pub mod tests;
+
+const GMAIL_DOMAINS: [&str; 2] = ["gmail.com", "googlemail.com"];
+const PLUS_ADDRESSING_DOMAINS: [&str; 7] = [
+    "outlook.com",
+    "hotmail.com",
+    "live.com",
+    "icloud.com",
+    "me.com",
+    "fastmail.com",
+    "protonmail.com",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct EmailNormalizerConfig {
+    /// Lowercases the part before the `@`. Few providers treat it as case
+    /// sensitive, and users rarely type it the same way twice.
+    pub lowercase_local_part: bool,
+    /// Applies the rules of well known providers, like dropping the dots of
+    /// Gmail addresses and the `+tag` of plus addressing.
+    pub provider_rules: bool,
+}
+
+impl Default for EmailNormalizerConfig {
+    fn default() -> Self {
+        Self {
+            lowercase_local_part: true,
+            provider_rules: false,
+        }
+    }
+}
+
+/// Normalizes emails by trimming them, lowercasing and converting the domain
+/// to ASCII (punycode), and optionally applying provider specific rules.
+#[derive(Default)]
+pub struct EmailNormalizerAdapter {
+    config: EmailNormalizerConfig,
+}
+
+impl EmailNormalizerAdapter {
+    pub fn new(config: EmailNormalizerConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl EmailNormalizer for EmailNormalizerAdapter {
+    fn normalize(&self, email: &str) -> String {
+        let email = email.trim();
+
+        let (local_part, domain) = match email.rsplit_once('@') {
+            Some(parts) => parts,
+            None => return email.to_string(),
+        };
+
+        let domain = match idna::domain_to_ascii(domain.trim_end_matches('.')) {
+            Ok(domain) => domain,
+            Err(_) => return email.to_string(),
+        };
+
+        let mut local_part = if self.config.lowercase_local_part {
+            local_part.to_lowercase()
+        } else {
+            local_part.to_string()
+        };
+
+        let mut domain = domain;
+
+        if self.config.provider_rules {
+            (local_part, domain) = apply_provider_rules(local_part, domain);
+        }
+
+        format!("{}@{}", local_part, domain)
+    }
+}
+
+fn apply_provider_rules(local_part: String, domain: String) -> (String, String) {
+    if GMAIL_DOMAINS.contains(&domain.as_str()) {
+        let local_part = strip_tag(&local_part).replace('.', "");
+        return (local_part, String::from("gmail.com"));
+    }
+
+    if PLUS_ADDRESSING_DOMAINS.contains(&domain.as_str()) {
+        return (strip_tag(&local_part).to_string(), domain);
+    }
+
+    (local_part, domain)
+}
+
+/// Drops the `+tag` of plus addressed local parts.
+fn strip_tag(local_part: &str) -> &str {
+    match local_part.split_once('+') {
+        Some((local_part, _)) if !local_part.is_empty() => local_part,
+        _ => local_part,
+    }
+}
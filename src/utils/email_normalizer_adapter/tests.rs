@@ -0,0 +1,91 @@
+use crate::presentation::protocols::EmailNormalizer;
+
+use super::{EmailNormalizerAdapter, EmailNormalizerConfig};
+
+fn make_sut() -> EmailNormalizerAdapter {
+    EmailNormalizerAdapter::default()
+}
+
+fn make_sut_with_provider_rules() -> EmailNormalizerAdapter {
+    EmailNormalizerAdapter::new(EmailNormalizerConfig {
+        provider_rules: true,
+        ..EmailNormalizerConfig::default()
+    })
+}
+
+#[test]
+fn trims_the_email() {
+    let sut = make_sut();
+
+    assert_eq!(sut.normalize("  foo@mail.com \n"), "foo@mail.com");
+}
+
+#[test]
+fn lowercases_the_email() {
+    let sut = make_sut();
+
+    assert_eq!(sut.normalize("Foo@Gmail.COM"), "foo@gmail.com");
+}
+
+#[test]
+fn keeps_the_local_part_case_if_configured() {
+    let sut = EmailNormalizerAdapter::new(EmailNormalizerConfig {
+        lowercase_local_part: false,
+        ..EmailNormalizerConfig::default()
+    });
+
+    assert_eq!(sut.normalize("Foo@Gmail.COM"), "Foo@gmail.com");
+}
+
+#[test]
+fn converts_the_domain_to_punycode() {
+    let sut = make_sut();
+
+    assert_eq!(sut.normalize("foo@Bücher.de"), "foo@xn--bcher-kva.de");
+}
+
+#[test]
+fn drops_the_trailing_dot_of_the_domain() {
+    let sut = make_sut();
+
+    assert_eq!(sut.normalize("foo@mail.com."), "foo@mail.com");
+}
+
+#[test]
+fn returns_malformed_emails_trimmed() {
+    let sut = make_sut();
+
+    assert_eq!(sut.normalize(" invalid_email "), "invalid_email");
+    assert_eq!(sut.normalize("foo@xn--a.com"), "foo@xn--a.com");
+}
+
+#[test]
+fn keeps_dots_and_tags_without_provider_rules() {
+    let sut = make_sut();
+
+    assert_eq!(
+        sut.normalize("f.o.o+news@gmail.com"),
+        "f.o.o+news@gmail.com"
+    );
+}
+
+#[test]
+fn applies_gmail_rules() {
+    let sut = make_sut_with_provider_rules();
+
+    assert_eq!(sut.normalize("F.o.o+news@GoogleMail.com"), "foo@gmail.com");
+}
+
+#[test]
+fn strips_the_tag_of_plus_addressing_providers() {
+    let sut = make_sut_with_provider_rules();
+
+    assert_eq!(sut.normalize("f.oo+news@outlook.com"), "f.oo@outlook.com");
+}
+
+#[test]
+fn leaves_other_providers_untouched() {
+    let sut = make_sut_with_provider_rules();
+
+    assert_eq!(sut.normalize("f.oo+news@mail.com"), "f.oo+news@mail.com");
+}
@@ -0,0 +1,130 @@
+use mockall::automock;
+use serde::Deserialize;
+
+use crate::presentation::protocols::{EmailRejection, EmailValidator};
+use crate::utils::EmailValidatorAdapter;
+use crate::GenericResult;
+
+#[cfg(test)]
+pub mod tests;
+
+/// Bundled list of disposable email domains, one per line.
+const DISPOSABLE_DOMAINS: &str = include_str!("disposable_domains.txt");
+
+/// Looks up whether a domain can receive mail. Kept as a protocol so the DNS
+/// lookup can be swapped, or stubbed in tests.
+#[automock]
+pub trait MxChecker: Send + Sync {
+    fn has_mx(&self, domain: &str) -> GenericResult<bool>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct EmailPolicyConfig {
+    /// When not empty, only these domains and their subdomains are accepted.
+    pub allowed_domains: Vec<String>,
+    /// Domains, and their subdomains, that are always rejected.
+    pub denied_domains: Vec<String>,
+    /// Rejects the domains of the bundled disposable email list.
+    pub reject_disposable: bool,
+}
+
+impl Default for EmailPolicyConfig {
+    fn default() -> Self {
+        Self {
+            allowed_domains: vec![],
+            denied_domains: vec![],
+            reject_disposable: true,
+        }
+    }
+}
+
+/// Email validator checking the syntax, then the domain against the allow and
+/// deny lists, the disposable list and, when set, its MX records.
+pub struct EmailPolicyValidator {
+    syntax_validator: Box<dyn EmailValidator>,
+    mx_checker: Option<Box<dyn MxChecker>>,
+    config: EmailPolicyConfig,
+}
+
+impl EmailPolicyValidator {
+    pub fn new(config: EmailPolicyConfig) -> Self {
+        Self {
+            syntax_validator: Box::new(EmailValidatorAdapter::new()),
+            mx_checker: None,
+            config,
+        }
+    }
+
+    /// Set the email policy validator's syntax validator.
+    pub fn set_syntax_validator(&mut self, syntax_validator: Box<dyn EmailValidator>) {
+        self.syntax_validator = syntax_validator;
+    }
+
+    /// Set the email policy validator's mx checker.
+    pub fn set_mx_checker(&mut self, mx_checker: Box<dyn MxChecker>) {
+        self.mx_checker = Some(mx_checker);
+    }
+}
+
+impl Default for EmailPolicyValidator {
+    fn default() -> Self {
+        Self::new(EmailPolicyConfig::default())
+    }
+}
+
+impl EmailValidator for EmailPolicyValidator {
+    fn is_valid(&self, email: &str) -> GenericResult<bool> {
+        Ok(self.check(email)?.is_none())
+    }
+
+    fn check(&self, email: &str) -> GenericResult<Option<EmailRejection>> {
+        if !self.syntax_validator.is_valid(email)? {
+            return Ok(Some(EmailRejection::InvalidSyntax));
+        }
+
+        let domain = match email.rsplit_once('@') {
+            Some((_, domain)) => domain.to_lowercase(),
+            None => return Ok(Some(EmailRejection::InvalidSyntax)),
+        };
+
+        let config = &self.config;
+
+        if !config.allowed_domains.is_empty() && !matches_any(&domain, &config.allowed_domains) {
+            return Ok(Some(EmailRejection::DomainNotAllowed));
+        }
+
+        if matches_any(&domain, &config.denied_domains) {
+            return Ok(Some(EmailRejection::DomainDenied));
+        }
+
+        if config.reject_disposable && matches_any(&domain, DISPOSABLE_DOMAINS.lines()) {
+            return Ok(Some(EmailRejection::DisposableDomain));
+        }
+
+        if let Some(mx_checker) = &self.mx_checker {
+            if !mx_checker.has_mx(&domain)? {
+                return Ok(Some(EmailRejection::NoMailServer));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Whether `domain` is one of `domains` or a subdomain of them.
+fn matches_any<I, S>(domain: &str, domains: I) -> bool
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    domains.into_iter().any(|listed| {
+        let listed = listed.as_ref().trim().to_lowercase();
+
+        let is_subdomain = domain
+            .strip_suffix(listed.as_str())
+            .is_some_and(|prefix| prefix.ends_with('.'));
+
+        !listed.is_empty() && (domain == listed || is_subdomain)
+    })
+}
@@ -0,0 +1,155 @@
+use mockall::predicate;
+use mockall_double::double;
+
+#[double]
+use crate::presentation::protocols::EmailValidator;
+
+use crate::presentation::protocols::EmailRejection;
+use crate::presentation::protocols::EmailValidator as _;
+
+use super::{EmailPolicyConfig, EmailPolicyValidator, MockMxChecker};
+
+fn make_sut(config: EmailPolicyConfig) -> EmailPolicyValidator {
+    EmailPolicyValidator::new(config)
+}
+
+fn make_mx_checker(has_mx: bool) -> Box<MockMxChecker> {
+    let mut mx_checker = MockMxChecker::default();
+    mx_checker.expect_has_mx().returning(move |_| Ok(has_mx));
+    Box::new(mx_checker)
+}
+
+#[test]
+fn accepts_a_valid_email() {
+    let sut = make_sut(EmailPolicyConfig::default());
+
+    assert_eq!(sut.check("any_email@mail.com").unwrap(), None);
+    assert!(sut.is_valid("any_email@mail.com").unwrap());
+}
+
+#[test]
+fn rejects_invalid_syntax() {
+    let sut = make_sut(EmailPolicyConfig::default());
+
+    assert_eq!(
+        sut.check("invalid_email").unwrap(),
+        Some(EmailRejection::InvalidSyntax)
+    );
+    assert!(!sut.is_valid("invalid_email").unwrap());
+}
+
+#[test]
+fn returns_err_if_syntax_validator_returns_err() {
+    let mut syntax_validator = EmailValidator::default();
+    syntax_validator
+        .expect_is_valid()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut(EmailPolicyConfig::default());
+    sut.set_syntax_validator(Box::new(syntax_validator));
+
+    assert!(sut.check("any_email@mail.com").is_err());
+}
+
+#[test]
+fn rejects_domains_missing_from_the_allow_list() {
+    let sut = make_sut(EmailPolicyConfig {
+        allowed_domains: vec![String::from("company.com")],
+        ..EmailPolicyConfig::default()
+    });
+
+    assert_eq!(sut.check("any_email@company.com").unwrap(), None);
+    assert_eq!(sut.check("any_email@eu.company.com").unwrap(), None);
+    assert_eq!(
+        sut.check("any_email@notcompany.com").unwrap(),
+        Some(EmailRejection::DomainNotAllowed)
+    );
+}
+
+#[test]
+fn rejects_denied_domains_and_their_subdomains() {
+    let sut = make_sut(EmailPolicyConfig {
+        denied_domains: vec![String::from("Spam.com")],
+        ..EmailPolicyConfig::default()
+    });
+
+    assert_eq!(
+        sut.check("any_email@spam.com").unwrap(),
+        Some(EmailRejection::DomainDenied)
+    );
+    assert_eq!(
+        sut.check("any_email@mail.spam.com").unwrap(),
+        Some(EmailRejection::DomainDenied)
+    );
+    assert_eq!(sut.check("any_email@notspam.com").unwrap(), None);
+}
+
+#[test]
+fn rejects_disposable_domains() {
+    let sut = make_sut(EmailPolicyConfig::default());
+
+    assert_eq!(
+        sut.check("any_email@Mailinator.com").unwrap(),
+        Some(EmailRejection::DisposableDomain)
+    );
+}
+
+#[test]
+fn accepts_disposable_domains_if_configured() {
+    let sut = make_sut(EmailPolicyConfig {
+        reject_disposable: false,
+        ..EmailPolicyConfig::default()
+    });
+
+    assert_eq!(sut.check("any_email@mailinator.com").unwrap(), None);
+}
+
+#[test]
+fn calls_mx_checker_with_correct_domain() {
+    let mut mx_checker = MockMxChecker::default();
+    mx_checker
+        .expect_has_mx()
+        .with(predicate::eq("mail.com"))
+        .once()
+        .returning(|_| Ok(true));
+
+    let mut sut = make_sut(EmailPolicyConfig::default());
+    sut.set_mx_checker(Box::new(mx_checker));
+
+    assert_eq!(sut.check("any_email@Mail.com").unwrap(), None);
+}
+
+#[test]
+fn rejects_domains_without_mx_records() {
+    let mut sut = make_sut(EmailPolicyConfig::default());
+    sut.set_mx_checker(make_mx_checker(false));
+
+    assert_eq!(
+        sut.check("any_email@mail.com").unwrap(),
+        Some(EmailRejection::NoMailServer)
+    );
+}
+
+#[test]
+fn does_not_call_mx_checker_if_the_domain_is_already_rejected() {
+    let mut mx_checker = MockMxChecker::default();
+    mx_checker.expect_has_mx().never();
+
+    let mut sut = make_sut(EmailPolicyConfig::default());
+    sut.set_mx_checker(Box::new(mx_checker));
+
+    let _ = sut.check("any_email@mailinator.com");
+}
+
+#[test]
+fn returns_err_if_mx_checker_returns_err() {
+    let mut mx_checker = MockMxChecker::default();
+    mx_checker
+        .expect_has_mx()
+        .returning(|_| Err("any_error".into()));
+
+    let mut sut = make_sut(EmailPolicyConfig::default());
+    sut.set_mx_checker(Box::new(mx_checker));
+
+    assert!(sut.check("any_email@mail.com").is_err());
+}
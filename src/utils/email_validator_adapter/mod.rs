@@ -39,20 +39,12 @@ impl EmailValidator for EmailValidatorAdapter {
     }
 }
 
-#[derive(Validate)]
+#[derive(Default, Validate)]
 struct StdEmailValidator {
     #[validate(email)]
     email: String,
 }
 
-impl Default for StdEmailValidator {
-    fn default() -> Self {
-        Self {
-            email: Default::default(),
-        }
-    }
-}
-
 impl EmailValidator for StdEmailValidator {
     fn is_valid(&self, email: &str) -> GenericResult<bool> {
         let email = String::from(email);
@@ -1,3 +1,9 @@
+pub mod email_normalizer_adapter;
+pub mod email_policy_validator;
 pub mod email_validator_adapter;
 
+pub use email_normalizer_adapter::{EmailNormalizerAdapter, EmailNormalizerConfig};
+pub use email_policy_validator::{
+    EmailPolicyConfig, EmailPolicyValidator, MockMxChecker, MxChecker,
+};
 pub use email_validator_adapter::{EmailValidatorAdapter, MockEmailValidatorAdapter};
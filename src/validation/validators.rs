@@ -0,0 +1,46 @@
+pub mod compare_fields_validation;
+pub mod email_validation;
+pub mod max_length_validation;
+pub mod min_length_validation;
+pub mod password_policy_validation;
+pub mod regex_validation;
+pub mod required_field_validation;
+pub mod validation_composite;
+
+pub use compare_fields_validation::CompareFieldsValidation;
+pub use email_validation::EmailValidation;
+pub use max_length_validation::MaxLengthValidation;
+pub use min_length_validation::MinLengthValidation;
+pub use password_policy_validation::{PasswordPolicy, PasswordPolicyValidation};
+pub use regex_validation::RegexValidation;
+pub use required_field_validation::RequiredFieldValidation;
+pub use validation_composite::ValidationComposite;
+
+use crate::presentation::protocols::ValidationInput;
+
+/// Gets the value of `field`, treating empty values as absent. Only
+/// `RequiredFieldValidation` reports missing fields, the others skip them.
+fn present_value<'a>(input: &'a dyn ValidationInput, field: &str) -> Option<&'a str> {
+    input.field(field).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod test_input {
+    use std::collections::HashMap;
+
+    use crate::presentation::protocols::ValidationInput;
+
+    pub struct TestInput(pub HashMap<&'static str, &'static str>);
+
+    impl TestInput {
+        pub fn new(fields: &[(&'static str, &'static str)]) -> Self {
+            Self(fields.iter().copied().collect())
+        }
+    }
+
+    impl ValidationInput for TestInput {
+        fn field(&self, name: &str) -> Option<&str> {
+            self.0.get(name).copied()
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{Validation, ValidationInput};
+use crate::GenericResult;
+
+use super::present_value;
+
+pub struct CompareFieldsValidation {
+    field: String,
+    field_to_compare: String,
+}
+
+impl CompareFieldsValidation {
+    pub fn new(field: &str, field_to_compare: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            field_to_compare: field_to_compare.to_string(),
+        }
+    }
+}
+
+impl Validation for CompareFieldsValidation {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        let value = present_value(input, &self.field);
+        let value_to_compare = present_value(input, &self.field_to_compare);
+
+        match (value, value_to_compare) {
+            (Some(value), Some(value_to_compare)) if value != value_to_compare => {
+                Ok(vec![FieldError::new(&self.field, "invalid param")])
+            }
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::Validation;
+    use crate::validation::validators::test_input::TestInput;
+
+    use super::CompareFieldsValidation;
+
+    fn make_sut() -> CompareFieldsValidation {
+        CompareFieldsValidation::new("field", "field_to_compare")
+    }
+
+    #[test]
+    fn returns_an_error_if_the_fields_differ() {
+        let sut = make_sut();
+        let input = TestInput::new(&[("field", "any"), ("field_to_compare", "other")]);
+
+        let errors = sut.validate(&input).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("field", "invalid param")]);
+    }
+
+    #[test]
+    fn returns_no_error_if_the_fields_match() {
+        let sut = make_sut();
+        let input = TestInput::new(&[("field", "any"), ("field_to_compare", "any")]);
+
+        let errors = sut.validate(&input).unwrap();
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn returns_no_error_if_a_field_is_missing() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "any")])).unwrap();
+
+        assert!(errors.is_empty());
+    }
+}
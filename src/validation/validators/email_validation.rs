@@ -0,0 +1,137 @@
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{EmailValidator, Validation, ValidationInput};
+use crate::GenericResult;
+
+use super::present_value;
+
+pub struct EmailValidation {
+    field: String,
+    email_validator: Box<dyn EmailValidator>,
+}
+
+impl EmailValidation {
+    pub fn new(field: &str, email_validator: Box<dyn EmailValidator>) -> Self {
+        Self {
+            field: field.to_string(),
+            email_validator,
+        }
+    }
+
+    /// Set the email validation's email validator.
+    pub fn set_email_validator(&mut self, email_validator: Box<dyn EmailValidator>) {
+        self.email_validator = email_validator;
+    }
+}
+
+impl Validation for EmailValidation {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        let email = match present_value(input, &self.field) {
+            Some(email) => email,
+            None => return Ok(vec![]),
+        };
+
+        match self.email_validator.check(email)? {
+            Some(rejection) => Ok(vec![FieldError::new(&self.field, rejection.detail())]),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use mockall_double::double;
+
+    #[double]
+    use crate::presentation::protocols::EmailValidator;
+
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::{EmailRejection, Validation};
+    use crate::validation::validators::test_input::TestInput;
+
+    use super::EmailValidation;
+
+    fn make_sut() -> EmailValidation {
+        let mut email_validator = EmailValidator::default();
+        email_validator.expect_check().returning(|_| Ok(None));
+
+        EmailValidation::new("email", Box::new(email_validator))
+    }
+
+    #[test]
+    fn calls_email_validator_with_correct_email() {
+        let mut email_validator = EmailValidator::default();
+        email_validator
+            .expect_check()
+            .with(predicate::eq("any_email@mail.com"))
+            .once()
+            .returning(|_| Ok(None));
+
+        let mut sut = make_sut();
+        sut.set_email_validator(Box::new(email_validator));
+
+        let _ = sut.validate(&TestInput::new(&[("email", "any_email@mail.com")]));
+    }
+
+    #[test]
+    fn returns_an_error_if_email_validator_rejects_the_email() {
+        let mut email_validator = EmailValidator::default();
+        email_validator
+            .expect_check()
+            .returning(|_| Ok(Some(EmailRejection::InvalidSyntax)));
+
+        let mut sut = make_sut();
+        sut.set_email_validator(Box::new(email_validator));
+
+        let errors = sut
+            .validate(&TestInput::new(&[("email", "invalid_email")]))
+            .unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("email", "invalid param")]);
+    }
+
+    #[test]
+    fn reports_the_rejection_reason() {
+        let mut email_validator = EmailValidator::default();
+        email_validator
+            .expect_check()
+            .returning(|_| Ok(Some(EmailRejection::DisposableDomain)));
+
+        let mut sut = make_sut();
+        sut.set_email_validator(Box::new(email_validator));
+
+        let errors = sut
+            .validate(&TestInput::new(&[("email", "any_email@mailinator.com")]))
+            .unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("email", "disposable domain")]);
+    }
+
+    #[test]
+    fn returns_err_if_email_validator_returns_err() {
+        let mut email_validator = EmailValidator::default();
+        email_validator
+            .expect_check()
+            .returning(|_| Err("any_error".into()));
+
+        let mut sut = make_sut();
+        sut.set_email_validator(Box::new(email_validator));
+
+        let result = sut.validate(&TestInput::new(&[("email", "any_email@mail.com")]));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn does_not_call_email_validator_if_the_email_is_missing() {
+        let mut email_validator = EmailValidator::default();
+        email_validator.expect_check().never();
+
+        let mut sut = make_sut();
+        sut.set_email_validator(Box::new(email_validator));
+
+        let errors = sut.validate(&TestInput::new(&[])).unwrap();
+
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{Validation, ValidationInput};
+use crate::GenericResult;
+
+use super::present_value;
+
+pub struct MaxLengthValidation {
+    field: String,
+    max_length: usize,
+}
+
+impl MaxLengthValidation {
+    /// Requires `field` to have at most `max_length` characters.
+    pub fn new(field: &str, max_length: usize) -> Self {
+        Self {
+            field: field.to_string(),
+            max_length,
+        }
+    }
+}
+
+impl Validation for MaxLengthValidation {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        match present_value(input, &self.field) {
+            Some(value) if value.chars().count() > self.max_length => {
+                Ok(vec![FieldError::new(&self.field, "too long")])
+            }
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::Validation;
+    use crate::validation::validators::test_input::TestInput;
+
+    use super::MaxLengthValidation;
+
+    fn make_sut() -> MaxLengthValidation {
+        MaxLengthValidation::new("field", 3)
+    }
+
+    #[test]
+    fn returns_an_error_if_the_field_is_too_long() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "abcd")])).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("field", "too long")]);
+    }
+
+    #[test]
+    fn counts_characters_instead_of_bytes() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "áéí")])).unwrap();
+
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{Validation, ValidationInput};
+use crate::GenericResult;
+
+use super::present_value;
+
+pub struct MinLengthValidation {
+    field: String,
+    min_length: usize,
+}
+
+impl MinLengthValidation {
+    /// Requires `field` to have at least `min_length` characters.
+    pub fn new(field: &str, min_length: usize) -> Self {
+        Self {
+            field: field.to_string(),
+            min_length,
+        }
+    }
+}
+
+impl Validation for MinLengthValidation {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        match present_value(input, &self.field) {
+            Some(value) if value.chars().count() < self.min_length => {
+                Ok(vec![FieldError::new(&self.field, "too short")])
+            }
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::Validation;
+    use crate::validation::validators::test_input::TestInput;
+
+    use super::MinLengthValidation;
+
+    fn make_sut() -> MinLengthValidation {
+        MinLengthValidation::new("field", 3)
+    }
+
+    #[test]
+    fn returns_an_error_if_the_field_is_too_short() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "ab")])).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("field", "too short")]);
+    }
+
+    #[test]
+    fn counts_characters_instead_of_bytes() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "áé")])).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("field", "too short")]);
+    }
+
+    #[test]
+    fn returns_no_error_if_the_field_is_long_enough() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "abc")])).unwrap();
+
+        assert!(errors.is_empty());
+    }
+}
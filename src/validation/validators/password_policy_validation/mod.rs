@@ -0,0 +1,133 @@
+use serde::Deserialize;
+
+use crate::domain::password::is_personal_info;
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{Validation, ValidationInput};
+use crate::GenericResult;
+
+use super::present_value;
+
+#[cfg(test)]
+mod tests;
+
+/// Offline list of common breached passwords, one per line, in lowercase.
+const COMMON_PASSWORDS: &str = include_str!("common_passwords.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    /// Bounds the cost of hashing the password.
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Rejects passwords found in the bundled common passwords list.
+    pub reject_common: bool,
+    /// Rejects passwords equal to the user's name or email.
+    pub reject_personal_info: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: false,
+            reject_common: true,
+            reject_personal_info: true,
+        }
+    }
+}
+
+/// Checks a password against a `PasswordPolicy`, reporting every broken rule.
+pub struct PasswordPolicyValidation {
+    field: String,
+    personal_fields: Vec<String>,
+    policy: PasswordPolicy,
+}
+
+impl PasswordPolicyValidation {
+    /// Validates `field` against `policy`. The values of `personal_fields`
+    /// (e.g. name and email) can't be used as the password. Bodies carrying
+    /// no personal info pass none, the use case then checks the password
+    /// against the account it changes.
+    pub fn new(field: &str, personal_fields: &[&str], policy: PasswordPolicy) -> Self {
+        Self {
+            field: field.to_string(),
+            personal_fields: personal_fields
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            policy,
+        }
+    }
+
+    fn is_personal_info(&self, input: &dyn ValidationInput, password: &str) -> bool {
+        let personal_values: Vec<&str> = self
+            .personal_fields
+            .iter()
+            .filter_map(|field| present_value(input, field))
+            .collect();
+
+        is_personal_info(password, &personal_values)
+    }
+}
+
+impl Validation for PasswordPolicyValidation {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        let password = match present_value(input, &self.field) {
+            Some(password) => password,
+            None => return Ok(vec![]),
+        };
+
+        let policy = &self.policy;
+        let length = password.chars().count();
+        let lowercase_password = password.to_lowercase();
+
+        let rules = [
+            (length < policy.min_length, "too short"),
+            (length > policy.max_length, "too long"),
+            (
+                policy.require_lowercase && !password.chars().any(char::is_lowercase),
+                "missing lowercase letter",
+            ),
+            (
+                policy.require_uppercase && !password.chars().any(char::is_uppercase),
+                "missing uppercase letter",
+            ),
+            (
+                policy.require_digit && !password.chars().any(|c| c.is_ascii_digit()),
+                "missing digit",
+            ),
+            (
+                policy.require_symbol && password.chars().all(char::is_alphanumeric),
+                "missing symbol",
+            ),
+            (
+                policy.reject_common && is_common(&lowercase_password),
+                "too common",
+            ),
+            (
+                policy.reject_personal_info && self.is_personal_info(input, password),
+                "contains personal info",
+            ),
+        ];
+
+        Ok(rules
+            .iter()
+            .filter(|(broken, _)| *broken)
+            .map(|(_, detail)| FieldError::new(&self.field, detail))
+            .collect())
+    }
+}
+
+fn is_common(lowercase_password: &str) -> bool {
+    COMMON_PASSWORDS
+        .lines()
+        .any(|common| common == lowercase_password)
+}
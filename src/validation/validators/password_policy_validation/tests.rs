@@ -0,0 +1,126 @@
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::Validation;
+use crate::validation::validators::test_input::TestInput;
+
+use super::{PasswordPolicy, PasswordPolicyValidation};
+
+fn make_sut() -> PasswordPolicyValidation {
+    PasswordPolicyValidation::new("password", &["name", "email"], PasswordPolicy::default())
+}
+
+fn validate(sut: &PasswordPolicyValidation, password: &'static str) -> Vec<FieldError> {
+    let input = TestInput::new(&[
+        ("name", "John"),
+        ("email", "john.doe@mail.com"),
+        ("password", password),
+    ]);
+
+    sut.validate(&input).unwrap()
+}
+
+fn details(errors: &[FieldError]) -> Vec<&str> {
+    errors.iter().map(|error| error.detail()).collect()
+}
+
+#[test]
+fn accepts_a_password_following_the_policy() {
+    let sut = make_sut();
+
+    assert!(validate(&sut, "Correct7Horse").is_empty());
+}
+
+#[test]
+fn skips_missing_passwords() {
+    let sut = make_sut();
+
+    assert!(sut.validate(&TestInput::new(&[])).unwrap().is_empty());
+}
+
+#[test]
+fn reports_every_broken_rule_of_the_field() {
+    let sut = make_sut();
+
+    let errors = validate(&sut, "abc");
+
+    assert_eq!(
+        errors,
+        vec![
+            FieldError::new("password", "too short"),
+            FieldError::new("password", "missing uppercase letter"),
+            FieldError::new("password", "missing digit"),
+        ]
+    );
+}
+
+#[test]
+fn returns_an_error_if_the_password_is_too_long() {
+    let policy = PasswordPolicy {
+        max_length: 10,
+        ..PasswordPolicy::default()
+    };
+
+    let sut = PasswordPolicyValidation::new("password", &[], policy);
+
+    assert_eq!(details(&validate(&sut, "Correct7Horse")), vec!["too long"]);
+}
+
+#[test]
+fn requires_a_symbol_if_the_policy_asks_for_it() {
+    let policy = PasswordPolicy {
+        require_symbol: true,
+        ..PasswordPolicy::default()
+    };
+
+    let sut = PasswordPolicyValidation::new("password", &[], policy);
+
+    assert_eq!(
+        details(&validate(&sut, "Correct7Horse")),
+        vec!["missing symbol"]
+    );
+    assert!(validate(&sut, "Correct7Horse!").is_empty());
+}
+
+#[test]
+fn rejects_common_passwords_ignoring_case() {
+    let sut = make_sut();
+
+    assert_eq!(details(&validate(&sut, "Password123")), vec!["too common"]);
+}
+
+#[test]
+fn rejects_the_name_or_email_as_password() {
+    let policy = PasswordPolicy {
+        require_uppercase: false,
+        require_digit: false,
+        ..PasswordPolicy::default()
+    };
+
+    let sut = PasswordPolicyValidation::new("password", &["name", "email"], policy);
+
+    assert_eq!(
+        details(&validate(&sut, "John.Doe@mail.com")),
+        vec!["contains personal info"]
+    );
+    assert_eq!(
+        details(&validate(&sut, "john.doe")),
+        vec!["contains personal info"]
+    );
+}
+
+#[test]
+fn allows_every_rule_to_be_disabled() {
+    let policy = PasswordPolicy {
+        min_length: 0,
+        max_length: usize::MAX,
+        require_lowercase: false,
+        require_uppercase: false,
+        require_digit: false,
+        require_symbol: false,
+        reject_common: false,
+        reject_personal_info: false,
+    };
+
+    let sut = PasswordPolicyValidation::new("password", &["name", "email"], policy);
+
+    assert!(validate(&sut, "john").is_empty());
+}
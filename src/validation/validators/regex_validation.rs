@@ -0,0 +1,67 @@
+use regex::Regex;
+
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{Validation, ValidationInput};
+use crate::GenericResult;
+
+use super::present_value;
+
+pub struct RegexValidation {
+    field: String,
+    regex: Regex,
+}
+
+impl RegexValidation {
+    /// Requires `field` to match `regex`. Anchor the pattern to match the
+    /// whole value.
+    pub fn new(field: &str, regex: Regex) -> Self {
+        Self {
+            field: field.to_string(),
+            regex,
+        }
+    }
+}
+
+impl Validation for RegexValidation {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        match present_value(input, &self.field) {
+            Some(value) if !self.regex.is_match(value) => {
+                Ok(vec![FieldError::new(&self.field, "invalid param")])
+            }
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::Validation;
+    use crate::validation::validators::test_input::TestInput;
+
+    use super::RegexValidation;
+
+    fn make_sut() -> RegexValidation {
+        RegexValidation::new("field", Regex::new(r"^\d+$").unwrap())
+    }
+
+    #[test]
+    fn returns_an_error_if_the_field_does_not_match() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "12a")])).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("field", "invalid param")]);
+    }
+
+    #[test]
+    fn returns_no_error_if_the_field_matches() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "123")])).unwrap();
+
+        assert!(errors.is_empty());
+    }
+}
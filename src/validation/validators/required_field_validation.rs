@@ -0,0 +1,66 @@
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{Validation, ValidationInput};
+use crate::GenericResult;
+
+use super::present_value;
+
+pub struct RequiredFieldValidation {
+    field: String,
+}
+
+impl RequiredFieldValidation {
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+impl Validation for RequiredFieldValidation {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        match present_value(input, &self.field) {
+            Some(_) => Ok(vec![]),
+            None => Ok(vec![FieldError::new(&self.field, "missing param")]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::Validation;
+    use crate::validation::validators::test_input::TestInput;
+
+    use super::RequiredFieldValidation;
+
+    fn make_sut() -> RequiredFieldValidation {
+        RequiredFieldValidation::new("field")
+    }
+
+    #[test]
+    fn returns_an_error_if_the_field_is_missing() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[])).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("field", "missing param")]);
+    }
+
+    #[test]
+    fn returns_an_error_if_the_field_is_empty() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "")])).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("field", "missing param")]);
+    }
+
+    #[test]
+    fn returns_no_error_if_the_field_is_present() {
+        let sut = make_sut();
+
+        let errors = sut.validate(&TestInput::new(&[("field", "any")])).unwrap();
+
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::presentation::http::FieldError;
+use crate::presentation::protocols::{Validation, ValidationInput};
+use crate::GenericResult;
+
+/// Runs every validation and collects their errors. Only the errors of the
+/// first validation failing a field are kept, so list the validations of a
+/// field from the most to the least basic one (e.g. required before email).
+pub struct ValidationComposite {
+    validations: Vec<Box<dyn Validation>>,
+}
+
+impl ValidationComposite {
+    pub fn new(validations: Vec<Box<dyn Validation>>) -> Self {
+        Self { validations }
+    }
+}
+
+impl Validation for ValidationComposite {
+    fn validate(&self, input: &dyn ValidationInput) -> GenericResult<Vec<FieldError>> {
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        for validation in &self.validations {
+            let validation_errors = validation.validate(input)?;
+            let failed_fields = errors.len();
+
+            for error in validation_errors {
+                let already_failed = errors[..failed_fields]
+                    .iter()
+                    .any(|other| other.field() == error.field());
+
+                if !already_failed {
+                    errors.push(error);
+                }
+            }
+        }
+
+        Ok(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presentation::http::FieldError;
+    use crate::presentation::protocols::{MockValidation, Validation};
+    use crate::validation::validators::test_input::TestInput;
+
+    use super::ValidationComposite;
+
+    fn make_validation(errors: Vec<FieldError>) -> Box<MockValidation> {
+        let mut validation = MockValidation::default();
+        validation
+            .expect_validate()
+            .returning(move |_| Ok(errors.clone()));
+        Box::new(validation)
+    }
+
+    #[test]
+    fn returns_the_errors_of_every_validation() {
+        let sut = ValidationComposite::new(vec![
+            make_validation(vec![FieldError::new("name", "missing param")]),
+            make_validation(vec![]),
+            make_validation(vec![FieldError::new("email", "invalid param")]),
+        ]);
+
+        let errors = sut.validate(&TestInput::new(&[])).unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                FieldError::new("name", "missing param"),
+                FieldError::new("email", "invalid param"),
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_the_errors_of_fields_that_already_failed() {
+        let sut = ValidationComposite::new(vec![
+            make_validation(vec![FieldError::new("email", "missing param")]),
+            make_validation(vec![FieldError::new("email", "invalid param")]),
+        ]);
+
+        let errors = sut.validate(&TestInput::new(&[])).unwrap();
+
+        assert_eq!(errors, vec![FieldError::new("email", "missing param")]);
+    }
+
+    #[test]
+    fn keeps_every_error_of_the_first_failing_validation() {
+        let sut = ValidationComposite::new(vec![
+            make_validation(vec![
+                FieldError::new("password", "too short"),
+                FieldError::new("password", "missing digit"),
+            ]),
+            make_validation(vec![FieldError::new("password", "too common")]),
+        ]);
+
+        let errors = sut.validate(&TestInput::new(&[])).unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                FieldError::new("password", "too short"),
+                FieldError::new("password", "missing digit"),
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_err_if_any_validation_returns_err() {
+        let mut failing = MockValidation::default();
+        failing
+            .expect_validate()
+            .returning(|_| Err("any_error".into()));
+
+        let sut = ValidationComposite::new(vec![make_validation(vec![]), Box::new(failing)]);
+
+        assert!(sut.validate(&TestInput::new(&[])).is_err());
+    }
+
+    #[test]
+    fn returns_no_error_if_every_validation_succeeds() {
+        let sut = ValidationComposite::new(vec![make_validation(vec![]), make_validation(vec![])]);
+
+        let errors = sut.validate(&TestInput::new(&[])).unwrap();
+
+        assert!(errors.is_empty());
+    }
+}
@@ -1,22 +1,91 @@
+use clean_rust_api::infra::db::{AccountMongoRepository, MongoConfig, MongoHelper};
+use mongodb::bson::oid::ObjectId;
+
+async fn make_sut() -> AccountMongoRepository {
+    let mongo = MongoHelper::connect(&MongoConfig::default()).await.unwrap();
+    AccountMongoRepository::new(mongo)
+}
+
+fn make_email(name: &str) -> String {
+    format!("{}_{}@gmail.com", name, ObjectId::new().to_hex())
+}
+
 mod add {
+    use clean_rust_api::data::errors::DataError;
     use clean_rust_api::data::protocols::AddAccountRepository;
     use clean_rust_api::domain::usecases::AddAccountDto;
-    use clean_rust_api::infra::db::AccountMongoRepository;
+    use clean_rust_api::infra::db::protocols::AccountRepository;
+
+    use mongodb::bson::oid::ObjectId;
+
+    use super::{make_email, make_sut};
 
     #[tokio::test]
     async fn returns_an_account_on_success() {
-        let sut = AccountMongoRepository::new();
+        let sut = make_sut().await;
+        let email = make_email("foo");
         let account_dto = AddAccountDto {
             name: String::from("Foo"),
-            email: String::from("foo@gmail.com"),
+            email: email.clone(),
             password: String::from("123"),
         };
 
         let account = sut.add(account_dto).await.unwrap();
 
-        assert!(!account.id().is_empty());
+        assert!(ObjectId::parse_str(account.id()).is_ok());
         assert_eq!(account.name(), "Foo");
-        assert_eq!(account.email(), "foo@gmail.com");
+        assert_eq!(account.email(), email);
         assert_eq!(account.password(), "123");
     }
+
+    #[tokio::test]
+    async fn returns_email_in_use_error_if_email_is_already_registered() {
+        let sut = make_sut().await;
+        sut.create_indexes().await.unwrap();
+
+        let email = make_email("baz");
+        let make_account_dto = || AddAccountDto {
+            name: String::from("Baz"),
+            email: email.clone(),
+            password: String::from("123"),
+        };
+
+        sut.add(make_account_dto()).await.unwrap();
+        let err = sut.add(make_account_dto()).await.unwrap_err();
+
+        assert!(matches!(err, DataError::DuplicateEmail));
+    }
+}
+
+mod load_by_email {
+    use clean_rust_api::data::protocols::{AddAccountRepository, LoadAccountByEmailRepository};
+    use clean_rust_api::domain::usecases::AddAccountDto;
+
+    use super::{make_email, make_sut};
+
+    #[tokio::test]
+    async fn returns_an_account_on_success() {
+        let sut = make_sut().await;
+        let email = make_email("bar");
+        let account_dto = AddAccountDto {
+            name: String::from("Bar"),
+            email: email.clone(),
+            password: String::from("123"),
+        };
+
+        let added = sut.add(account_dto).await.unwrap();
+
+        let account = sut.load_by_email(&email).await.unwrap().unwrap();
+
+        assert_eq!(account, added);
+    }
+
+    #[tokio::test]
+    async fn returns_none_if_no_account_has_the_email() {
+        let sut = make_sut().await;
+
+        let account = sut.load_by_email(&make_email("missing")).await.unwrap();
+
+        assert_eq!(account, None);
+    }
 }